@@ -0,0 +1,62 @@
+extern crate util;
+
+use util::error::AppErr;
+use util::solution::{ Answer, Registration, Solution };
+
+fn find_final_level(input: &str) -> i32 {
+
+    let mut level = 0;
+
+    for c in input.chars() {
+        match c {
+            '(' => level += 1, //Step up
+            ')' => level -= 1, //Step down
+             _  => continue,   //Ignore character
+        }
+    }
+
+    level
+}
+
+fn find_first_step_which_descends_into_the_basement(input: &str) -> Option<u32> {
+
+    let mut level = 0;
+    let mut step = 0;
+
+    for c in input.chars() {
+        match c {
+            '(' => level += 1, //Step up
+            ')' => level -= 1, //Step down
+             _  => continue,   //Ignore character
+        }
+
+        step += 1;
+        if level < 0 {
+            return Some(step);
+        }
+    }
+
+    None
+}
+
+pub struct Day1;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 1, solver: &Day1 };
+
+impl Solution for Day1 {
+    type Model<'a> = &'a str;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<&'a str, AppErr> {
+        Ok(input)
+    }
+
+    fn part1(&self, input: &&str) -> Result<Answer, AppErr> {
+        Ok(find_final_level(input).into())
+    }
+
+    fn part2(&self, input: &&str) -> Result<Answer, AppErr> {
+        let first = find_first_step_which_descends_into_the_basement(input)
+            .ok_or_else(|| AppErr::no_solution("Never entered the basement"))?;
+        Ok(first.into())
+    }
+}
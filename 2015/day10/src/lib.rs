@@ -0,0 +1,89 @@
+extern crate util;
+
+use std::fmt::Write;
+
+use util::error::AppErr;
+use util::solution::{ Answer, Registration, Solution };
+
+fn count_digits(s: &str) -> Vec<(u32, u32)> {
+    
+    fn char_to_u32(c: char) -> u32 {
+        match c {
+            '0'..='9' => (c as u8 - b'0') as u32,
+             _        => panic!("Unexpected character `{}`", c) 
+        }
+    }
+    
+    let mut data = Vec::new();
+    let mut current = None;
+    
+    for c in s.chars() {
+        let n = char_to_u32(c);
+        current = match current {
+            None => {
+                Some((n, 1))
+            },
+            Some((num, count)) if num != n => {
+                data.push((num, count));
+                Some((n, 1))
+            },
+            Some((num, count)) => {
+                Some((num, count + 1))
+            }
+        };
+    }
+    
+    if let Some(pair) = current {
+        data.push(pair);
+    }
+    
+    data
+}
+
+fn build_look_and_say_string(counts: &Vec<(u32, u32)>) -> String {
+    
+    let mut buf = String::with_capacity(counts.len() * 2);
+    
+    for &(num, count) in counts {
+        write!(&mut buf, "{}{}", count, num).unwrap();
+    }
+    
+    buf
+}
+
+fn look_and_say_length(input: &str, iterations: usize) -> Result<usize, AppErr> {
+
+    let mut input = input.to_string();
+
+    for _ in 0..iterations {
+        // The string grows by about a third each time
+        util::cancel::check()?;
+
+        let counts = count_digits(&input);
+
+        input = build_look_and_say_string(&counts);
+    }
+
+    Ok(input.chars().count())
+}
+
+pub struct Day10;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 10, solver: &Day10 };
+
+impl Solution for Day10 {
+    type Model<'a> = &'a str;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<&'a str, AppErr> {
+        let input = input.lines().next().ok_or("No lines in input")?;
+        Ok(input.trim())
+    }
+
+    fn part1(&self, input: &&str) -> Result<Answer, AppErr> {
+        Ok(look_and_say_length(input, 40)?.into())
+    }
+
+    fn part2(&self, input: &&str) -> Result<Answer, AppErr> {
+        Ok(look_and_say_length(input, 50)?.into())
+    }
+}
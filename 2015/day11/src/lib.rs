@@ -0,0 +1,155 @@
+extern crate util;
+
+use std::collections::HashSet;
+
+use util::error::AppErr;
+use util::solution::{ Answer, Registration, Solution };
+
+fn char_to_alpha_index(c: char) -> u8 {
+    match c {
+        'a'..='z' => c as u8 - b'a',
+         _        => panic!("Character '{}' out of range", c)
+    }
+}
+
+fn alpha_index_to_char(i: u8) -> char {
+    match i {
+        0..=25 => (b'a' + i) as char,
+        _      => panic!("Alpha index '{}' invalid", i) 
+    }
+    
+}
+
+fn check_non_overlapping_pairs(s: &[u8]) -> bool {
+    const MIN_PAIRS: usize = 2;
+    
+    let mut last = None;
+    let mut unique_pairs = HashSet::new();
+    
+    for c in s.iter() {
+        last = match last {
+            Some(last) if last == c => {
+                unique_pairs.insert(c);
+                None
+            },
+            Some(_) | None => {
+                Some(c)
+            }
+        };
+        
+        if unique_pairs.len() >= MIN_PAIRS {
+            return true;
+        }
+    }
+    
+    false
+}
+
+fn check_increasing_straight(s: &[u8]) -> bool {
+    const MIN_STRAIGHT_LEN: usize = 3;
+    
+    let mut last = None;
+    let mut straight_length = 0;
+    
+    for &c in s {
+        if let Some(last) = last {
+            if c == (last + 1) {
+                straight_length += 1;
+            }
+            else {
+                straight_length = 1;
+            }
+        }
+        
+        if straight_length >= MIN_STRAIGHT_LEN {
+            return true;
+        }
+        
+        last = Some(c);
+    }
+    
+    false
+}
+
+fn check_invalid_characters(s: &[u8]) -> bool {
+    
+    let invalid_chars = [char_to_alpha_index('i'), char_to_alpha_index('o'), char_to_alpha_index('l')];
+    
+    !s.iter().any(|c| invalid_chars.contains(c))
+}
+
+fn is_valid_password(s: &[u8]) -> bool {
+ 
+    check_non_overlapping_pairs(s) && check_increasing_straight(s) && check_invalid_characters(s)
+}
+
+fn increment_password(s: &mut [u8]) {
+    
+    if s.is_empty() {
+        panic!("Input is zero length")
+    }
+    
+    // Step the last digit in the password (wrapping after 24).
+    // If the digit wraps, continue and wrap the next digit too.
+    // Repeat until you reach a digit that does not wrap.
+    
+    let digit_max = char_to_alpha_index('z');
+    
+    let last = s.len() - 1;
+    let mut i = last;
+    
+    loop {
+        
+        let next_digit = s[i] + 1;
+        
+        if next_digit <= digit_max {
+            s[i] = next_digit;
+            
+            // Incremented this digit without wrapping - halt
+            break;
+        }
+        
+        s[i] = 0;
+        
+        // Move to the next digit, or wrap back around
+        i = if i == 0 { last } else { i - 1 }
+    }
+}
+
+fn next_password(s: &str) -> String {
+    
+    //Convert string into a vector of alphabetic indices
+    let mut working: Vec<u8> = s.chars().map(char_to_alpha_index).collect();
+ 
+    loop {
+        increment_password(&mut working);
+        
+        if is_valid_password(&working) {
+            break;
+        }
+    }
+    
+    working.into_iter().map(alpha_index_to_char).collect()
+}
+
+pub struct Day11;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 11, solver: &Day11 };
+
+impl Solution for Day11 {
+    type Model<'a> = &'a str;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<&'a str, AppErr> {
+        let input = input.lines().next().ok_or("No lines in input")?;
+        Ok(input.trim())
+    }
+
+    fn part1(&self, input: &&str) -> Result<Answer, AppErr> {
+        Ok(next_password(input).into())
+    }
+
+    fn part2(&self, input: &&str) -> Result<Answer, AppErr> {
+        let input = next_password(input);
+        Ok(next_password(&input).into())
+    }
+}
@@ -0,0 +1,57 @@
+extern crate serde_json;
+extern crate util;
+
+use serde_json::{ Value };
+
+use util::error::{ AppErr, ErrorKind };
+use util::solution::{ Answer, Registration, Solution };
+
+fn is_red_value(json: &Value) -> bool {
+    match json {
+        Value::String(val) => val == "red",
+        _                       => false
+    }
+}
+
+fn recursive_sum_numbers(json: &Value, ignore_red: bool) -> i64 {
+    match json {
+        Value::Object(map) => {
+            // Challenge two - discount any objects with a "red" property value
+            if ignore_red && map.iter().any(|(_, val)| is_red_value(val)) {
+                return 0;
+            }
+            map.iter()
+               .map(|(_, val)| recursive_sum_numbers(val, ignore_red))
+               .sum()
+        },
+        Value::Array(arr) => {
+            arr.iter()
+               .map(|val| recursive_sum_numbers(val, ignore_red))
+               .sum()
+        },
+        Value::Number(num) => num.as_i64().unwrap_or_else(|| num.as_f64().unwrap_or(0.0) as i64),
+        _                       => 0,
+    }
+}
+
+pub struct Day12;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 12, solver: &Day12 };
+
+impl Solution for Day12 {
+    type Model<'a> = Value;
+
+    fn parse(&self, input: &str) -> Result<Value, AppErr> {
+        serde_json::from_str(input)
+            .map_err(|e| AppErr::from_display("serde_json::Error", &e).with_kind(ErrorKind::Parse))
+    }
+
+    // Sum of all numbers in the input
+    fn part1(&self, json: &Value) -> Result<Answer, AppErr> {
+        Ok(recursive_sum_numbers(json, false).into())
+    }
+
+    fn part2(&self, json: &Value) -> Result<Answer, AppErr> {
+        Ok(recursive_sum_numbers(json, true).into())
+    }
+}
@@ -0,0 +1,131 @@
+extern crate regex;
+extern crate util;
+
+mod permute;
+
+use std::collections::{ HashSet, HashMap };
+use regex::Regex;
+use permute::permute;
+
+use util::ConsumeIterator;
+use util::error::AppErr;
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Debug)]
+enum Change {
+    Gain(i32),
+    Lose(i32)
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    person: String,
+    neighbour: String,
+    change: Change,
+}
+
+fn parse_instruction(re: &Regex, s: &str) -> Result<Instruction, AppErr> {
+
+    match re.captures(s) {
+        None       => Err("Instruction does not match pattern".into()),
+        Some(caps) => {
+
+            let person    = caps[1].into();
+            let change    = &caps[2];
+            let units     = caps[3].parse()?;
+            let neighbour = caps[4].into();
+
+            let change = match change {
+                "gain" => Change::Gain(units),
+                "lose" => Change::Lose(units),
+                 _     => unreachable!()
+            };
+
+            Ok(Instruction {
+                person,
+                neighbour,
+                change
+            })
+        }
+    }
+}
+
+fn biggest_happiness_change(instructions: &[Instruction], include_self: bool) -> Result<i32, AppErr> {
+
+    // Collate distinct people in the instruction set
+    let mut people: HashSet<_> =
+        instructions.iter().map(|s| s.person.as_str()).collect();
+
+    // Build lookup table of relationships
+    let mut relationships = HashMap::new();
+    for inst in instructions.iter() {
+        relationships.entry(inst.person.as_str())
+                     .or_insert_with(HashMap::new)
+                     .insert(inst.neighbour.as_str(), &inst.change);
+    }
+
+    // Part two - add yourself to the mix (with no relationship baggage)
+    if include_self {
+        people.insert("My own good self");
+    }
+
+    let mut happiness_delta_max = 0;
+
+    // Permute lazily generates all permutations of the given sequence,
+    // of which there are millions, so check for cancellation now and then
+    for (i, peeps) in permute(people.iter()).enumerate() {
+        if i.is_multiple_of(1 << 14) {
+            util::cancel::check()?;
+        }
+
+        let mut happiness_delta = 0;
+
+        // Iterate over all adjacent pairs in the given sequence
+        for (&left, &right) in peeps.iter().circular_pairs() {
+
+            // Checks for a relationship betweek a and b...
+            let mut check = |a, b| {
+                match relationships.get(a).and_then(|rel| rel.get(b)) {
+                    Some(&&Change::Gain(amt)) => { happiness_delta += amt; },
+                    Some(&&Change::Lose(amt)) => { happiness_delta -= amt; },
+                    _ => {}
+                }
+            };
+
+            // Be sure to check the relationship from both directions!
+            check(left, right);
+            check(right, left);
+        }
+
+        if happiness_delta > happiness_delta_max {
+            happiness_delta_max = happiness_delta;
+        }
+    }
+
+    Ok(happiness_delta_max)
+}
+
+pub struct Day13;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 13, solver: &Day13 };
+
+impl Solution for Day13 {
+    type Model<'a> = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, AppErr> {
+        let pattern = r"^([A-Za-z]+) would (gain|lose) (\d+) happiness units by sitting next to ([A-Za-z]+).$";
+        let re = Regex::new(pattern).unwrap();
+
+        parse_lines(input, |line| parse_instruction(&re, line))
+    }
+
+    // Biggest happiness change
+    fn part1(&self, instructions: &Vec<Instruction>) -> Result<Answer, AppErr> {
+        Ok(biggest_happiness_change(instructions, false)?.into())
+    }
+
+    fn part2(&self, instructions: &Vec<Instruction>) -> Result<Answer, AppErr> {
+        Ok(biggest_happiness_change(instructions, true)?.into())
+    }
+}
@@ -0,0 +1,145 @@
+#![allow(dead_code)]
+
+extern crate regex;
+extern crate util;
+
+use regex::Regex;
+
+use util::error::AppErr;
+use util::input::parse_lines;
+use util::params::Param;
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Debug)]
+pub struct Reindeer {
+    name: String,
+    velocity_kms: i32,
+    flight_time_secs: i32,
+    rest_time_secs: i32
+}
+
+fn parse_reindeer(re: &Regex, s: &str) -> Result<Reindeer, AppErr> {
+
+    match re.captures(s) {
+        None => Err("Invalid instruction".into()),
+        Some(caps) => {
+
+            let name   = caps[1].into();
+            let vel    = caps[2].parse()?;
+            let f_time = caps[3].parse()?;
+            let r_time = caps[4].parse()?;
+
+            Ok(Reindeer {
+                name,
+                velocity_kms: vel,
+                flight_time_secs: f_time,
+                rest_time_secs: r_time
+            })
+        }
+    }
+}
+
+const RACE_SECS: Param = Param::new("race_secs", "2503", "How many seconds the race lasts");
+
+fn race(instructions: &[Reindeer], race_secs: u32) -> Vec<State<'_>> {
+
+    let mut states: Vec<_> =
+        instructions.iter()
+            .map(|r| State {
+                reindeer: r,
+                kms_travelled: 0,
+                resting: false,
+                secs: 0,
+                points: 0
+            })
+            .collect();
+
+    for _ in 0..race_secs {
+        for s in states.iter_mut() {
+            step(s);
+        }
+
+        //Part 2 - find the leading reindeer
+        if let Some(max_dist) = states.iter().map(|s| s.kms_travelled).max() {
+            // And award them a point
+            for leader in states.iter_mut().filter(|s| s.kms_travelled == max_dist) {
+                leader.points += 1;
+            }
+        }
+    }
+
+    states
+}
+
+pub struct Day14;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 14, solver: &Day14 };
+
+impl Solution for Day14 {
+    type Model<'a> = Vec<Reindeer>;
+
+    const PARAMS: &'static [Param] = &[RACE_SECS];
+
+    fn parse(&self, input: &str) -> Result<Vec<Reindeer>, AppErr> {
+        let pattern = r"^([A-Za-z]+) can fly (\d+) km/s for (\d+) seconds, but then must rest for (\d+) seconds.$";
+        let re = Regex::new(pattern).unwrap();
+
+        parse_lines(input, |line| parse_reindeer(&re, line))
+    }
+
+    // Best distance
+    fn part1(&self, instructions: &Vec<Reindeer>) -> Result<Answer, AppErr> {
+        let states = race(instructions, RACE_SECS.parse()?);
+        let best_dist = states.iter().max_by_key(|s| s.kms_travelled).ok_or("No reindeer")?;
+        Ok(best_dist.kms_travelled.into())
+    }
+
+    // Highest points
+    fn part2(&self, instructions: &Vec<Reindeer>) -> Result<Answer, AppErr> {
+        let states = race(instructions, RACE_SECS.parse()?);
+        let best_points = states.iter().max_by_key(|s| s.points).ok_or("No reindeer")?;
+        Ok(best_points.points.into())
+    }
+}
+
+struct State<'a> {
+    reindeer: &'a Reindeer,
+    kms_travelled: i32,
+    resting: bool,
+
+    //Seconds since last state change
+    secs: i32,
+
+    //Part 2 - points awarded
+    points: u32,
+}
+
+fn step(state: &mut State) {
+    //Step by one second
+    state.secs += 1;
+    if state.resting {
+        if state.secs >= state.reindeer.rest_time_secs {
+            state.resting = false;
+            state.secs = 0;
+        }
+    }
+    else {
+        state.kms_travelled += state.reindeer.velocity_kms;
+
+        if state.secs >= state.reindeer.flight_time_secs {
+            state.resting = true;
+            state.secs = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+    use util::aoc_example;
+
+    #[test]
+    fn example() {
+        aoc_example!(include_str!("../tests/fixtures/part1-1.txt"), params(race_secs = 1000), part1 = 1120, part2 = 689);
+    }
+}
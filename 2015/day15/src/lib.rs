@@ -0,0 +1,144 @@
+#![allow(dead_code)]
+
+extern crate regex;
+extern crate util;
+
+use regex::Regex;
+
+use util::error::AppErr;
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Debug)]
+pub struct Ingredient {
+    name: String,
+    capacity: i32,
+    durability: i32,
+    flavor: i32,
+    texture: i32,
+    calories: i32
+}
+
+fn parse_ingredient(re: &Regex, s: &str) -> Result<Ingredient, AppErr> {
+
+    match re.captures(s) {
+        None => Err("Unable to parse ingredient".into()),
+        Some(caps) => {
+            Ok(Ingredient {
+                name:       caps[1].into(),
+                capacity:   caps[2].parse()?,
+                durability: caps[3].parse()?,
+                flavor:     caps[4].parse()?,
+                texture:    caps[5].parse()?,
+                calories:   caps[6].parse()?,
+            })
+        }
+    }
+}
+
+pub struct Day15;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 15, solver: &Day15 };
+
+impl Solution for Day15 {
+    type Model<'a> = Vec<Ingredient>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Ingredient>, AppErr> {
+        let pattern = r"([A-Za-z]+): capacity (-?\d+), durability (-?\d+), flavor (-?\d+), texture (-?\d+), calories (-?\d+)";
+        let re = Regex::new(pattern).unwrap();
+
+        parse_lines(input, |line| parse_ingredient(&re, line))
+    }
+
+    // Calculate best ingredient mix by recursive brute-force solution
+    fn part1(&self, ingredients: &Vec<Ingredient>) -> Result<Answer, AppErr> {
+        let best_mix = find_best(ingredients, 100, Mix::empty(), None);
+
+        Ok(best_mix.score(None).into())
+    }
+
+    fn part2(&self, ingredients: &Vec<Ingredient>) -> Result<Answer, AppErr> {
+        let calorie_limit = Some(500);
+        let best_mix = find_best(ingredients, 100, Mix::empty(), calorie_limit);
+
+        Ok(best_mix.score(calorie_limit).into())
+    }
+}
+
+#[derive(Debug)]
+struct Mix {
+    capacity: i32,
+    durability: i32,
+    flavor: i32,
+    texture: i32,
+    calories: i32
+}
+
+impl Mix {
+    fn empty() -> Mix {
+        Mix {
+            capacity: 0,
+            durability: 0,
+            flavor: 0,
+            texture: 0,
+            calories: 0
+        }
+    }
+
+    fn is_poor_mix(&self, calorie_requirement: Option<i32>) -> bool {
+        if let Some(required) = calorie_requirement {
+            if self.calories != required {
+                return true;
+            }
+        }
+        // Operands swapped because < confuses my syntax highlighting
+        1 > self.capacity || 1 > self.durability || 1 > self.flavor || 1 > self.texture
+    }
+
+    fn score(&self, calorie_requirement: Option<i32>) -> i32 {
+        if self.is_poor_mix(calorie_requirement) { 0 } else { self.capacity * self.durability * self.flavor * self.texture }
+    }
+}
+
+fn find_best(ingredients: &[Ingredient], max_tsp: i32, input_mix: Mix, calorie_requirement: Option<i32>) -> Mix {
+
+    assert!(!ingredients.is_empty());
+    assert!(max_tsp >= 1);
+
+    if ingredients.len() == 1 {
+        //Return the only possible mix of a single ingredient
+        let ing = &ingredients[0];
+        return Mix {
+            capacity:   input_mix.capacity   + (ing.capacity   * max_tsp),
+            durability: input_mix.durability + (ing.durability * max_tsp),
+            flavor:     input_mix.flavor     + (ing.flavor     * max_tsp),
+            texture:    input_mix.texture    + (ing.texture    * max_tsp),
+            calories:   input_mix.calories   + (ing.calories   * max_tsp)
+        };
+    }
+
+    let mut best_mix = Mix::empty();
+
+    for tsp in 0..max_tsp + 1 {
+        let ing = &ingredients[0];
+        let mix = Mix {
+            capacity:   input_mix.capacity   + (ing.capacity   * tsp),
+            durability: input_mix.durability + (ing.durability * tsp),
+            flavor:     input_mix.flavor     + (ing.flavor     * tsp),
+            texture:    input_mix.texture    + (ing.texture    * tsp),
+            calories:   input_mix.calories   + (ing.calories   * tsp)
+        };
+
+        let mix =
+            if max_tsp - tsp <= 0 { mix }
+            else                  { find_best(&ingredients[1..], max_tsp - tsp, mix, calorie_requirement) };
+
+        //println!("{}x{} => {} {:?}", tsp, max_tsp - tsp, mix.score(), mix);
+
+        if best_mix.score(calorie_requirement) < mix.score(calorie_requirement) {
+            best_mix = mix;
+        }
+    }
+
+    best_mix
+}
@@ -0,0 +1,105 @@
+extern crate regex;
+extern crate util;
+
+use std::collections::HashMap;
+use regex::Regex;
+
+use util::error::AppErr;
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Debug)]
+pub struct Memory {
+    number: u32,
+    things: HashMap<String, u32>
+}
+
+fn parse_memory(s: &str) -> Result<Memory, AppErr> {
+
+    let re = Regex::new(r"^Sue (\d+):").unwrap();
+    let number = match re.captures(s) {
+        None => return Err("Unable to parse memory".into()),
+        Some(caps) => caps[1].parse()?
+    };
+
+    let mut things = HashMap::new();
+
+    // Can't seem to access repeated sub-patterns with a single regex?
+    // Need to break this out into two steps
+
+    let re = Regex::new(r"([a-z]+): (\d+)(,|$)").unwrap();
+    for caps in re.captures_iter(s) {
+        let name = caps[1].into();
+        let count = caps[2].parse()?;
+        things.insert(name, count);
+    }
+
+    Ok(Memory { number, things })
+}
+
+fn find_aunt(memories: &[Memory], is_match: fn(&str, u32, u32) -> bool) -> Result<u32, AppErr> {
+
+    let facts = {
+        let mut h = HashMap::new();
+        h.insert("children", 3);
+        h.insert("cats", 7);
+        h.insert("samoyeds", 2);
+        h.insert("pomeranians", 3);
+        h.insert("akitas", 0);
+        h.insert("vizslas", 0);
+        h.insert("goldfish", 5);
+        h.insert("trees", 3);
+        h.insert("cars", 2);
+        h.insert("perfumes", 1);
+        h
+    };
+
+    //Compare facts and memories to filter out invalid Aunts
+
+    let best_match =
+        memories.iter()
+                .find(|mem| {
+                    // For each memory of this aunt...
+                    for (key, &memory) in mem.things.iter() {
+                        let key = key.as_str();
+                        // ...compare it with the facts
+                        let fact = *facts.get(key).unwrap();
+                        if !is_match(key, memory, fact) {
+                            return false;
+                        }
+                    }
+                    true
+                })
+                .ok_or_else(|| AppErr::no_solution("No aunt matches"))?;
+
+    Ok(best_match.number)
+}
+
+pub struct Day16;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 16, solver: &Day16 };
+
+impl Solution for Day16 {
+    type Model<'a> = Vec<Memory>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Memory>, AppErr> {
+        parse_lines(input, parse_memory)
+    }
+
+    // Best aunt match
+    fn part1(&self, memories: &Vec<Memory>) -> Result<Answer, AppErr> {
+        let number = find_aunt(memories, |_, memory, fact| memory == fact)?;
+        Ok(number.into())
+    }
+
+    fn part2(&self, memories: &Vec<Memory>) -> Result<Answer, AppErr> {
+        let number = find_aunt(memories, |key, memory, fact| {
+            match key {
+                "cats"        | "trees"    => memory > fact,
+                "pomeranians" | "goldfish" => memory < fact,
+                _                          => memory == fact
+            }
+        })?;
+        Ok(number.into())
+    }
+}
@@ -3,9 +3,9 @@ pub fn combinations<T>(pool: Vec<T>, r: usize) -> Combinations<T>
     where T: Copy
 {
     Combinations {
-        pool: pool,
+        pool,
         indices: (0..r).collect(),
-        r: r,
+        r,
         state: 0
     }
 }
@@ -0,0 +1,81 @@
+extern crate util;
+
+mod combinations;
+
+use combinations::combinations;
+
+use util::error::AppErr;
+use util::params::Param;
+use util::solution::{ Answer, Registration, Solution };
+
+const CONTAINERS: Param = Param::new("containers", "33,14,18,20,45,35,16,35,1,13,18,13,50,44,48,6,24,41,30,42", "The size of each container, separated by commas");
+const LITRES: Param = Param::new("litres", "150", "The amount of eggnog to store");
+
+fn find_all_sets() -> Result<Vec<Vec<u32>>, AppErr> {
+
+    let target = LITRES.parse::<u32>()?;
+    let input = CONTAINERS.value()
+        .split(',')
+        .map(|size| size.trim().parse::<u32>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| AppErr::usage("expected the containers to be sizes separated by commas"))?;
+
+    let mut all_sets = Vec::new();
+
+    for size in 2 .. input.len() {
+        for set in combinations(input.clone(), size) {
+
+            let sum: u32 = set.iter().sum();
+            if sum == target {
+                all_sets.push(set);
+            }
+        }
+    }
+
+    Ok(all_sets)
+}
+
+pub struct Day17;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 17, solver: &Day17 };
+
+impl Solution for Day17 {
+    // The containers are a parameter of the puzzle
+    type Model<'a> = ();
+
+    const PARAMS: &'static [Param] = &[CONTAINERS, LITRES];
+
+    fn parse(&self, _input: &str) -> Result<(), AppErr> {
+        Ok(())
+    }
+
+    // Count
+    fn part1(&self, _: &()) -> Result<Answer, AppErr> {
+        let all_sets = find_all_sets()?;
+
+        Ok(all_sets.len().into())
+    }
+
+    // Short set count
+    fn part2(&self, _: &()) -> Result<Answer, AppErr> {
+        let all_sets = find_all_sets()?;
+
+        let shortest_len = all_sets.iter().map(|s| s.len()).min()
+            .ok_or_else(|| AppErr::no_solution("No sets of containers"))?;
+
+        let short_set_count = all_sets.iter().filter(|s| s.len() == shortest_len).count();
+
+        Ok(short_set_count.into())
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+    use util::aoc_example;
+
+    #[test]
+    fn example() {
+        aoc_example!("", params(containers = "20,15,10,5,5", litres = 25), part1 = 4, part2 = 3);
+    }
+}
@@ -0,0 +1,152 @@
+extern crate util;
+
+use util::error::AppErr;
+use util::params::Param;
+use util::solution::{ Answer, Registration, Solution };
+
+const STEPS: Param = Param::new("steps", "100", "The number of steps to animate");
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Live,
+    Dead
+}
+
+// A terminal world - cells beyond the edge of the grid are dead
+#[derive(Clone)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>
+}
+
+impl Grid {
+    fn cell(&self, x: usize, y: usize) -> Cell {
+        self.cells[y * self.width + x]
+    }
+
+    fn set_cell(&mut self, x: usize, y: usize, cell: Cell) {
+        self.cells[y * self.width + x] = cell;
+    }
+
+    fn live_neighbours(&self, x: usize, y: usize) -> usize {
+        let mut count = 0;
+        for ny in y.saturating_sub(1) ..= (y + 1).min(self.height - 1) {
+            for nx in x.saturating_sub(1) ..= (x + 1).min(self.width - 1) {
+                if (nx, ny) != (x, y) && self.cell(nx, ny) == Cell::Live {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    fn step(&self) -> Grid {
+        let mut next = self.clone();
+        for y in 0 .. self.height {
+            for x in 0 .. self.width {
+                let cell = match (self.cell(x, y), self.live_neighbours(x, y)) {
+                    (Cell::Live, 2) | (Cell::Live, 3) => Cell::Live,
+                    (Cell::Dead, 3)                   => Cell::Live,
+                    _                                 => Cell::Dead,
+                };
+                next.set_cell(x, y, cell);
+            }
+        }
+        next
+    }
+
+    fn live_cell_count(&self) -> usize {
+        self.cells.iter().filter(|&&cell| cell == Cell::Live).count()
+    }
+}
+
+fn read_input(input: &str) -> Result<Grid, AppErr> {
+
+    let char_to_cell = |c| {
+        match c {
+            '#' => Some(Cell::Live),
+            '.' => Some(Cell::Dead),
+             _  => None
+        }
+    };
+
+    let cells: Vec<Cell> =
+        input.chars()
+             .filter_map(char_to_cell)
+             .collect();
+
+    //Expecting a rectangular grid, which is 100x100 for the puzzle
+    let width = input.lines().next().map_or(0, |line| line.trim().len());
+    let height = input.lines().filter(|line| !line.trim().is_empty()).count();
+    if width == 0 || cells.len() != width * height {
+        return Err(AppErr::new("Input", &format!("Expected a {}x{} grid, found {} cells", width, height, cells.len())));
+    }
+
+    Ok(Grid { width, height, cells })
+}
+
+fn reset_corners(grid: &mut Grid) {
+    // Find the x, y coordinates of the bottom right corner
+    let x_last = grid.width - 1;
+    let y_last = grid.height - 1;
+
+    // Resurrect the four corner cells
+    grid.set_cell(0,      0,      Cell::Live);
+    grid.set_cell(x_last, 0,      Cell::Live);
+    grid.set_cell(0,      y_last, Cell::Live);
+    grid.set_cell(x_last, y_last, Cell::Live);
+}
+
+pub struct Day18;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 18, solver: &Day18 };
+
+impl Solution for Day18 {
+    type Model<'a> = Grid;
+
+    const PARAMS: &'static [Param] = &[STEPS];
+
+    fn parse(&self, input: &str) -> Result<Grid, AppErr> {
+        read_input(input)
+    }
+
+    // Lights on after 100 iterations
+    fn part1(&self, initial_grid: &Grid) -> Result<Answer, AppErr> {
+        let iterations = STEPS.parse::<usize>()?;
+        let mut grid = initial_grid.clone();
+
+        for _ in 0 .. iterations {
+            grid = grid.step();
+        }
+
+        Ok(grid.live_cell_count().into())
+    }
+
+    // Part 2 - Keep the four corners turned on
+    fn part2(&self, initial_grid: &Grid) -> Result<Answer, AppErr> {
+        let iterations = STEPS.parse::<usize>()?;
+        let mut grid = initial_grid.clone();
+
+        for _ in 0 .. iterations {
+            reset_corners(&mut grid);
+            grid = grid.step();
+            reset_corners(&mut grid);
+        }
+
+        Ok(grid.live_cell_count().into())
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+    use util::aoc_example;
+
+    #[test]
+    fn example() {
+        let input = include_str!("../tests/fixtures/part1-1.txt");
+        aoc_example!(input, params(steps = 4), part1 = 4);
+        aoc_example!(input, params(steps = 5), part2 = 17);
+    }
+}
@@ -0,0 +1,95 @@
+extern crate util;
+
+use std::str::FromStr;
+use std::cmp::min;
+
+use util::error::{ AppErr, Context };
+use util::input::lines;
+use util::solution::{ Answer, Registration, Solution };
+
+pub struct Box { l: u32, w: u32, h: u32 }
+
+impl Box {
+    fn top(&self)             -> u32 { self.l *     self.w     }
+    fn top_perimeter(&self)   -> u32 { self.l * 2 + self.w * 2 }
+    
+    fn side(&self)            -> u32 { self.l *     self.h     }
+    fn side_perimeter(&self)  -> u32 { self.l * 2 + self.h * 2 }
+    
+    fn front(&self)           -> u32 { self.w *     self.h     }
+    fn front_perimeter(&self) -> u32 { self.w * 2 + self.h * 2 }
+    
+    fn perimeter_of_smallest_side(&self) -> u32 {
+        min(min(self.top_perimeter(), self.front_perimeter()), self.side_perimeter())
+    }
+    
+    fn surface_area_of_smallest_side(&self) -> u32 {
+        min(min(self.top(), self.front()), self.side())
+    }
+    
+    fn surface_area(&self) -> u32 {
+        2 * self.top() + 
+        2 * self.side() + 
+        2 * self.front()
+    }
+    
+    fn volume(&self) -> u32 {
+        self.l * self.w * self.h
+    }
+}
+
+impl FromStr for Box {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Box, Self::Err> {
+        let mut iter = s.split('x').map(|i| i.parse());
+        
+        let l = match iter.next() { Some(Ok(c)) => c, _ => return Err("Expected `LxWxH`") };
+        let w = match iter.next() { Some(Ok(c)) => c, _ => return Err("Expected `LxWxH`") };
+        let h = match iter.next() { Some(Ok(c)) => c, _ => return Err("Expected `LxWxH`") };
+        
+        if iter.next().is_some() {
+            return Err("Too many parts");
+        }
+        
+        Ok(Box { l, w, h })
+    }
+}
+
+pub struct Day2;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 2, solver: &Day2 };
+
+impl Solution for Day2 {
+    type Model<'a> = Vec<Box>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Box>, AppErr> {
+        // Blank lines are skipped
+        lines(input)
+            .filter(|&(_, line)| !line.is_empty())
+            .map(|(number, line)| line.parse().with_context(|| format!("failed to parse line {}: {:?}", number, line)))
+            .collect()
+    }
+
+    //Find the surface area of the box, which is 2*l*w + 2*w*h + 2*h*l.
+    //The elves also need a little extra paper for each present: the area of the smallest side.
+    fn part1(&self, boxes: &Vec<Box>) -> Result<Answer, AppErr> {
+        let total_square_footage_needed: u32 =
+            boxes.iter()
+                 .map(|b| b.surface_area() + b.surface_area_of_smallest_side())
+                 .sum();
+
+        Ok(total_square_footage_needed.into())
+    }
+
+    //Each present also requires a bow made out of ribbon as well;
+    //The ribbon required to wrap a present is the shortest distance around its sides, or the smallest perimeter of any one face. 
+    //The feet of ribbon required for the perfect bow is equal to the cubic feet of volume of the present.
+    fn part2(&self, boxes: &Vec<Box>) -> Result<Answer, AppErr> {
+        let total_feet_of_ribbon_needed: u32 =
+            boxes.iter()
+                 .map(|b| b.perimeter_of_smallest_side() + b.volume())
+                 .sum();
+
+        Ok(total_feet_of_ribbon_needed.into())
+    }
+}
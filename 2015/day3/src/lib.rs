@@ -0,0 +1,90 @@
+extern crate util;
+
+use std::collections::HashMap;
+use std::mem::swap;
+
+use util::error::AppErr;
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Up, Down, Left, Right
+}
+
+#[derive(Hash, PartialEq, Eq, Clone, Copy)]
+struct Coord(i32, i32);
+
+impl Coord {
+    fn update_with_instruction(&mut self, inst: Instruction) {
+        match inst {
+            Up    => self.1 += 1,
+            Down  => self.1 -= 1,
+            Left  => self.0 -= 1,
+            Right => self.0 += 1,
+        }
+    }
+}
+
+use Instruction::{ Up, Down, Left, Right };
+
+fn read_instructions(input: &str) -> Vec<Instruction> {
+    input.chars()
+        .filter_map(|c| match c {
+            '^' => Some(Up),
+            'v' => Some(Down),
+            '<' => Some(Left),
+            '>' => Some(Right),
+             _  => None
+        })
+        .collect()
+}
+
+pub struct Day3;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 3, solver: &Day3 };
+
+impl Solution for Day3 {
+    type Model<'a> = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>, AppErr> {
+        Ok(read_instructions(input))
+    }
+
+    fn part1(&self, instructions: &Vec<Instruction>) -> Result<Answer, AppErr> {
+        // Count houses visited by santa alone
+        let mut houses = HashMap::new();
+        let mut santa_pos = Coord(0, 0);
+
+        for inst in instructions {
+            *houses.entry(santa_pos).or_insert(0) += 1;
+            santa_pos.update_with_instruction(*inst);
+        }
+
+        let count_of_houses_visited = houses.keys().count();
+        Ok(count_of_houses_visited.into())
+    }
+
+    fn part2(&self, instructions: &Vec<Instruction>) -> Result<Answer, AppErr> {
+        // Count houses visited by santa and robo santa
+        let mut houses = HashMap::new();
+        let mut pos = Coord(0, 0);
+
+        //First house is always visited twice
+        houses.insert(pos, 2);
+
+        // pos and other track the positions of santa and robo santa
+        let mut other = pos;
+
+        for &inst in instructions {
+            // Give the instruction to the first worker
+            pos.update_with_instruction(inst);
+            *houses.entry(pos).or_insert(0) += 1;
+
+            // And swap the workers
+            swap(&mut pos, &mut other);
+        }
+
+        let count_of_houses_visited = houses.keys().count();
+        Ok(count_of_houses_visited.into())
+    }
+}
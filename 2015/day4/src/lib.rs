@@ -0,0 +1,69 @@
+extern crate md5;
+extern crate util;
+
+use std::io::Write;
+
+use util::error::AppErr;
+use util::solution::{ Answer, Registration, Solution };
+
+fn to_hex(input: &[u8]) -> String {
+    let mut s = String::new();
+    for i in input {
+        let octet = format!("{:0>2x}", i);
+        s.push_str(&octet);
+    }
+    s
+}
+
+fn make_sequence(secret_key: &str) -> impl Iterator<Item=(u32, String)> + '_ {
+    let mut buf = Vec::new();
+    (0..).map(move |number| {
+
+        //write! writes utf8 bytes into the buffer
+        //Assumption: bytes will be in the ASCII range only
+        buf.clear();
+        write!(&mut buf, "{}{}", secret_key, number).expect("Error writing input");
+
+        let hash = md5::compute(&buf);
+
+        (number, to_hex(&*hash))
+    })
+}
+
+fn find_number_with_prefix(secret_key: &str, prefix: &str) -> Result<u32, AppErr> {
+    // The search takes millions of hashes, so check for cancellation now and then
+    for (number, hash) in make_sequence(secret_key) {
+        if number.is_multiple_of(1 << 16) {
+            util::cancel::check()?;
+        }
+        if hash.starts_with(prefix) {
+            return Ok(number);
+        }
+    }
+    Err(AppErr::no_solution("Sequence ended"))
+}
+
+pub struct Day4;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 4, solver: &Day4 };
+
+impl Solution for Day4 {
+    type Model<'a> = &'a str;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<&'a str, AppErr> {
+        let secret_key = input.lines()
+            .nth(0)
+            .ok_or("No lines in input")?;
+        Ok(secret_key.trim())
+    }
+
+    // Smallest number whose md5 starts with five zeroes
+    fn part1(&self, secret_key: &&str) -> Result<Answer, AppErr> {
+        Ok(find_number_with_prefix(secret_key, "00000")?.into())
+    }
+
+    // Smallest number whose md5 starts with six zeroes
+    fn part2(&self, secret_key: &&str) -> Result<Answer, AppErr> {
+        Ok(find_number_with_prefix(secret_key, "000000")?.into())
+    }
+}
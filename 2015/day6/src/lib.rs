@@ -0,0 +1,191 @@
+extern crate util;
+
+use std::str::FromStr;
+use std::mem::swap;
+
+use util::error::AppErr;
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+// Coord(x, y)
+#[derive(Clone, Copy, Debug)]
+struct Coord(usize, usize);
+
+impl Coord {
+    fn to_index(self, stride: usize) -> usize {
+        self.1 * stride + self.0
+    }
+}
+
+impl FromStr for Coord {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Coord, Self::Err> {
+        //Consume a string of the form '489,959'
+        let mut parts = s.split(',').map(|s| s.parse());
+        let x = match parts.next() { Some(Ok(x)) => x, _ => return Err("Error parsing x coord") };
+        let y = match parts.next() { Some(Ok(y)) => y, _ => return Err("Error parsing y coord") };
+        if parts.next().is_some() {
+            return Err("Too many parts");
+        }
+        Ok(Coord(x, y))
+    }
+}
+
+#[derive(Debug)]
+enum Action {
+    Toggle,
+    TurnOn,
+    TurnOff
+}
+
+#[derive(Debug)]
+pub struct Command {
+    action: Action,
+    from: Coord,
+    to: Coord
+}
+
+impl FromStr for Command {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Command, Self::Err> {
+        // Consume a string of the form 'turn on 489,959 through 759,964'
+        // e.g. turn on 489,959 through 759,964
+        //      turn off 820,516 through 871,914
+        let mut parts = s.split(" ");
+        
+        //Expect `turn`
+        let action =
+            match parts.next() {
+                Some("turn") => {
+                    match parts.next() {
+                        Some("on") => Action::TurnOn,
+                        Some("off") => Action::TurnOff,
+                        _ => return Err("Expected `on` or `off`") 
+                    }
+                },
+                Some("toggle") => {
+                    Action::Toggle
+                }
+                _ => {
+                    return Err("Expected `turn` or `toggle`")
+                }
+            };
+            
+        //Expect range
+        let from = match parts.next().map(|s| s.parse()) { 
+            Some(Ok(r))  => r, 
+            Some(Err(e)) => return Err(e),
+            _            => return Err("Expected range start")
+        };
+            
+        //Expect `through`
+        match parts.next() {
+            Some("through") => (), 
+            _ => return Err("Expected `through`")
+        };
+            
+        //Expect range
+        let to = match parts.next().map(|s| s.parse()) { 
+            Some(Ok(r))  => r,
+            Some(Err(e)) => return Err(e),
+            _            => return Err("Expected range to")
+        };
+        
+        if parts.next().is_some() {
+            return Err("Expected end of input");
+        }
+        
+        Ok(Command {
+            action,
+            to,
+            from
+        })
+    }
+}
+
+type Light = u32;
+
+struct LightGrid {
+    stride: usize,
+    lights: Vec<Light>
+}
+
+impl LightGrid {
+    fn new(w: usize, h: usize) -> LightGrid {
+        LightGrid { stride: w, lights: vec![0; w * h] }
+    }
+    
+    fn get_light_mut(&mut self, coord: Coord) -> &mut Light {
+        let index = coord.to_index(self.stride);
+        &mut self.lights[index]
+    }
+    
+    fn get_all(&self) -> &[Light] {
+        &self.lights[..]
+    }
+}
+
+fn total_brightness(commands: &[Command], apply: fn(&mut Light, &Action)) -> u32 {
+
+    let mut lights = LightGrid::new(1000, 1000);
+
+    for command in commands {
+
+        //Command from, to describes a rectangle
+        let Coord(mut x1, mut y1) = command.from;
+        let Coord(mut x2, mut y2) = command.to;
+
+        if x2 < x1  { swap(&mut x1, &mut x2); }
+        if y2 < y1  { swap(&mut y1, &mut y2); }
+
+        for y in y1..y2 + 1 {
+            for x in x1..x2 + 1 {
+                apply(lights.get_light_mut(Coord(x, y)), &command.action);
+            }
+        }
+    }
+
+    lights.get_all().iter().copied().sum()
+}
+
+pub struct Day6;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 6, solver: &Day6 };
+
+impl Solution for Day6 {
+    type Model<'a> = Vec<Command>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Command>, AppErr> {
+        parse_lines(input, |line| line.parse())
+    }
+
+    // Lights are either on or off
+    fn part1(&self, commands: &Vec<Command>) -> Result<Answer, AppErr> {
+        let lights_lit = total_brightness(commands, |light, action| {
+            match action {
+                Action::TurnOn => *light = 1,
+                Action::Toggle => *light = 1 - *light,
+                Action::TurnOff => *light = 0,
+            };
+        });
+
+        Ok(lights_lit.into())
+    }
+
+    // Lights have a brightness
+    fn part2(&self, commands: &Vec<Command>) -> Result<Answer, AppErr> {
+        let sum_of_all_light = total_brightness(commands, |light, action| {
+            match action {
+                Action::TurnOn => *light += 1,
+                Action::Toggle => *light += 2,
+                Action::TurnOff => {
+                    if *light > 0 {
+                        *light -= 1;
+                    }
+                }
+            };
+        });
+
+        Ok(sum_of_all_light.into())
+    }
+}
@@ -1,14 +1,12 @@
-extern crate combine;
+extern crate util;
 
 pub mod ast;
 pub mod parser;
 
-use combine::{ parser, Parser };
+use util::error::AppErr;
+use util::parse::{ Input, ParseResultEx };
 use parser::{ p_inst };
 
-pub fn parse_instruction(s: &str) -> Result<ast::Instruction, String> {
-    match parser(p_inst).parse(s) {
-        Ok((result, _)) => Ok(result),
-        Err(err)        => Err(format!("{}", err))
-    }
-}
\ No newline at end of file
+pub fn parse_instruction(s: &str) -> Result<ast::Instruction, AppErr> {
+    p_inst(Input::new(s)).complete()
+}
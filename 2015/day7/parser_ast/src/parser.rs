@@ -1,98 +1,63 @@
-use combine::primitives::{ Stream, State };
-use combine::{ Parser, ParserExt, ParseResult };
-use combine::{ spaces, many1, digit, lower, string, choice, try, parser };
+use util::parse::{ Input, ParseErr, ParseResult, ParseResultEx };
 
-use ast;
+use crate::ast;
 
-pub fn p_label<I>(input: State<I>) -> ParseResult<ast::Label, I>
-    where I: Stream<Item=char>
-{
-    let label = many1(lower()).message("Label");
-    
-    spaces().with(label).parse_state(input)
+pub fn p_label(input: Input) -> ParseResult<ast::Label> {
+    let (next, label) = input.parse_alpha()?;
+    if !label.chars().all(|c| c.is_lowercase()) {
+        return Err(ParseErr::invalid_input(input.snapshot(), "Label"));
+    }
+    Ok((next, label.to_string()))
 }
 
-pub fn p_signal<I>(input: State<I>) -> ParseResult<ast::Signal, I>
-    where I: Stream<Item=char>
-{
-    let signal =
-        many1(digit())
-            .map(|val: String| val.parse::<ast::Signal>().unwrap())
-            .message("Raw Signal");
-    
-    spaces().with(signal).parse_state(input)
+pub fn p_signal(input: Input) -> ParseResult<ast::Signal> {
+    let (next, signal) = input.parse_numeric()?;
+    let signal = signal.parse::<ast::Signal>()
+        .map_err(|_| ParseErr::invalid_input(input.snapshot(), "Raw Signal"))?;
+    Ok((next, signal))
 }
 
-pub fn p_source<I>(input: State<I>) -> ParseResult<ast::Source, I>
-    where I: Stream<Item=char>
-{
-    let label = parser(p_label).map(|s| ast::Source::Wire(s));
-    let source = parser(p_signal).map(|s| ast::Source::Const(s));
-    
-    try(label).or(source).parse_state(input)
+pub fn p_source(input: Input) -> ParseResult<ast::Source> {
+    p_label(input).map_val(ast::Source::Wire)
+        .or_try(|| p_signal(input).map_val(ast::Source::Const))
 }
 
-pub fn p_gate1<I>(input: State<I>) -> ParseResult<ast::Gate1, I>
-    where I: Stream<Item=char>
-{
-    let choice =
-        string("NOT").map(|_| ast::Gate1::NOT);
-        
-    spaces().with(choice).parse_state(input)
+pub fn p_gate1(input: Input) -> ParseResult<ast::Gate1> {
+    input.parse_token("NOT").val(ast::Gate1::NOT)
 }
 
-pub fn p_gate2<I>(input: State<I>) -> ParseResult<ast::Gate2, I>
-    where I: Stream<Item=char>
-{
-    let choice =
-        choice([
-            string("AND"),
-            string("OR"),
-            string("LSHIFT"),
-            string("RSHIFT"),
-        ])
-        .map(|label| match label {
-            "AND"    => ast::Gate2::AND,
-            "OR"     => ast::Gate2::OR,
-            "LSHIFT" => ast::Gate2::LSHIFT,
-            "RSHIFT" => ast::Gate2::RSHIFT,
-            _        => panic!("p_gate2")
-        });
-        
-    spaces().with(choice).parse_state(input)
+pub fn p_gate2(input: Input) -> ParseResult<ast::Gate2> {
+    input.parse_token("AND").val(ast::Gate2::AND)
+        .or_try(|| input.parse_token("OR").val(ast::Gate2::OR))
+        .or_try(|| input.parse_token("LSHIFT").val(ast::Gate2::LSHIFT))
+        .or_try(|| input.parse_token("RSHIFT").val(ast::Gate2::RSHIFT))
 }
 
-pub fn p_expr<I>(input: State<I>) -> ParseResult<ast::Expr, I>
-    where I: Stream<Item=char>
-{
-    let gate2  =
-        parser(p_source).and(parser(p_gate2)).and(parser(p_source))
-            .map(|((source1, gate), source2)| ast::Expr::Gate2(gate, source1, source2))
-            .message("Two-input Gate");
-            
-    let gate1 =
-        parser(p_gate1).and(parser(p_source))
-            .map(|(gate, source)| ast::Expr::Gate1(gate, source))
-            .message("One-input Gate");
-            
-    let source =
-        parser(p_source)
-            .map(|source| ast::Expr::Input(source));
-    
-    let match_one = try(gate2).or(try(gate1)).or(source);
-    
-    spaces().with(match_one).parse_state(input)
+pub fn p_expr(input: Input) -> ParseResult<ast::Expr> {
+    let gate2 = || {
+        let (input, source1) = p_source(input)?;
+        let (input, gate) = p_gate2(input)?;
+        let (input, source2) = p_source(input)?;
+        Ok((input, ast::Expr::Gate2(gate, source1, source2)))
+    };
+
+    let gate1 = || {
+        let (input, gate) = p_gate1(input)?;
+        let (input, source) = p_source(input)?;
+        Ok((input, ast::Expr::Gate1(gate, source)))
+    };
+
+    let source = || {
+        p_source(input).map_val(ast::Expr::Input)
+    };
+
+    gate2().or_try(gate1).or_try(source)
 }
 
-pub fn p_inst<I>(input: State<I>) -> ParseResult<ast::Instruction, I>
-    where I: Stream<Item=char>
-{
-    let arrow = spaces().with(string("->"));
-    let target = spaces().with(parser(p_label));
-    
-    let mut expr =
-        parser(p_expr).skip(arrow).and(target)
-            .map(|(e, t)| ast::Instruction { expr: e, target: t });
-            
-    expr.parse_state(input)
-}
\ No newline at end of file
+pub fn p_inst(input: Input) -> ParseResult<ast::Instruction> {
+    let (input, expr) = p_expr(input)?;
+    let (input, ()) = input.parse_token_sequence(["-", ">"])?;
+    let (input, target) = p_label(input)?;
+    let (input, ()) = input.parse_end()?;
+    Ok((input, ast::Instruction { expr, target }))
+}
@@ -0,0 +1,124 @@
+extern crate parser_ast;
+extern crate util;
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry::*;
+
+use parser_ast::ast::*;
+use parser_ast::parse_instruction;
+
+use util::error::AppErr;
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Debug, Clone)]
+pub struct Wire {
+    source: Expr,
+    value: Option<Signal>
+}
+
+pub struct Day7;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 7, solver: &Day7 };
+
+impl Solution for Day7 {
+    // Map of wire to their labels
+    type Model<'a> = HashMap<Label, Wire>;
+
+    fn parse(&self, input: &str) -> Result<HashMap<Label, Wire>, AppErr> {
+        let instructions = parse_lines(input, parse_instruction)?;
+
+        let mut map = HashMap::new();
+
+        for inst in instructions {
+            match map.entry(inst.target.clone()) {
+                Occupied(_) => return Err(AppErr::new("Wire", &format!("Attempted to redefine wire {}", &inst.target))),
+                Vacant(e) => {
+                    e.insert(Wire {
+                        source: inst.expr,
+                        value: None
+                    });
+                }
+            };
+        }
+
+        Ok(map)
+    }
+
+    // Signal on wire a
+    fn part1(&self, map: &HashMap<Label, Wire>) -> Result<Answer, AppErr> {
+        let mut map = map.clone();
+        let signal = resolve_recusive(&mut map, "a")?;
+        Ok(signal.into())
+    }
+
+    // Part two:
+    // Now, take the signal you got on wire a, override wire b to that signal,
+    // and reset the other wires (including wire a).
+    // What new signal is ultimately provided to wire a?
+    fn part2(&self, map: &HashMap<Label, Wire>) -> Result<Answer, AppErr> {
+        let mut map = map.clone();
+        let signal = resolve_recusive(&mut map, "a")?;
+
+        for (key, wire) in map.iter_mut() {
+            wire.value = match &key[..] {
+               "b" => Some(signal),
+                _  => None 
+            }     
+        }
+
+        let signal = resolve_recusive(&mut map, "a")?;
+        Ok(signal.into())
+    }
+}
+
+fn resolve_recusive(map: &mut HashMap<Label, Wire>, label: &str) -> Result<Signal, AppErr> {
+    
+    let expr = {
+        
+        let wire = map.get(label).ok_or_else(|| AppErr::new("Wire", &format!("Cannot find wire {}", label)))?;
+        
+        //Already worked out the value of this wire?
+        if let Some(ref val) = wire.value {
+            return Ok(*val);
+        }
+        
+        wire.source.clone()
+    };
+    
+    // Resolve the value of the expression attached to this wire.
+    let signal = match expr {
+        Expr::Input(src) => {
+            resolve_source(map, &src)?
+        },
+        Expr::Gate2(gate, a, b) => {
+            let a = resolve_source(map, &a)?;
+            let b = resolve_source(map, &b)?;
+            match gate {
+                Gate2::AND    => a & b, 
+                Gate2::OR     => a | b,
+                Gate2::RSHIFT => a >> b,
+                Gate2::LSHIFT => a << b
+            }
+        },
+        Expr::Gate1(gate, a) => {
+            let a = resolve_source(map, &a)?;
+            match gate {
+                Gate1::NOT => !a
+            }
+        }
+    };
+    
+    // Cache it
+    map.get_mut(label).unwrap().value = Some(signal);
+    
+    // Done
+    Ok(signal)
+}
+
+fn resolve_source(map: &mut HashMap<Label, Wire>, source: &Source) -> Result<Signal, AppErr> {
+    match source {
+        Source::Const(s) => Ok(*s),
+        Source::Wire(label) => resolve_recusive(map, label)
+    }
+}
@@ -0,0 +1,149 @@
+extern crate util;
+
+use util::error::AppErr;
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+pub struct StringInfo {
+    source: String,
+    parsed: String
+}
+
+fn hex_octet(a: char, b: char) -> Option<u8> {
+    
+    fn hex_char(c: char) -> Option<u8> {
+        match c {
+            '0'..='9' => Some(c as u8 - b'0'),
+            'a'..='f' => Some(c as u8 - b'a' + 10),
+            'A'..='F' => Some(c as u8 - b'A' + 10),
+            _         => None
+        }
+    }
+    
+    hex_char(a).and_then(|a| hex_char(b).map(|b| (16 * a) + b))
+}
+
+fn parse_quoted_string(source: String) -> Result<StringInfo, String> {
+    
+    let parsed = {
+        let mut chars = source.chars();
+        
+        //Consume the first quote character up front
+        match chars.next() {
+            Some('"') => {
+                //OK!
+            },
+            Some(c) => return Err(format!("Expected '\"', found '{}'", c)),
+            None    => return Err("Expected character, found nothing".to_string())
+        }
+        
+        let mut parsed = String::new();
+        let mut found_closing_quote = false;
+        
+        //Parse the source string one char at a time
+        while let Some(c) = chars.next() {
+        
+            match c {
+                '"' => {
+                    found_closing_quote = true;
+                    break;
+                },
+                '\\' => {
+                    //Read escape sequence
+                    match chars.next() {
+                        Some('"')  => { parsed.push('"'); },
+                        Some('\\') => { parsed.push('\\'); },
+                        Some('x')  => {
+                            //Read the next two characters from the stream
+                            let next_two = chars.next().and_then(|a| chars.next().map(|b| (a, b)));
+                            //Parse them as a Hex octet
+                            let octet = match next_two {
+                                Some((a, b)) => hex_octet(a, b),
+                                None         => return Err("Expected ASCII escape sequence, found nothing".into())
+                            };
+                            match octet {
+                                Some(c) => { parsed.push(c as char); },
+                                None    => return Err("Invalid ASCII escape sequence".into())
+                            };
+                        },
+                        Some(c) => return Err(format!("Invalid escape sequence: \\{}", c)),
+                        None    => return Err("Expected character, found end-of-input".to_string())
+                    }
+                },
+                c => parsed.push(c)
+            }
+        }
+        
+        match chars.next() {
+            Some(c)                      => return Err(format!("Expected end-of-input, found '{}'", c)),
+            None if !found_closing_quote => return Err("Expected '\"', found end-of-input".to_string()),
+            None => {
+                //Ok!
+            }
+        }
+        
+        parsed
+    };
+    
+    Ok(StringInfo { source, parsed })
+}
+
+fn encode_string(s: &str) -> String {
+    let mut buf = String::new();
+    
+    buf.push('"');
+    
+    for char in s.chars() {
+        match char {
+            '\"'         => buf.push_str("\\\""),
+            '\\'         => buf.push_str("\\\\"),
+            c => buf.push(c),
+        }
+    }
+    
+    buf.push('"');
+    buf
+}
+
+pub struct Day8;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 8, solver: &Day8 };
+
+impl Solution for Day8 {
+    type Model<'a> = Vec<StringInfo>;
+
+    fn parse(&self, input: &str) -> Result<Vec<StringInfo>, AppErr> {
+        parse_lines(input, |line| parse_quoted_string(line.to_string()))
+    }
+
+    // Disregarding the whitespace in the file, what is the number of characters of code 
+    // for string literals minus the number of characters in memory for the values of the
+    // strings in total for the entire file?
+    fn part1(&self, lines: &Vec<StringInfo>) -> Result<Answer, AppErr> {
+        let mut weird_sum = 0;
+
+        for line in lines {
+            let source_chars = line.source.chars().count();
+            let parsed_chars = line.parsed.chars().count();
+
+            weird_sum += source_chars - parsed_chars;
+        }
+
+        Ok(weird_sum.into())
+    }
+
+    fn part2(&self, lines: &Vec<StringInfo>) -> Result<Answer, AppErr> {
+        let mut other_weird_sum = 0;
+
+        for line in lines {
+            let source_chars = line.source.chars().count();
+
+            let double_encoded = encode_string(&line.source);
+            let double_encoded_chars = double_encoded.chars().count();
+
+            other_weird_sum += double_encoded_chars - source_chars;
+        }
+
+        Ok(other_weird_sum.into())
+    }
+}
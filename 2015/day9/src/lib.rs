@@ -0,0 +1,127 @@
+extern crate util;
+
+use std::collections::HashMap;
+
+use util::error::AppErr;
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+type KMs = u32;
+
+#[derive(Debug)]
+struct Distance {
+    from: String,
+    to: String,
+    distance: KMs
+}
+
+fn parse_distance(s: &str) -> Result<Distance, String> {
+    let mut parts = s.split(" ");
+    let from = match parts.next() { Some(s) => s.into(),  o => return Err(format!("Expected FROM, got {:?}", o)) };
+               match parts.next() { Some("to") => { },    o => return Err(format!("Expected `to`, got {:?}", o)) };
+    let to =   match parts.next() { Some(s) => s.into(),  o => return Err(format!("Expected TO, got {:?}", o))   };
+               match parts.next() { Some("=") =>  { },    o => return Err(format!("Expected `=`, got {:?}", o))  };
+    let dist = match parts.next() { Some(s) => s.parse(), o => return Err(format!("Expected DIST, got {:?}", o)) };
+    
+    let dist = match dist { Ok(d) => d, Err(e) => return Err(format!("Error parsing distance: {}", e)) };
+    
+    Ok(Distance { from, to, distance: dist })
+}
+
+pub type Map = HashMap<String, City>; 
+
+#[derive(Debug)]
+pub struct City {
+    distances: HashMap<String, KMs>
+}
+
+fn build_map(distances: Vec<Distance>) -> Map {
+    
+    let mut map = HashMap::new();
+    
+    fn update_map(map: &mut Map, from: &str, to: &str, dist: KMs) {
+        let city = map.entry(from.into()).or_insert_with(|| City { distances: HashMap::new() });
+        city.distances.insert(to.into(), dist);
+    }
+    
+    for dist in distances {
+        //Locate an existing cities in the map and update them
+        update_map(&mut map, &dist.from, &dist.to, dist.distance);
+        update_map(&mut map, &dist.to, &dist.from, dist.distance);
+    }
+    
+    map
+}
+
+fn nearest_neighbour<'a>(map: &'a Map, city_name: &'a str, visited: &Vec<&'a str>) -> Option<(&'a str, KMs)> {
+    
+    let mut nearest: Option<(&'a str, KMs)> = None;
+    
+    for (name, city) in map.iter() {
+        if name == city_name || visited.contains(&&name[..]) {
+            continue;
+        }
+        
+        if let Some(&dist) = city.distances.get(city_name) {
+            
+            match nearest {
+                Some((_, nearest_dist)) if dist < nearest_dist => {
+                    nearest = Some((&name[..], dist));
+                },
+                None => {
+                    nearest = Some((&name[..], dist));
+                },
+                _ => {
+                    
+                }
+            }
+        }
+    }
+    
+    nearest
+}
+
+fn find_shortest_route_starting_from<'a>(map: &'a Map, start: &'a str) -> (Vec<&'a str>, KMs) {
+    
+    let mut route = vec![start];
+    let mut current = start;
+    let mut length = 0;
+    
+    while let Some((next, dist)) = nearest_neighbour(map, current, &route) {
+        
+        current = next;
+        length += dist;
+        route.push(current);
+    }
+    
+    (route, length)
+}
+
+pub struct Day9;
+
+pub const SOLUTION: Registration = Registration { year: 2015, day: 9, solver: &Day9 };
+
+impl Solution for Day9 {
+    type Model<'a> = Map;
+
+    fn parse(&self, input: &str) -> Result<Map, AppErr> {
+        let distances = parse_lines(input, parse_distance)?;
+        Ok(build_map(distances))
+    }
+
+    fn part1(&self, map: &Map) -> Result<Answer, AppErr> {
+        let mut routes: Vec<(Vec<&str>, KMs)> =
+            map.keys()
+               .map(|name| find_shortest_route_starting_from(map, name))
+               .collect();
+
+        routes.sort_by_key(|(_, a)| *a);
+
+        let (_, length) = routes.into_iter().next().ok_or_else(|| AppErr::no_solution("No routes"))?;
+        Ok(length.into())
+    }
+
+    fn part2(&self, _map: &Map) -> Result<Answer, AppErr> {
+        Ok("TODO".into())
+    }
+}
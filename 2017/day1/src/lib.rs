@@ -0,0 +1,54 @@
+extern crate util;
+
+use util::error::AppErr;
+use util::solution::{ Answer, Registration, Solution };
+
+#[allow(clippy::just_underscores_and_digits)]
+fn char_to_number (c: char) -> Result<u32, AppErr> {
+    match c {
+        '0'..='9' => Ok((c as u32) - ('0' as u32)),
+        _________ => Err(AppErr::new("Input", &format!("Unexpeted input! '{}'", c)))
+    }
+}
+
+fn sum_matching(numbers: &[u32], step: usize) -> u32 {
+    let mut sum = 0;
+    let len = numbers.len();
+
+    for i in 0..len {
+
+        let a = numbers[i];
+        let b = numbers[(i + step) % len];
+
+        if a == b {
+            sum += a;
+        }
+    }
+
+    sum
+}
+
+pub struct Day1;
+
+pub const SOLUTION: Registration = Registration { year: 2017, day: 1, solver: &Day1 };
+
+impl Solution for Day1 {
+    type Model<'a> = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<u32>, AppErr> {
+        input.lines()
+            .flat_map(|line| line.trim().chars())
+            .map(char_to_number)
+            .collect()
+    }
+
+    // Day 1 Part 1
+    fn part1(&self, numbers: &Vec<u32>) -> Result<Answer, AppErr> {
+        Ok(sum_matching(numbers, 1).into())
+    }
+
+    // Day 1 Part 2
+    fn part2(&self, numbers: &Vec<u32>) -> Result<Answer, AppErr> {
+        Ok(sum_matching(numbers, numbers.len() / 2).into())
+    }
+}
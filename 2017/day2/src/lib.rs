@@ -0,0 +1,60 @@
+extern crate util;
+
+use util::error::AppErr;
+use util::solution::{ Answer, Registration, Solution };
+
+pub struct Day2;
+
+pub const SOLUTION: Registration = Registration { year: 2017, day: 2, solver: &Day2 };
+
+impl Solution for Day2 {
+    type Model<'a> = Vec<Vec<u32>>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<u32>>, AppErr> {
+        input.lines()
+            .map(|line| {
+                line.trim()
+                    .split("\t")
+                    .map(|field| Ok(field.parse::<u32>()?))
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Part 1
+    fn part1(&self, rows: &Vec<Vec<u32>>) -> Result<Answer, AppErr> {
+        let mut result = 0;
+        for row in rows {
+            let highest = row.iter().max().ok_or("Unable to find max")?;
+            let lowest = row.iter().min().ok_or("Unable to find min")?;
+            result += highest - lowest;
+        }
+        Ok(result.into())
+    }
+
+    // Part 2
+    fn part2(&self, rows: &Vec<Vec<u32>>) -> Result<Answer, AppErr> {
+        // Find the two numbers in this row which divide evenly, largest over smallest
+        fn divide_evenly(row: &[u32]) -> Option<u32> {
+            for (i, a) in row.iter().enumerate() {
+                for (j, b) in row.iter().enumerate() {
+                    if i == j {
+                        continue;
+                    }
+                    if a % b == 0 {
+                        return Some(a / b);
+                    }
+                }
+            }
+            None
+        }
+
+        let mut result = 0;
+        for row in rows {
+            result += divide_evenly(row).ok_or_else(|| {
+                AppErr::no_solution(&format!("Unable to find two evenly divisible numbers in row! {:?}", row))
+            })?;
+        }
+        Ok(result.into())
+    }
+}
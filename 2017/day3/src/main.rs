@@ -1,105 +1,8 @@
-extern crate input;
+extern crate aoc_2017_day3;
+extern crate util;
 
-/*
-You come across an experimental new kind of memory stored on an infinite two-dimensional grid.
+use util::error::Exit;
 
-Each square on the grid is allocated in a spiral pattern starting at a location marked 1 and then
-counting up while spiraling outward. For example, the first few squares are allocated like this:
-
-    17  16  15  14  13
-    18   5   4   3  12
-    19   6   1   2  11
-    20   7   8   9  10
-    21  22  23---> ...
-
-While this is very space-efficient (no squares are skipped), requested data must be carried back to
-square 1 (the location of the only access port for this memory system) by programs that can only move
-up, down, left, or right. They always take the shortest path: the Manhattan Distance between the
-location of the data and square 1.
-
-For example:
-
-    Data from square 1 is carried 0 steps, since it's at the access port.
-    Data from square 12 is carried 3 steps, such as: down, left, left.
-    Data from square 23 is carried only 2 steps: up twice.
-    Data from square 1024 must be carried 31 steps.
-
-How many steps are required to carry the data from the square identified in your puzzle input all
-the way to the access port?
-
-    36  35  34  33  32  31
-    17  16  15  14  13  30
-    18   5   4   3  12  29
-    19   6   1   2  11  28
-    20   7   8   9  10  27
-    21  22  23  24  25  26
-
-    NOTE: we end each ring in the top left
-    Position  1 is at (0, 0)
-    Position  2 is at (1, 0)
-    Position  3 is at (1, -1)
-    Position  4 is at (0, -1)
-
-    Position 16 is at (-1, -2)
-    Position 36 is at (-2, -3)
-*/
-
-fn main() {
-    let input = 1;
-
-    println!("input: {}", input);
-
-    // Length of edge - 1
-    let mut short = 1;
-    let mut count = 4;
-
-    // Determine the sides of the ring which contains our number
-    loop {
-        if count >= input {
-            break;
-        }
-        short += 2;
-        count += short * 4;
-    }
-
-    let edge = short + 1;
-    let half = edge / 2;
-
-    println!("{}x{}, count: {}", edge, edge, count);
-
-    // Find the top-left coordinates
-
-    let tl_x = 1 - half;
-    let tl_y = 0 - half;
-
-    println!("top left: ({}, {})", tl_x, tl_y);
-
-    // Find the coordinates of our number
-    // NOTE: Top left is (0, 0)
-    let (x, y) = {
-        // top row
-        if count - (short * 1) < input {
-            let x = input - (count - short);
-            (x, 0)
-        }
-        // right row
-        else if count - (short * 2) < input {
-            let y = (input - short) - (count - short);
-            (short, y)
-        }
-        // bottom row
-        else if count - (short * 3) < input {
-            let x = (input - short * 2) - (count - short);
-            (x, short)
-        }
-        // left row
-        else {
-            let y = (input - short * 3) - (count - short);
-            (0, y)
-        }
-    };
-
-    println!("number pos: ({}, {})", x, y);
-
-    println!("result: ");
+fn main() -> Exit {
+    util::solution::main(&aoc_2017_day3::SOLUTION)
 }
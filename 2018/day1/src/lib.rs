@@ -0,0 +1,120 @@
+extern crate util;
+
+use util::error::{ AppErr };
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+use std::collections::hash_set::HashSet;
+
+fn parse_delta(s: &str) -> Result<i32, AppErr> {
+    let sign = match s.bytes().next() {
+        Some(b'+') => Ok(1),
+        Some(b'-') => Ok(-1),
+        _ => Err(AppErr::new("Parse", "bad sign")),
+    }?;
+    let delta = s[1..].parse::<i32>()? * sign;
+    Ok(delta)
+}
+
+pub struct Day1;
+
+pub const SOLUTION: Registration = Registration { year: 2018, day: 1, solver: &Day1 };
+
+impl Solution for Day1 {
+    type Model<'a> = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>, AppErr> {
+        parse_lines(input, parse_delta)
+    }
+
+    /*
+    --- Part One ---
+    After feeling like you've been falling for a few minutes, you look at the device's tiny screen. "Error: Device must be calibrated before first use. Frequency drift detected. Cannot maintain destination lock." Below the message, the device shows a sequence of changes in frequency (your puzzle input). A value like +6 means the current frequency increases by 6; a value like -3 means the current frequency decreases by 3.
+
+    For example, if the device displays frequency changes of +1, -2, +3, +1, then starting from a frequency of zero, the following changes would occur:
+
+    Current frequency  0, change of +1; resulting frequency  1.
+    Current frequency  1, change of -2; resulting frequency -1.
+    Current frequency -1, change of +3; resulting frequency  2.
+    Current frequency  2, change of +1; resulting frequency  3.
+    In this example, the resulting frequency is 3.
+
+    Here are other example situations:
+
+    +1, +1, +1 results in  3
+    +1, +1, -2 results in  0
+    -1, -2, -3 results in -6
+    Starting with a frequency of zero, what is the resulting frequency after all of the changes in frequency have been applied?
+    */
+    fn part1(&self, deltas: &Vec<i32>) -> Result<Answer, AppErr> {
+        let result = deltas.iter().sum::<i32>();
+
+        Ok(result.into())
+    }
+
+    /*
+    --- Part Two ---
+    You notice that the device repeats the same frequency change list over and over. To calibrate the device, you need to find the first frequency it reaches twice.
+
+    For example, using the same list of changes above, the device would loop as follows:
+
+    Current frequency  0, change of +1; resulting frequency  1.
+    Current frequency  1, change of -2; resulting frequency -1.
+    Current frequency -1, change of +3; resulting frequency  2.
+    Current frequency  2, change of +1; resulting frequency  3.
+    (At this point, the device continues from the start of the list.)
+    Current frequency  3, change of +1; resulting frequency  4.
+    Current frequency  4, change of -2; resulting frequency  2, which has already been seen.
+    In this example, the first frequency reached twice is 2. Note that your device might need to repeat its list of frequency changes many times before a duplicate frequency is found, and that duplicates might be found while in the middle of processing the list.
+
+    Here are other examples:
+
+    +1, -1 first reaches 0 twice.
+    +3, +3, +4, -2, -4 first reaches 10 twice.
+    -6, +3, +8, +5, -6 first reaches 5 twice.
+    +7, +7, -2, -7, -4 first reaches 14 twice.
+    What is the first frequency your device reaches twice?
+    */
+    fn part2(&self, deltas: &Vec<i32>) -> Result<Answer, AppErr> {
+        // The starting frequency counts as reached, so `+1, -1` first reaches 0 twice (not 1)
+        let mut sum = 0;
+        let mut seen = HashSet::new();
+        seen.insert(sum);
+
+        // Loops forever if no frequency is ever reached twice, unless cancelled
+        'outer: loop {
+            util::cancel::check()?;
+            for delta in deltas {
+                sum += delta;
+                if !seen.insert(sum) {
+                    break 'outer;
+                }
+            }
+        }
+
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+    use util::aoc_example;
+
+    #[test]
+    fn part1_examples() {
+        aoc_example!("+1\n-2\n+3\n+1", part1 = 3);
+        aoc_example!("+1\n+1\n+1", part1 = 3);
+        aoc_example!("+1\n+1\n-2", part1 = 0);
+        aoc_example!("-1\n-2\n-3", part1 = -6);
+    }
+
+    #[test]
+    fn part2_examples() {
+        aoc_example!("+1\n-2\n+3\n+1", part2 = 2);
+        aoc_example!("+1\n-1", part2 = 0);
+        aoc_example!("+3\n+3\n+4\n-2\n-4", part2 = 10);
+        aoc_example!("-6\n+3\n+8\n+5\n-6", part2 = 5);
+        aoc_example!("+7\n+7\n-2\n-7\n-4", part2 = 14);
+    }
+}
@@ -0,0 +1,151 @@
+extern crate util;
+extern crate regex;
+
+use util::error::{ AppErr };
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Copy, Clone, Debug)]
+enum State { On, Off }
+
+struct Grid {
+    data: Vec<State>,
+    width: isize,
+    height: isize,
+}
+
+impl Grid {
+    fn new(w: isize, h: isize) -> Grid {
+        Grid {
+            data: vec![State::Off; (w * h) as usize],
+            width: w,
+            height: h,
+        }
+    }
+
+    fn get(&self, x: isize, y: isize) -> &State {
+        let pos = (y * self.width) + x;
+        &self.data[pos as usize]
+    }
+
+    fn get_mut(&mut self, x: isize, y: isize) -> &mut State {
+        let pos = (y * self.width) + x;
+        &mut self.data[pos as usize]
+    }
+
+    fn rows(&self) -> Vec<String> {
+        (0..self.height)
+            .map(|y| {
+                (0..self.width)
+                    .map(|x| match self.get(x, y) { State::On => '#', State::Off => ' ' })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+pub struct Vector {
+    position: (isize, isize),
+    velocity: (isize, isize),
+}
+
+type Bounds = ((isize, isize), (isize, isize));
+
+fn find_bounds(vectors: &[Vector]) -> Bounds {
+    let mut min_x = isize::MAX;
+    let mut max_x = isize::MIN;
+    let mut min_y = isize::MAX;
+    let mut max_y = isize::MIN;
+    for v in vectors {
+        let (x, y) = v.position;
+        min_x = x.min(min_x);
+        max_x = x.max(max_x);
+        min_y = y.min(min_y);
+        max_y = y.max(max_y);
+    }
+    ((min_x, max_x),
+     (min_y, max_y))
+}
+
+fn tick(vectors: &mut [Vector], direction: isize) {
+    for v in vectors.iter_mut() {
+        let (x, y) = v.position;
+        let (dx, dy) = v.velocity;
+        v.position = (x + dx * direction, y + dy * direction);
+    }
+}
+
+// Runs the simulation until the stars are most tightly grouped, which is when the message appears.
+// Returns the number of seconds elapsed and the rendered message.
+fn find_message(vectors: &[Vector]) -> Result<(u32, Grid), AppErr> {
+    if vectors.is_empty() {
+        return Err(AppErr::no_solution("No stars"));
+    }
+
+    let mut vectors = vectors.to_vec();
+    let mut iteration = 0;
+    let mut bounds = find_bounds(&vectors);
+
+    loop {
+        util::cancel::check()?;
+        tick(&mut vectors, 1);
+        let next_bounds = find_bounds(&vectors);
+        let ((_, _), (min_y, max_y)) = bounds;
+        let ((_, _), (next_min_y, next_max_y)) = next_bounds;
+
+        // Have the stars started to drift apart again?
+        if next_max_y - next_min_y > max_y - min_y {
+            tick(&mut vectors, -1);
+            break;
+        }
+
+        bounds = next_bounds;
+        iteration += 1;
+    }
+
+    // Render the grid
+    let ((min_x, max_x), (min_y, max_y)) = bounds;
+    let mut grid = Grid::new(max_x - min_x + 1, max_y - min_y + 1);
+    for v in &vectors {
+        let (x, y) = v.position;
+        *grid.get_mut(x - min_x, y - min_y) = State::On;
+    }
+
+    Ok((iteration, grid))
+}
+
+pub struct Day10;
+
+pub const SOLUTION: Registration = Registration { year: 2018, day: 10, solver: &Day10 };
+
+impl Solution for Day10 {
+    type Model<'a> = Vec<Vector>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vector>, AppErr> {
+        let input_matcher = regex::Regex::new(r"position=<\s*(-?\d+),\s*(-?\d+)> velocity=<\s*(-?\d+),\s*(-?\d+)>").unwrap();
+        parse_lines(input, |s| {
+            let captures = input_matcher.captures(s).ok_or("Unexpected vector")?;
+            Ok::<_, AppErr>(Vector {
+                position: (
+                    captures.get(1).unwrap().as_str().parse()?,
+                    captures.get(2).unwrap().as_str().parse()?
+                ),
+                velocity: (
+                    captures.get(3).unwrap().as_str().parse()?,
+                    captures.get(4).unwrap().as_str().parse()?
+                ),
+            })
+        })
+    }
+
+    fn part1(&self, vectors: &Vec<Vector>) -> Result<Answer, AppErr> {
+        let (_, grid) = find_message(vectors)?;
+        Ok(grid.rows().into())
+    }
+
+    fn part2(&self, vectors: &Vec<Vector>) -> Result<Answer, AppErr> {
+        let (iteration, _) = find_message(vectors)?;
+        Ok(iteration.into())
+    }
+}
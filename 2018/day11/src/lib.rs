@@ -0,0 +1,149 @@
+extern crate util;
+
+use util::error::AppErr;
+use util::params::Param;
+use util::solution::{ Answer, Registration, Solution };
+
+const SERIAL_NUMBER: Param = Param::new("serial_number", "1723", "The grid serial number, which is the puzzle input");
+
+struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl <T> Grid<T> where T: Default + Clone {
+    fn new(w: usize, h: usize) -> Grid<T> {
+        Grid {
+            data: vec![Default::default(); w * h],
+            width: w,
+            height: h,
+        }
+    }
+}
+
+impl <T> Grid<T> {
+    fn get(&self, x: usize, y: usize) -> &T {
+        let pos = (y * self.width) + x;
+        &self.data[pos]
+    }
+
+    fn get_mut(&mut self, x: usize, y: usize) -> &mut T {
+        let pos = (y * self.width) + x;
+        &mut self.data[pos]
+    }
+}
+
+fn calculate_power_grid(serial_number: i32) -> Grid<i32> {
+    let (width, height) = (300, 300);
+    let mut grid = Grid::new(width, height);
+    for x in 0..width {
+        for y in 0..height {
+            let x_coord = (x + 1) as i32;
+            let y_coord = (y + 1) as i32;
+            let rack_id = x_coord + 10; 
+            let power = ((rack_id * y_coord) + serial_number) * rack_id;
+            let hundreds_digit = (power.abs() / 100) % 100 % 10;
+            let power_2 = hundreds_digit - 5;
+            *grid.get_mut(x, y) = power_2;
+        }
+    }
+    grid
+}
+
+fn test_cell_max_power(grid: &Grid<i32>, cell_size: usize) -> ((usize, usize), i32) {
+    let mut highest_power = 0;
+    let mut highest_power_coords = (0, 0);
+    for x in 0..(grid.width - (cell_size - 1)) {
+        for y in 0..(grid.height - (cell_size - 1)) {
+            // Total up this NxN grid
+            let mut total = 0;
+            for sx in 0..cell_size {
+                for sy in 0..cell_size {
+                    total += grid.get(x + sx, y + sy);
+                }
+            }
+            if total > highest_power {
+                highest_power = total;
+                highest_power_coords = (x + 1, y + 1);
+            }
+        }
+    }
+    (highest_power_coords, highest_power)
+}
+
+pub struct Day11;
+
+pub const SOLUTION: Registration = Registration { year: 2018, day: 11, solver: &Day11 };
+
+impl Solution for Day11 {
+    // The puzzle input is the grid serial number, which is part of the puzzle text
+    type Model<'a> = ();
+
+    const PARAMS: &'static [Param] = &[SERIAL_NUMBER];
+
+    fn parse(&self, _input: &str) -> Result<(), AppErr> {
+        Ok(())
+    }
+
+    /*
+    --- Part One ---
+    What is the X,Y coordinate of the top-left fuel cell of the 3x3 square with the largest total power?
+
+    Your puzzle input is 1723.
+    */
+    fn part1(&self, _: &()) -> Result<Answer, AppErr> {
+        let grid = calculate_power_grid(SERIAL_NUMBER.parse()?);
+        let ((x, y), _) = test_cell_max_power(&grid, 3);
+        Ok(format!("{},{}", x, y).into())
+    }
+
+    /*
+    --- Part Two ---
+    You discover a dial on the side of the device; it seems to let you select a square
+    of any size, not just 3x3. Sizes from 1x1 to 300x300 are supported.
+
+    Realizing this, you now must find the square of any size with the largest total power.
+    Identify this square by including its size as a third parameter after the top-left
+    coordinate: a 9x9 square with a top-left corner of 3,5 is identified as 3,5,9.
+
+    For example:
+    - For grid serial number 18, the largest total square (with a total power of 113) is 16x16 and has a top-left corner of 90,269, so its identifier is 90,269,16.
+    - For grid serial number 42, the largest total square (with a total power of 119) is 12x12 and has a top-left corner of 232,251, so its identifier is 232,251,12.
+
+    What is the X,Y,size identifier of the square with the largest total power?
+    */
+    fn part2(&self, _: &()) -> Result<Answer, AppErr> {
+        let grid = calculate_power_grid(SERIAL_NUMBER.parse()?);
+
+        let mut highest_power = 0;
+        let mut highest_power_coords = (0, 0);
+        let mut highest_power_cell_size = 0;
+
+        for cell_size in 1..=30 {
+            // Each size takes longer than the last
+            util::cancel::check()?;
+            let (coords, power) = test_cell_max_power(&grid, cell_size);
+            if power > highest_power {
+                highest_power = power;
+                highest_power_coords = coords;
+                highest_power_cell_size = cell_size;
+            }
+        }
+
+        let (x, y) = highest_power_coords;
+        Ok(format!("{},{},{}", x, y, highest_power_cell_size).into())
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+    use util::aoc_example;
+
+    #[test]
+    fn part1_examples() {
+        aoc_example!("", params(serial_number = 18), part1 = "33,45");
+        aoc_example!("", params(serial_number = 42), part1 = "21,61");
+    }
+}
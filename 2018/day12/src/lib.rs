@@ -0,0 +1,168 @@
+#![allow(unused)]
+
+extern crate util;
+
+use util::error::{ AppErr, Context };
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum State {
+    Live,
+    Dead
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    matches: [State; 5],
+    result: State,
+}
+
+fn parse_state(c: char) -> Result<State, AppErr> {
+    use State::*;
+    match c {
+        '.' => Ok(Dead),
+        '#' => Ok(Live),
+        _ => Err(AppErr::validation(&format!("invalid state char {}", c))),
+    }
+}
+
+fn parse_initial_state(s: &str) -> Result<Vec<State>, AppErr> {
+    // initial state: #..#.#..##......###...###
+    // ignore the preamble
+    let s = s.strip_prefix("initial state: ").ok_or("Expected initial state")?;
+    s.chars().map(parse_state).collect()
+}
+
+fn parse_rule(s: &str) -> Result<Rule, AppErr> {
+    // ...## => #
+    let mut parts = s.split(" => ");
+    let mut matches = [State::Dead; 5];
+    for (i, c) in parts.next().ok_or("Expected rule pattern")?.chars().enumerate().take(5) {
+        matches[i] = parse_state(c)?;
+    }
+    let result = parse_state(parts.next().and_then(|s| s.chars().next()).ok_or("Expected rule result")?)?;
+    Ok(Rule {
+        matches,
+        result
+    })
+}
+
+fn run_simulation(generations: usize, initial_state: &[State], rules: &[Rule]) -> Result<isize, AppErr> {
+    use State::*;
+
+    // Hax - build a state vector which is larger than the size of our initial state.
+    // Position zero begins in the middle of the vector
+
+    const WORLD_SIZE: usize = 180;
+    const ZERO_START: usize = 60;
+
+    let len = initial_state.len();
+    let world_size = len * WORLD_SIZE;
+    let zero_start = len * ZERO_START;
+    let mut state = vec![Dead; world_size];
+    let mut state_next = state.clone();
+    
+    // Initialise the world state
+    for (s, is) in state.iter_mut().skip(zero_start).take(len).zip(initial_state) {
+        *s = *is;
+    }
+
+    let dead = &[Dead; 5];
+    let get_next_state = |window: &[State]| {
+        if window == dead {
+            return Dead;
+        }
+        match rules.iter().find(|r| r.matches == window) {
+            Some(rule) => rule.result,
+            None       => Dead,
+        }
+    };
+
+    for gen in 1..=generations {
+        util::cancel::check()?;
+        for (i, window) in state.windows(5).enumerate() {
+            // We update the pot in the middle of each window
+            state_next[i + 2] = get_next_state(window);
+        }
+        std::mem::swap(&mut state, &mut state_next);
+    }
+
+    /*
+    After N generations, what is the sum of the numbers of all pots which contain a plant?
+    */
+    let result = state.iter().enumerate()
+        .map(|(i, s)| match s {
+            Live => (i as isize - zero_start as isize),
+            Dead => 0,
+        })
+        .sum::<isize>();
+
+    Ok(result)
+}
+
+pub struct Day12;
+
+pub const SOLUTION: Registration = Registration { year: 2018, day: 12, solver: &Day12 };
+
+impl Solution for Day12 {
+    type Model<'a> = (Vec<State>, Vec<Rule>);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        let mut lines = util::input::lines(input);
+        let (_, first) = lines.next().ok_or("Empty input")?;
+        let initial_state = parse_initial_state(first)?;
+        let rules = lines
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(n, line)| parse_rule(line).with_context(|| format!("failed to parse line {}: {:?}", n, line)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((initial_state, rules))
+    }
+
+    /*
+    --- Part One ---
+    The pots are numbered, with 0 in front of you. To the left, the pots are numbered -1, -2, -3, and so on; to the
+    right, 1, 2, 3.... Your puzzle input contains a list of pots from 0 to the right and whether they do (#) or do
+    not (.) currently contain a plant, the initial state. (No other pots currently contain plants.) For example, an
+    initial state of #..##.... indicates that pots 0, 3, and 4 currently contain plants.
+
+    Your puzzle input also contains some notes you find on a nearby table: someone has been trying to figure out how
+    these plants spread to nearby pots. Based on the notes, for each generation of plants, a given pot has or does
+    not have a plant based on whether that pot (and the two pots on either side of it) had a plant in the last generation.
+    These are written as LLCRR => N, where L are pots to the left, C is the current pot being considered, R are the pots
+    to the right, and N is whether the current pot will have a plant in the next generation. For example:
+
+    - A note like ..#.. => . means that a pot that contains a plant but with no plants within two pots of it will not have a plant in it during the next generation.
+    - A note like ##.## => . means that an empty pot with two plants on each side of it will remain empty in the next generation.
+    - A note like .##.# => # means that a pot has a plant in a given generation if, in the previous generation, there were plants in that pot, the one immediately to the left, and the one two pots to the right, but not in the ones immediately to the right and two to the left.
+
+    It's not clear what these plants are for, but you're sure it's important, so you'd like to make sure the current
+    configuration of plants is sustainable by determining what will happen after 20 generations.
+
+    In this example, after 20 generations, the pots shown as # contain plants, the furthest left of which
+    is pot -2, and the furthest right of which is pot 34. Adding up all the numbers of plant-containing pots after the 20th generation produces 325.
+    */
+    fn part1(&self, (initial_state, rules): &Self::Model<'_>) -> Result<Answer, AppErr> {
+        Ok(run_simulation(20, initial_state, rules)?.into())
+    }
+
+    /*
+    --- Part Two ---
+    You realize that 20 generations aren't enough. After all, these plants will
+    need to last another 1500 years to even reach your timeline, not to mention your future.
+
+    After fifty billion (50000000000) generations, what is the sum of the numbers of all pots which contain a plant?
+    */
+    fn part2(&self, (initial_state, rules): &Self::Model<'_>) -> Result<Answer, AppErr> {
+        // NOTE: This simulation is massive! However after the initial iterations the amount seems to
+        // increase by a fixed amount every iteration...
+        //
+        // Gather some samples, then do some arithmetic...
+        let sample_a = run_simulation(2000, initial_state, rules)?;
+        let sample_b = run_simulation(3000, initial_state, rules)?;
+
+        let simulation_size = 50_000_000_000;
+        let total = ((simulation_size - 2000) / 1000) * (sample_b - sample_a) + sample_a;
+
+        Ok(total.into())
+    }
+}
@@ -0,0 +1,241 @@
+extern crate util;
+
+use util::error::{ AppErr };
+use util::iter::{ ConsumeIterator, Single };
+use util::solution::{ Answer, Registration, Solution };
+
+type X = usize;
+type Y = usize;
+
+#[derive(Copy, Clone, Debug)]
+pub enum Tile {
+    Space,
+    Backslash,
+    Forwardslash,
+    Horizontal,
+    Vertical,
+    Cross,
+}
+
+impl Tile {
+    #[allow(clippy::just_underscores_and_digits)]
+    fn from_char(c: char) -> Result<Tile, AppErr> {
+        match c {
+            ' ' => Ok(Tile::Space),
+            '\\' => Ok(Tile::Backslash),
+            '/' => Ok(Tile::Forwardslash),
+            '-' | '>' | '<' => Ok(Tile::Horizontal),
+            '|' | 'v' | '^' => Ok(Tile::Vertical),
+            '+' => Ok(Tile::Cross),
+            ___ => Err(AppErr::validation(&format!("Unexpected character: {}", c))),
+        }
+    }
+}
+
+pub struct Grid {
+    data: Vec<Tile>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Grid {
+    fn new(width: usize, height: usize) -> Grid {
+        Grid {
+            data: vec![Tile::Space; width * height],
+            width,
+            height,
+        }
+    }
+
+    fn get(&self, (x, y): (X, Y)) -> Tile {
+        let pos = (y * self.width) + x;
+        self.data[pos]
+    }
+
+    fn get_mut(&mut self, (x, y): (X, Y)) -> &mut Tile {
+        let pos = (y * self.width) + x;
+        &mut self.data[pos]
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[allow(clippy::enum_variant_names)]
+enum Turn {
+    GoLeft,
+    GoStraight,
+    GoRight,
+}
+
+#[derive(Clone)]
+pub struct Cart {
+    direction: Direction,
+    next_turn: Turn,
+    pos: (X, Y),
+    destroyed: bool,
+}
+
+type CartId = usize;
+
+fn test_for_collision(cart_id: CartId, carts: &[Cart]) -> Option<CartId> {
+    for (other_cart_id, other_cart) in carts.iter().enumerate() {
+        if other_cart_id == cart_id || other_cart.destroyed {
+            continue;
+        }
+        if carts[cart_id].pos == other_cart.pos {
+            return Some(other_cart_id);
+        }
+    }
+    None
+}
+
+fn tick_cart(grid: &Grid, cart: &mut Cart) -> Result<(), AppErr> {
+    use Tile::*;
+    use Turn::*;
+    use Direction::*;
+    // Update cart position
+    cart.pos = match (cart.direction, cart.pos) {
+        (Up,    (x, y)) => (x, y - 1),
+        (Down,  (x, y)) => (x, y + 1),
+        (Left,  (x, y)) => (x - 1, y),
+        (Right, (x, y)) => (x + 1, y),
+    };
+    // Determine new cart direction
+    let current_tile = grid.get(cart.pos);
+    let move_direction = match (cart.direction, current_tile) {
+        (_,         Space)        => return Err(AppErr::no_solution(&format!("Derailed! {:?}", cart.pos))),
+        (Up,        Backslash)    => Left,
+        (Down,      Backslash)    => Right,
+        (Left,      Backslash)    => Up,
+        (Right,     Backslash)    => Down,
+        (Up,        Forwardslash) => Right,
+        (Down,      Forwardslash) => Left,
+        (Left,      Forwardslash) => Down,
+        (Right,     Forwardslash) => Up,
+        (direction, Vertical)     => direction,
+        (direction, Horizontal)   => direction,
+        (direction, Cross) => {
+            match (direction, cart.next_turn) {
+                (Up,    GoLeft)     => Left,
+                (Down,  GoLeft)     => Right,
+                (Left,  GoLeft)     => Down,
+                (Right, GoLeft)     => Up,
+                (dir,   GoStraight) => dir,
+                (Up,    GoRight)    => Right,
+                (Down,  GoRight)    => Left,
+                (Left,  GoRight)    => Up,
+                (Right, GoRight)    => Down,
+            }
+        },
+    };
+    // Update cart
+    cart.direction = move_direction;
+    if let Cross = current_tile {
+        cart.next_turn = match cart.next_turn {
+            GoLeft     => GoStraight,
+            GoStraight => GoRight,
+            GoRight    => GoLeft,
+        };
+    }
+    Ok(())
+}
+
+// Runs the simulation for one tick, returning the position of the first collision (if any).
+// NOTE: A collision may occur mid-tick!
+fn tick(grid: &Grid, carts: &mut [Cart]) -> Result<Option<(X, Y)>, AppErr> {
+    let mut first_collision = None;
+    // Since we have to scan this collection *every time* we modify one of its elements
+    // we can't iterate directly 😢
+    for cart_id in 0..carts.len() {
+        let cart = &mut carts[cart_id];
+        if cart.destroyed {
+            continue;
+        }
+        tick_cart(grid, cart)?;
+        // Check for collisions with this cart
+        if let Some(colliding_cart_id) = test_for_collision(cart_id, carts) {
+            first_collision = first_collision.or(Some(carts[cart_id].pos));
+
+            // And destroy the colliding carts
+            carts[colliding_cart_id].destroyed = true;
+            carts[cart_id].destroyed = true;
+        }
+    }
+    Ok(first_collision)
+}
+
+pub struct Day13;
+
+pub const SOLUTION: Registration = Registration { year: 2018, day: 13, solver: &Day13 };
+
+impl Solution for Day13 {
+    type Model<'a> = (Grid, Vec<Cart>);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        let input = input.lines().collect::<Vec<_>>();
+
+        let width = input.iter().map(|s| s.len()).max().ok_or("Empty input")?;
+        let height = input.len();
+
+        let mut grid = Grid::new(width, height);
+        let mut carts = Vec::new();
+
+        for (y, row) in input.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                *grid.get_mut((x, y)) = Tile::from_char(c)?;
+                let direction = match c {
+                    '^' => Some(Direction::Up),
+                    'v' => Some(Direction::Down),
+                    '<' => Some(Direction::Left),
+                    '>' => Some(Direction::Right),
+                    _   => None,
+                };
+                if let Some(direction) = direction {
+                    carts.push(Cart {
+                        direction,
+                        next_turn: Turn::GoLeft,
+                        pos: (x, y),
+                        destroyed: false,
+                    });
+                }
+            }
+        }
+
+        Ok((grid, carts))
+    }
+
+    fn part1(&self, (grid, carts): &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let mut carts = carts.clone();
+        loop {
+            if let Some((x, y)) = tick(grid, &mut carts)? {
+                return Ok(format!("{},{}", x, y).into());
+            }
+        }
+    }
+
+    fn part2(&self, (grid, carts): &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let mut carts = carts.clone();
+        loop {
+            tick(grid, &mut carts)?;
+
+            // Check for a single remaining cart at the end of the tick
+            match carts.iter().filter(|c| !c.destroyed).single() {
+                Single::One(cart) => {
+                    let (x, y) = cart.pos;
+                    return Ok(format!("{},{}", x, y).into());
+                }
+                Single::Empty => {
+                    return Err(AppErr::no_solution("All out of carts!"));
+                }
+                Single::Many => ()
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+extern crate util;
+
+use util::error::AppErr;
+use util::params::Param;
+use util::solution::{ Answer, Registration, Solution };
+
+const PUZZLE_INPUT: Param = Param::new("recipes", "920831", "The number of recipes, which is the puzzle input");
+
+/*
+--- Part One ---
+To create new recipes, the two Elves combine their current recipes.
+This creates new recipes from the digits of the sum of the current recipes' scores.
+With the current recipes' scores of 3 and 7, their sum is 10, and so two new recipes would be
+created: the first with score 1 and the second with score 0. If the current recipes' scores
+were 2 and 3, the sum, 5, would only create one recipe (with a score of 5) with its single digit.
+
+The new recipes are added to the end of the scoreboard in the order they are created.
+So, after the first round, the scoreboard is 3, 7, 1, 0.
+
+After all new recipes are added to the scoreboard, each Elf picks a new current recipe.
+To do this, the Elf steps forward through the scoreboard a number of recipes equal to 1 plus
+the score of their current recipe. So, after the first round, the first Elf moves
+forward 1 + 3 = 4 times, while the second Elf moves forward 1 + 7 = 8 times. If they run out of
+recipes, they loop back around to the beginning. After the first round, both Elves happen to loop
+around until they land on the same recipe that they had in the beginning; in general, they will
+move to different recipes.
+
+The Elves think their skill will improve after making a few recipes (your puzzle input). However,
+that could take ages; you can speed this up considerably by identifying the scores of the ten
+recipes after that.
+*/
+
+fn digits_from(n: i32) -> (Option<i32>, Option<i32>) {
+    if n > 100 {
+        panic!("Out of range")
+    }
+    (
+        /* tens */ if n >= 10 { Some((n / 10) % 10) } else { None },
+        /* ones */ Some(n % 10)
+    )
+}
+
+struct Scoreboard {
+    recipes: Vec<i32>,
+    elf_1: i32,
+    elf_2: i32,
+}
+
+impl Scoreboard {
+    fn new() -> Scoreboard {
+        Scoreboard { recipes: vec![3, 7], elf_1: 0, elf_2: 1 }
+    }
+
+    fn round(&mut self) {
+        let recipes = &mut self.recipes;
+        let combined = recipes[self.elf_1 as usize] + recipes[self.elf_2 as usize];
+        let (tens, ones) = digits_from(combined);
+        recipes.extend(tens.iter());
+        recipes.extend(ones.iter());
+
+        self.elf_1 = (self.elf_1 + (1 + recipes[self.elf_1 as usize])) % recipes.len() as i32;
+        self.elf_2 = (self.elf_2 + (1 + recipes[self.elf_2 as usize])) % recipes.len() as i32;
+    }
+}
+
+pub struct Day14;
+
+pub const SOLUTION: Registration = Registration { year: 2018, day: 14, solver: &Day14 };
+
+impl Solution for Day14 {
+    // The puzzle input is a number, which is part of the puzzle text
+    type Model<'a> = ();
+
+    const PARAMS: &'static [Param] = &[PUZZLE_INPUT];
+
+    fn parse(&self, _input: &str) -> Result<(), AppErr> {
+        Ok(())
+    }
+
+    /*
+    --- Part One ---
+    If the Elves think their skill will improve after making 9 recipes, the scores of the ten recipes
+    after the first nine on the scoreboard would be 5158916779 (highlighted in the last line of the diagram).
+
+    - After 5 recipes, the scores of the next ten would be 0124515891.
+    - After 18 recipes, the scores of the next ten would be 9251071085.
+    - After 2018 recipes, the scores of the next ten would be 5941429882.
+
+    What are the scores of the ten recipes immediately after the number of recipes in your puzzle input?
+    */
+    fn part1(&self, _: &()) -> Result<Answer, AppErr> {
+        let mut scoreboard = Scoreboard::new();
+
+        let max_recipies_to_test = PUZZLE_INPUT.parse::<usize>()?;
+
+        while scoreboard.recipes.len() < (max_recipies_to_test + 10) {
+            scoreboard.round();
+        }
+
+        let next_ten_recipes = &scoreboard.recipes[max_recipies_to_test..(max_recipies_to_test + 10)];
+
+        Ok(next_ten_recipes.iter().map(|r| r.to_string()).collect::<String>().into())
+    }
+
+    /*
+    --- Part Two ---
+    As it turns out, you got the Elves' plan backwards. They actually want to know how many
+    recipes appear on the scoreboard to the left of the first recipes whose scores are the
+    digits from your puzzle input.
+
+    - 51589 first appears after 9 recipes.
+    - 01245 first appears after 5 recipes.
+    - 92510 first appears after 18 recipes.
+    - 59414 first appears after 2018 recipes.
+
+    How many recipes appear on the scoreboard to the left of the score sequence in your puzzle input?
+    */
+    fn part2(&self, _: &()) -> Result<Answer, AppErr> {
+        let mut scoreboard = Scoreboard::new();
+
+        // Read as digits, as the sequence may start with 0
+        let pattern_to_search_for = PUZZLE_INPUT.value().chars()
+            .map(|c| c.to_digit(10).map(|d| d as i32).ok_or_else(|| AppErr::usage("expected the recipes to be digits")))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // The pattern may never appear, so check for cancellation now and then
+        let mut rounds = 0u64;
+        loop {
+            rounds += 1;
+            if rounds.is_multiple_of(1 << 16) {
+                util::cancel::check()?;
+            }
+            scoreboard.round();
+
+            // Check the end of the vector for our pattern
+            let result = scoreboard.recipes
+                .windows(pattern_to_search_for.len()).enumerate().rev().take(2)
+                .find(|(_, w)| *w == &pattern_to_search_for[..]);
+
+            if let Some((i, _)) = result {
+                return Ok(i.into());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+    use util::aoc_example;
+
+    #[test]
+    fn part1_examples() {
+        aoc_example!("", params(recipes = 9), part1 = "5158916779");
+        aoc_example!("", params(recipes = 5), part1 = "0124515891");
+        aoc_example!("", params(recipes = 18), part1 = "9251071085");
+        aoc_example!("", params(recipes = 2018), part1 = "5941429882");
+    }
+
+    #[test]
+    fn part2_examples() {
+        aoc_example!("", params(recipes = "51589"), part2 = 9);
+        aoc_example!("", params(recipes = "01245"), part2 = 5);
+        aoc_example!("", params(recipes = "92510"), part2 = 18);
+        aoc_example!("", params(recipes = "59414"), part2 = 2018);
+    }
+}
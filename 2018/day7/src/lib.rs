@@ -0,0 +1,229 @@
+extern crate util;
+extern crate regex;
+
+use util::ConsumeIterator;
+use util::error::{ AppErr };
+use util::input::parse_lines;
+use util::params::Param;
+use util::solution::{ Answer, Registration, Solution };
+use std::collections::hash_map::HashMap;
+use std::cell::RefCell;
+
+type Id = char;
+
+#[derive(Debug)]
+struct Step {
+    pub id: Id,
+    pub depends_on: Id,
+}
+
+/// Each step, and the steps it depends on
+pub type Dependencies = HashMap<Id, Vec<Id>>;
+
+// Get a sorted list of all steps
+fn available_steps(step_dependencies: &Dependencies) -> Vec<Id> {
+    let mut temp = step_dependencies.keys().cloned().collect::<Vec<_>>();
+    temp.sort();
+    temp
+}
+
+const WORKERS: Param = Param::new("workers", "5", "The number of workers, including you");
+const BASE_DURATION: Param = Param::new("base_duration", "60", "Seconds added to the duration of every step");
+
+pub struct Day7;
+
+pub const SOLUTION: Registration = Registration { year: 2018, day: 7, solver: &Day7 };
+
+impl Solution for Day7 {
+    type Model<'a> = Dependencies;
+
+    const PARAMS: &'static [Param] = &[WORKERS, BASE_DURATION];
+
+    fn parse(&self, input: &str) -> Result<Dependencies, AppErr> {
+        let pattern = regex::Regex::new("^Step ([A-Z]) must be finished before step ([A-Z]) can begin.$").unwrap();
+
+        let input = parse_lines(input, |s| {
+            let caps = pattern.captures(s).ok_or("Unexpected step")?;
+            Ok::<_, AppErr>(Step {
+                id: caps[2].chars().next().unwrap(),
+                depends_on: caps[1].chars().next().unwrap(),
+            })
+        })?;
+
+        // Build a lookup of step dependencies
+        let mut step_dependencies = HashMap::new();
+        for step in input.iter() {
+            let init = || Vec::new();
+            step_dependencies.entry(step.depends_on).or_insert_with(init);
+            step_dependencies.entry(step.id).or_insert_with(init).push(step.depends_on);
+        }
+
+        Ok(step_dependencies)
+    }
+
+    /*
+    --- Part One ---
+    The instructions specify a series of steps and requirements about which steps must be finished before others can begin (your puzzle input).
+    Each step is designated by a single letter. For example, suppose you have the following instructions:
+
+    Step C must be finished before step A can begin.
+    Step C must be finished before step F can begin.
+    Step A must be finished before step B can begin.
+    Step A must be finished before step D can begin.
+    Step B must be finished before step E can begin.
+    Step D must be finished before step E can begin.
+    Step F must be finished before step E can begin.
+    Visually, these requirements look like this:
+
+     -->A--->B--
+    /    \       \
+    C     -->D----->E
+    \            /
+     ---->F-----
+
+    Your first goal is to determine the order in which the steps should be completed. If more than one step is ready,
+    choose the step which is first alphabetically. In this example, the steps would be completed as follows:
+
+    Only C is available, and so it is done first.
+    Next, both A and F are available. A is first alphabetically, so it is done next.
+    Then, even though F was available earlier, steps B and D are now also available, and B is the first alphabetically of the three.
+    After that, only D and F are available. E is not available because only some of its prerequisites are complete. Therefore, D is completed next.
+    F is the only choice, so it is done next.
+    Finally, E is completed.
+    So, in this example, the correct order is CABDFE.
+
+    In what order should the steps in your instructions be completed?
+    */
+    fn part1(&self, step_dependencies: &Dependencies) -> Result<Answer, AppErr> {
+        let available_steps = available_steps(step_dependencies);
+
+        // Repeatedly scan the list for the next step which
+        // a) is not yet completed
+        // b) has all its dependencies completed
+        let mut completed_steps: Vec<char> = Vec::new();
+        loop {
+            let step = available_steps.iter()
+                .find(|step| {
+                    let is_completed = || completed_steps.contains(step);
+                    let are_dependencies_met = || step_dependencies.get(step).unwrap().iter().all(|dep| completed_steps.contains(dep));
+                    !is_completed() && are_dependencies_met()
+                });
+
+            match step {
+                Some(&step) => completed_steps.push(step),
+                None => break
+            }
+        }
+
+        Ok(completed_steps.iter().join("").into())
+    }
+
+    /*
+    --- Part Two ---
+    As you're about to begin construction, four of the Elves offer to help. "The sun will set soon; it'll go faster if we work together."
+    Now, you need to account for multiple people working on steps simultaneously. If multiple steps are available, workers should still begin them in alphabetical order.
+
+    Each step takes 60 seconds plus an amount corresponding to its letter: A=1, B=2, C=3, and so on.
+    So, step A takes 60+1=61 seconds, while step Z takes 60+26=86 seconds. No time is required between steps.
+
+    To simplify things for the example, however, suppose you only have help from one Elf (a total of two workers) and
+    that each step takes 60 fewer seconds (so that step A takes 1 second and step Z takes 26 seconds). Then, using the
+    same instructions as above, this is how each second would be spent:
+
+    Second   Worker 1   Worker 2   Done
+    0         C          .        
+    1         C          .        
+    2         C          .        
+    3         A          F         C
+    4         B          F         CA
+    5         B          F         CA
+    6         D          F         CAB
+    7         D          F         CAB
+    8         D          F         CAB
+    9         D          .         CABF
+    10        E          .         CABFD
+    11        E          .         CABFD
+    12        E          .         CABFD
+    13        E          .         CABFD
+    14        E          .         CABFD
+    15        .          .         CABFDE
+
+    Each row represents one second of time. The Second column identifies how many seconds have passed as
+    of the beginning of that second. Each worker column shows the step that worker is currently
+    doing (or . if they are idle). The Done column shows completed steps.
+
+    Note that the order of the steps has changed; this is because steps now take time to finish and multiple
+    workers can begin multiple steps simultaneously.
+
+    In this example, it would take 15 seconds for two workers to complete these steps.
+
+    With 5 workers and the 60+ second step durations described above, how long will it take to complete all of the steps?
+    */
+    fn part2(&self, step_dependencies: &Dependencies) -> Result<Answer, AppErr> {
+        #[derive(Copy, Clone)]
+        struct Work {
+            step: Id,
+            time_remaining: u32,
+        }
+
+        let available_steps = available_steps(step_dependencies);
+        let workers = WORKERS.parse::<usize>()?;
+        let base_duration = BASE_DURATION.parse::<u32>()?;
+
+        // NOTE: This puzzle requires us to interrogate a collection (the worker state) while we modify it
+        // RefCell to the rescue
+
+        let assigned_steps = vec![RefCell::new(None as Option<Work>); workers];
+        let mut completed_steps = Vec::new();
+        let mut time = 0;
+        loop {
+            for assigned in assigned_steps.iter() {
+                let mut work_ref = assigned.borrow_mut();
+                if let Some(work) = *work_ref {
+                    // Work on the assigned step
+                    if work.time_remaining > 1 {
+                        *work_ref = Some(Work { time_remaining: work.time_remaining - 1, ..work });
+                    }
+                    else {
+                        completed_steps.push(work.step);
+                        *work_ref = None;
+                    }
+                };
+                // All done? Get new work
+                if work_ref.is_none() {
+                    *work_ref = available_steps.iter()
+                        .filter(|step| {
+                            let is_completed = || completed_steps.contains(step);
+                            let is_in_progress = || assigned_steps.iter().any(|a| a.try_borrow().ok().and_then(|b| b.map(|w| w.step == **step)).unwrap_or(false));
+                            let are_dependencies_met = || step_dependencies.get(step).unwrap().iter().all(|dep| completed_steps.contains(dep));
+                            !is_completed() && !is_in_progress() && are_dependencies_met()
+                        })
+                        .map(|&step| Work {
+                            step,
+                            time_remaining: (step as u32 - 'A' as u32) + 1 + base_duration,
+                        })
+                        .next();
+                }
+            }
+            if assigned_steps.iter().all(|s| s.borrow().is_none()) {
+                break;
+            }
+            time += 1;
+        }
+
+        Ok(time.into())
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+    use util::aoc_example;
+
+    #[test]
+    fn example() {
+        let input = include_str!("../tests/fixtures/part1-1.txt");
+        aoc_example!(input, part1 = "CABDFE");
+        aoc_example!(input, params(workers = 2, base_duration = 0), part2 = 15);
+    }
+}
@@ -96,7 +96,7 @@ impl<T> List<T> {
         let n = Pointer(self.slab.insert(Node {
             value: t,
             prev: node,
-            next: next,
+            next,
         }));
         if next.is_null() {
             self.tail = n;
@@ -112,7 +112,7 @@ impl<T> List<T> {
         let prev = self[node].prev;
         let n = Pointer(self.slab.insert(Node {
             value: t,
-            prev: prev,
+            prev,
             next: node,
         }));
         
@@ -0,0 +1,71 @@
+extern crate util;
+
+use util::error::{ AppErr };
+use util::input::parse_lines;
+use util::iter::pairs;
+use util::solution::{ Answer, Registration, Solution };
+
+fn parse_item(s: &str) -> Result<i32, AppErr> {
+    let i = s.parse::<i32>()?;
+    Ok(i)
+}
+
+pub struct Day1;
+
+pub const SOLUTION: Registration = Registration { year: 2020, day: 1, solver: &Day1 };
+
+impl Solution for Day1 {
+    type Model<'a> = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>, AppErr> {
+        parse_lines(input, parse_item)
+    }
+
+    /*
+    --- Part One ---
+    Before you leave, the Elves in accounting just need you to fix your expense report (your puzzle input); apparently, something isn't quite adding up.
+
+    Specifically, they need you to find the two entries that sum to 2020 and then multiply those two numbers together.
+
+    For example, suppose your expense report contained the following:
+
+        1721
+        979
+        366
+        299
+        675
+        1456
+
+    In this list, the two entries that sum to 2020 are 1721 and 299. Multiplying them together produces 1721 * 299 = 514579, so the correct answer is 514579.
+
+    Of course, your expense report is much larger. Find the two entries that sum to 2020; what do you get if you multiply them together?
+    */
+    fn part1(&self, input: &Vec<i32>) -> Result<Answer, AppErr> {
+        // pair up inputs and find the two which sum to 2020
+        let (a, b) = pairs(input)
+            .map(|(&a, &b)| (a, b))
+            .find(|(a, b)| a + b == 2020)
+            .ok_or_else(|| AppErr::no_solution("No items which sum to 2020 found!"))?;
+
+        Ok((a * b).into())
+    }
+
+    /*
+    --- Part Two ---
+    Find three numbers in your expense report that meet the same criteria.
+
+    Using the above example again, the three entries that sum to 2020 are 979, 366, and 675. Multiplying them together produces the answer, 241861950.
+
+    In your expense report, what is the product of the three entries that sum to 2020?
+    */
+    fn part2(&self, input: &Vec<i32>) -> Result<Answer, AppErr> {
+        // pair up each input with the ones after it and find the three which sum to 2020
+        let (a, b, c) = input.iter()
+            .enumerate()
+            .flat_map(|(i, &a)| pairs(&input[i + 1..]).map(move |(&b, &c)| (a, b, c)))
+            .find(|(a, b, c)| a + b + c == 2020)
+            .ok_or_else(|| AppErr::no_solution("No items which sum to 2020 found!"))?;
+
+        Ok((a * b * c).into())
+    }
+}
@@ -0,0 +1,151 @@
+extern crate util;
+
+use util::error::{ AppErr };
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Pos { Empty, Occupied, Floor }
+type WH = (isize, isize);
+type XY = (isize, isize);
+
+pub struct Seats {
+    grid: Vec<Pos>,
+    size: WH,
+}
+
+fn cardinal_offsets() -> impl Iterator<Item=XY> {
+    (-1..=1).flat_map(|x| (-1..=1).map(move |y| (x, y))).filter(|xy| *xy != (0, 0))
+}
+
+fn grid_positions((w, h): WH) -> impl Iterator<Item=XY> {
+    (0..w).flat_map(move |x| (0..h).map(move |y| (x, y)))
+}
+
+fn iterate_until_stable(grid: &[Pos], mut calc_next: impl FnMut(&[Pos], &mut [Pos])) -> Result<(usize, usize), AppErr> {
+    let mut iteration_count = 0;
+    let mut curr = grid.to_vec();
+    let mut next = curr.clone();
+    loop {
+        util::cancel::check()?;
+        calc_next(&curr, &mut next);
+        if next == curr {
+            // Grid is stable
+            break;
+        }
+        std::mem::swap(&mut curr, &mut next);
+        iteration_count += 1;
+    }
+    let occupied_count = curr.iter().filter(|p| **p == Pos::Occupied).count();
+    Ok((iteration_count, occupied_count))
+}
+
+fn get_pos_and_neighbors_part1(grid: &[Pos], (w, h): WH, (x, y): XY) -> (Pos, usize) {
+    let mut count = 0;
+    for (off_x, off_y) in cardinal_offsets() {
+        let x = x + off_x;
+        let y = y + off_y;
+        if x < 0 || x >= w || y < 0 || y >= h {
+            continue;
+        }
+        let p = grid[(y * w + x) as usize];
+        if p == Pos::Occupied {
+            count += 1;
+        }
+    }
+    let p = grid[(y * w + x) as usize];
+    (p, count)
+}
+
+fn get_pos_and_neighbors_part2(grid: &[Pos], (w, h): WH, (x, y): XY) -> (Pos, usize) {
+    let mut count = 0;
+    for (off_x, off_y) in cardinal_offsets() {
+        let mut x = x;
+        let mut y = y;
+        loop {
+            x += off_x;
+            y += off_y;
+            if x < 0 || x >= w || y < 0 || y >= h {
+                break;
+            }
+            let p = grid[(y * w + x) as usize];
+            if p == Pos::Occupied {
+                count += 1;
+                break;
+            }
+            if p == Pos::Empty {
+                break;
+            }
+        }
+    }
+    let p = grid[(y * w + x) as usize];
+    (p, count)
+}
+
+pub struct Day11;
+
+pub const SOLUTION: Registration = Registration { year: 2020, day: 11, solver: &Day11 };
+
+impl Solution for Day11 {
+    type Model<'a> = Seats;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        let input = input.lines().map(str::trim_end).collect::<Vec<_>>();
+        let w = input.first().ok_or("empty input")?.len() as isize;
+        let h = input.len() as isize;
+        let grid = input
+            .iter()
+            .flat_map(|line| line.chars().map(|c|
+                match c {
+                    '#' => Pos::Occupied,
+                    'L' => Pos::Empty,
+                     _  => Pos::Floor
+                }
+            ))
+            .collect::<Vec<_>>();
+        Ok(Seats { grid, size: (w, h) })
+    }
+
+    /*
+    --- Part One ---
+
+    It's conway's game of life.
+    Rules:
+    - If a seat is empty (L) and there are no occupied seats adjacent to it, the seat becomes occupied.
+    - If a seat is occupied (#) and four or more seats adjacent to it are also occupied, the seat becomes empty.
+    - Otherwise, the seat's state does not change.
+
+    Simulate your seating area by applying the seating rules repeatedly until no seats change state.
+    How many seats end up occupied?
+    */
+    fn part1(&self, seats: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let (w, h) = seats.size;
+        let (_, occupied_count) = iterate_until_stable(&seats.grid, |curr, next| {
+            for (x, y) in grid_positions((w, h)) {
+                let b = match get_pos_and_neighbors_part1(curr, (w, h), (x, y)) {
+                    (Pos::Empty, 0) => Pos::Occupied,
+                    (Pos::Occupied, o) if o >= 4 => Pos::Empty,
+                    (p, _) => p
+                };
+                next[(y * w + x) as usize] = b;
+            }
+        })?;
+
+        Ok(occupied_count.into())
+    }
+
+    fn part2(&self, seats: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let (w, h) = seats.size;
+        let (_, occupied_count) = iterate_until_stable(&seats.grid, |curr, next| {
+            for (x, y) in grid_positions((w, h)) {
+                let b = match get_pos_and_neighbors_part2(curr, (w, h), (x, y)) {
+                    (Pos::Empty, 0) => Pos::Occupied,
+                    (Pos::Occupied, o) if o >= 5 => Pos::Empty,
+                    (p, _) => p
+                };
+                next[(y * w + x) as usize] = b;
+            }
+        })?;
+
+        Ok(occupied_count.into())
+    }
+}
@@ -0,0 +1,158 @@
+extern crate util;
+
+use util::error::{ AppErr };
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Facing { North, South, East, West }
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Dir { Left, Right }
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Step {
+    Move(Facing, i64),
+    Turn(Dir, i64),
+    Forward(i64),
+}
+
+fn parse_step(line: &str) -> Result<Step, AppErr> {
+    let c = line.chars().next().ok_or("expected direction instruction")?;
+    let num = line[1..].parse::<i64>()?;
+    match c {
+        'N' => Ok(Step::Move(Facing::North, num)),
+        'S' => Ok(Step::Move(Facing::South, num)),
+        'E' => Ok(Step::Move(Facing::East, num)),
+        'W' => Ok(Step::Move(Facing::West, num)),
+        'L' => Ok(Step::Turn(Dir::Left, num)),
+        'R' => Ok(Step::Turn(Dir::Right, num)),
+        'F' => Ok(Step::Forward(num)),
+         c  => Err(AppErr::validation(&format!("unexpected step {}", c))),
+    }
+}
+
+fn d_move(facing: Facing, distance: i64) -> (i64, i64) {
+    match facing {
+        Facing::North => (0, distance),
+        Facing::South => (0, -distance),
+        Facing::East => (distance, 0),
+        Facing::West => (-distance, 0),
+    }
+}
+
+fn d_facing(facing: Facing, angle: i64) -> Result<Facing, AppErr> {
+    let turn = match facing {
+        Facing::North => 0,
+        Facing::East  => 90,
+        Facing::South => 180,
+        Facing::West  => 270,
+    };
+    match (angle + turn) % 360 {
+        0          => Ok(Facing::North),
+        180 | -180 => Ok(Facing::South),
+        90  | -270 => Ok(Facing::East),
+        -90 |  270 => Ok(Facing::West),
+        a   => Err(AppErr::validation(&format!("unexpected angle {}", a))),
+    }
+}
+
+pub struct Day12;
+
+pub const SOLUTION: Registration = Registration { year: 2020, day: 12, solver: &Day12 };
+
+impl Solution for Day12 {
+    type Model<'a> = Vec<Step>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        parse_lines(input, parse_step)
+    }
+
+    /*
+    --- Part One ---
+
+
+
+    Figure out where the navigation instructions lead.
+    What is the Manhattan distance between that location and the ship's starting position?
+    */
+    fn part1(&self, steps: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let mut facing = Facing::East;
+        let mut x = 0;
+        let mut y = 0;
+
+        for step in steps {
+            match step {
+                Step::Move(f, d) => {
+                    let (dx, dy) = d_move(*f, *d);
+                    x += dx;
+                    y += dy;
+                },
+                Step::Forward(d) => {
+                    let (dx, dy) = d_move(facing, *d);
+                    x += dx;
+                    y += dy;
+                },
+                Step::Turn(direction, angle) => {
+                    facing = d_facing(facing, match direction {
+                        Dir::Left  => -angle,
+                        Dir::Right => *angle,
+                    })?;
+                },
+            }
+        }
+
+        Ok((x.abs() + y.abs()).into())
+    }
+
+    /*
+    --- Part Two ---
+
+    Figure out where the navigation instructions actually lead.
+    What is the Manhattan distance between that location and the ship's starting position?
+    */
+    fn part2(&self, steps: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let mut waypoint_x = 10;
+        let mut waypoint_y = 1;
+        let mut ship_x = 0;
+        let mut ship_y = 0;
+
+        for step in steps {
+            match step {
+                Step::Move(f, d) => {
+                    // Move the waypoint
+                    let (dx, dy) = d_move(*f, *d);
+                    waypoint_x += dx;
+                    waypoint_y += dy;
+                },
+                Step::Turn(direction, angle) => {
+                    match (direction, angle) {
+                        (_, 180) => {
+                            // Rotate 180 - flip both coordinates
+                            waypoint_x *= -1;
+                            waypoint_y *= -1;
+                        },
+                        (Dir::Left,  90) | (Dir::Right, 270) => {
+                            // Rotate left - swap the coordinates and flip x
+                            std::mem::swap(&mut waypoint_x, &mut waypoint_y);
+                            waypoint_x *= -1;
+                        },
+                        (Dir::Right, 90) | (Dir::Left,  270) => {
+                            // Rotate right - swap the coordinates and flip y
+                            std::mem::swap(&mut waypoint_x, &mut waypoint_y);
+                            waypoint_y *= -1;
+                        },
+                        a => return Err(AppErr::validation(&format!("unexpected turn {:?}", a))),
+                    }
+                },
+                Step::Forward(d) => {
+                    // Move towards the waypoint
+                    ship_x += waypoint_x * d;
+                    ship_y += waypoint_y * d;
+                },
+            }
+        }
+
+        Ok((ship_x.abs() + ship_y.abs()).into())
+    }
+}
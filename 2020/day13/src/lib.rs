@@ -0,0 +1,136 @@
+extern crate util;
+
+use util::{ ConsumeIterator };
+use util::error::{ AppErr };
+use util::solution::{ Answer, Part, Registration, Solution };
+
+pub struct Notes {
+    earliest_time_to_leave: u64,
+    bus_ids: Vec<Option<u64>>,
+}
+
+fn bus_timetable(bus_id: u64) -> impl Iterator<Item=u64> {
+    (0..).map(move |i| i * bus_id)
+}
+
+struct Timetable {
+    id: u64,
+    offset: u64,
+    time: u64,
+}
+
+impl Timetable {
+    fn next(&mut self) {
+        self.time += self.id;
+    }
+}
+
+pub struct Day13;
+
+pub const SOLUTION: Registration = Registration { year: 2020, day: 13, solver: &Day13 };
+
+impl Solution for Day13 {
+    type Model<'a> = Notes;
+
+    const SLOW_PARTS: &'static [Part] = &[Part::Two];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        let mut input = input.lines().map(str::trim);
+
+        let earliest_time_to_leave = input.expect_next("earliest departure time")?.parse::<u64>()?;
+        let bus_ids = input.expect_next("bus ids")?.split(',')
+            .map(|id| match id {
+                "x" => Ok(None),
+                id => id.parse::<u64>().map(Some),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Notes { earliest_time_to_leave, bus_ids })
+    }
+
+    /*
+    --- Part One ---
+
+    What is the ID of the earliest bus you can take to the airport multiplied
+    by the number of minutes you'll need to wait for that bus?
+    */
+    fn part1(&self, notes: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let earliest_time_to_leave = notes.earliest_time_to_leave;
+        let earliest_bus = notes.bus_ids
+            .iter()
+            .filter_map(|o| *o)
+            .filter_map(|id| {
+                let first_time =
+                    bus_timetable(id)
+                        .take(1_000_000)
+                        .find(|time| *time >= earliest_time_to_leave)?;
+                Some((id, first_time))
+            })
+            .fold(None, |best, (id, time)| match best {
+                Some((_, best)) if time < best => Some((id, time)),
+                Some(b) => Some(b),
+                None => Some((id, time)),
+            })
+            .ok_or_else(|| AppErr::no_solution("unable to find earliest bus"))?;
+
+        let (id, time) = earliest_bus;
+        let time_to_wait = time - earliest_time_to_leave;
+        let puzzle_solution = time_to_wait * id;
+        Ok(puzzle_solution.into())
+    }
+
+    /*
+    --- Part Two ---
+
+    What is the earliest timestamp such that all of the listed bus IDs
+    depart at offsets matching their positions in the list?
+    */
+    fn part2(&self, notes: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let mut bus_timetables = notes.bus_ids
+            .iter()
+            .enumerate()
+            .filter_map(|(offset, &id)| {
+                let id = id?;
+                Some(Timetable { id, offset: offset as u64, time: 0 })
+            })
+            .collect::<Vec<_>>();
+
+        if bus_timetables.is_empty() {
+            return Err(AppErr::validation("no buses in service"));
+        }
+
+        // hack - hint indicates the result will likely be higher than 100_000_000_000_000
+        {
+            let hint = 100_000_000_000_000;
+            let tt = &mut bus_timetables[0];
+            tt.time = (hint / tt.id) * tt.id;
+        }
+
+        // The brute force search may never finish, so check for cancellation now and then
+        let mut steps = 0u64;
+        'outer: loop {
+            let base_time = {
+                let tt = &mut bus_timetables[0];
+                tt.next();
+                tt.time
+            };
+            for tt in bus_timetables.iter_mut().skip(1) {
+                let target_time = base_time + tt.offset;
+                while tt.time < target_time {
+                    tt.next();
+                    steps += 1;
+                    if steps.is_multiple_of(1 << 20) {
+                        util::cancel::check()?;
+                    }
+                }
+                if tt.time != target_time {
+                    continue 'outer;
+                }
+            }
+            break;
+        }
+
+        let earliest = &bus_timetables[0];
+        Ok(earliest.time.into())
+    }
+}
@@ -0,0 +1,111 @@
+extern crate util;
+
+use util::{ ConsumeIterator };
+use util::error::{ AppErr };
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Debug)]
+pub struct PasswordPolicy {
+    min: usize,
+    max: usize,
+    character: char,
+}
+
+fn parse_item(s: &str) -> Result<(PasswordPolicy, &str), AppErr> {
+    let mut parts = s.split(":");
+    // PasswordPolicy
+    let mut policy_parts = parts.expect_next("policy")?.split(['-', ' ']);
+    let policy = PasswordPolicy {
+        min: policy_parts.expect_next("min")?.parse::<usize>()?,
+        max: policy_parts.expect_next("max")?.parse::<usize>()?,
+        character: policy_parts.expect_last("character")?.chars().expect_last("character")?
+    };
+    // Password
+    let password = parts.expect_last("password")?.trim();
+    Ok((policy, password))
+}
+
+fn is_password_valid(policy: &PasswordPolicy, password: &str) -> bool {
+    let count = password.chars()
+        .filter(|c| *c == policy.character)
+        .count();
+    count <= policy.max && count >= policy.min
+}
+
+fn is_password_valid_v2(policy: &PasswordPolicy, password: &str) -> bool {
+    password.char_indices()
+        .filter(|(i, _)| *i == (policy.min - 1) || *i == (policy.max - 1))
+        .filter(|(_, c)| *c == policy.character)
+        .count() == 1
+}
+
+pub struct Day2;
+
+pub const SOLUTION: Registration = Registration { year: 2020, day: 2, solver: &Day2 };
+
+impl Solution for Day2 {
+    type Model<'a> = Vec<(PasswordPolicy, &'a str)>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        parse_lines(input, parse_item)
+    }
+
+    /*
+    --- Part One ---
+    Their password database seems to be a little corrupted: some of the passwords wouldn't
+    have been allowed by the Official Toboggan Corporate Policy that was in effect when they
+    were chosen.
+
+    To try to debug the problem, they have created a list (your puzzle input) of passwords
+    (according to the corrupted database) and the corporate policy when that password was set.
+
+    For example, suppose you have the following list:
+
+        1-3 a: abcde
+        1-3 b: cdefg
+        2-9 c: ccccccccc
+
+    Each line gives the password policy and then the password. The password policy indicates
+    the lowest and highest number of times a given letter must appear for the password to be
+    valid. For example, 1-3 a means that the password must contain a at least 1 time and at
+    most 3 times.
+
+    In the above example, 2 passwords are valid. The middle password, cdefg, is not; it
+    contains no instances of b, but needs at least 1. The first and third passwords are
+    valid: they contain one a or nine c, both within the limits of their respective policies.
+
+    How many passwords are valid according to their policies?
+    */
+    fn part1(&self, input: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let valid_count = input.iter()
+            .filter(|(policy, pass)| is_password_valid(policy, pass))
+            .count();
+
+        Ok(valid_count.into())
+    }
+
+    /*
+    --- Part Two ---
+    Each policy actually describes two positions in the password, where 1 means the first
+    character, 2 means the second character, and so on. (Be careful; Toboggan Corporate
+    Policies have no concept of "index zero"!) Exactly one of these positions must
+    contain the given letter. Other occurrences of the letter are irrelevant for the
+    purposes of policy enforcement.
+
+    Given the same example list from above:
+
+        1-3 a: abcde is valid: position 1 contains a and position 3 does not.
+        1-3 b: cdefg is invalid: neither position 1 nor position 3 contains b.
+        2-9 c: ccccccccc is invalid: both position 2 and position 9 contain c.
+
+    How many passwords are valid according to the new interpretation of the policies?
+    */
+    fn part2(&self, input: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let valid_count = input.iter()
+            .filter(|(policy, pass)| is_password_valid_v2(policy, pass))
+            .count();
+
+        Ok(valid_count.into())
+    }
+}
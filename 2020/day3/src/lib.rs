@@ -0,0 +1,102 @@
+extern crate util;
+
+use std::collections::{ HashMap };
+
+use util::error::{ AppErr };
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Pos { Gap, Tree }
+
+fn count_trees_on_slope(input: &HashMap<(usize, usize), Pos>, slope: (usize, usize)) -> Result<u32, AppErr> {
+    let (x_max, y_max) = *input.keys().max().ok_or("zero-sized grid")?;
+    let (x_delta, y_delta) = slope;
+
+    let (mut x_actual, mut y_actual) = (0, 0);
+    let mut tree_count = 0;
+    loop {
+        // count the tree
+        let x = x_actual % (x_max + 1);
+        let y = y_actual;
+        if Pos::Tree == *input.get(&(x, y)).unwrap_or(&Pos::Gap) {
+            tree_count += 1;
+        }
+        // move
+        x_actual += x_delta;
+        y_actual += y_delta;
+        // check for end of slope
+        if y_actual > y_max {
+            break;
+        }
+    }
+
+    Ok(tree_count)
+}
+
+pub struct Day3;
+
+pub const SOLUTION: Registration = Registration { year: 2020, day: 3, solver: &Day3 };
+
+impl Solution for Day3 {
+    // NOTE: Top left is 1, 1
+    type Model<'a> = HashMap<(usize, usize), Pos>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        input.lines()
+            .enumerate()
+            .flat_map(|(y, line)| line.trim_end().char_indices()
+                .map(move |(x, c)| {
+                    let p = match c {
+                        '.' => Pos::Gap,
+                        '#' => Pos::Tree,
+                        _ => return Err(AppErr::from_debug("unexpected input", &(x, y, c))),
+                    };
+                    Ok(((x, y), p))
+                }))
+            .collect()
+    }
+
+    /*
+    --- Part One ---
+
+        Given a grid of trees (#) and open spaces (.) which repeats infinitely to right.
+        Input is the first chunk of this repeating pattern.
+
+        Beginning from the top-left open space, start by counting all the trees
+        you would encounter for the slope right 3, down 1 - how many trees would
+        you encounter?
+    */
+    fn part1(&self, input: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        Ok(count_trees_on_slope(input, (3, 1))?.into())
+    }
+
+    /*
+    --- Part Two ---
+    Time to check the rest of the slopes - you need to minimize the probability of a sudden
+    arboreal stop, after all.
+
+    Determine the number of trees you would encounter if, for each of the following slopes,
+    you start at the top-left corner and traverse the map all the way to the bottom:
+
+        Right 1, down 1.
+        Right 3, down 1. (This is the slope you already checked.)
+        Right 5, down 1.
+        Right 7, down 1.
+        Right 1, down 2.
+
+    In the above example, these slopes would find 2, 7, 3, 4, and 2 tree(s) respectively;
+    multiplied together, these produce the answer 336.
+
+    What do you get if you multiply together the number of trees encountered on each of the listed slopes?
+    */
+    fn part2(&self, input: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let part_2_result =
+            count_trees_on_slope(input, (1, 1))? *
+            count_trees_on_slope(input, (3, 1))? *
+            count_trees_on_slope(input, (5, 1))? *
+            count_trees_on_slope(input, (7, 1))? *
+            count_trees_on_slope(input, (1, 2))?;
+
+        Ok(part_2_result.into())
+    }
+}
@@ -0,0 +1,163 @@
+extern crate util;
+
+use std::collections::{ HashMap };
+
+use util::{ ConsumeIterator };
+use util::error::{ AppErr };
+use util::solution::{ Answer, Registration, Solution };
+
+pub type PassportData<'a> = HashMap<&'a str, &'a str>;
+
+fn try_read_passport<'a>(input: &mut dyn Iterator<Item=&'a str>) -> Result<Option<PassportData<'a>>, AppErr> {
+    let hash =  input
+        .take_while(|line| !line.trim().is_empty())
+        .flat_map(|line| line.split(" "))
+        .map(|segment| {
+            let mut pair = segment.split(":");
+            let key = pair.expect_next("key")?.trim();
+            let value = pair.expect_last("value")?.trim();
+            Ok((key, value))
+        })
+        .collect::<Result<HashMap<_, _>, AppErr>>()?;
+
+    if hash.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(hash))
+}
+
+fn is_passport_valid_part_1(passport: &PassportData) -> bool {
+    // NOTE: cid is optional
+    passport.get("byr").is_some() &&
+    passport.get("iyr").is_some() &&
+    passport.get("eyr").is_some() &&
+    passport.get("hgt").is_some() &&
+    passport.get("hcl").is_some() &&
+    passport.get("ecl").is_some() &&
+    passport.get("pid").is_some()
+}
+
+fn is_passport_valid_part_2(passport: &PassportData) -> bool {
+    fn validate_year(year: &str, min: i32, max: i32) -> bool {
+        year.len() == 4 &&
+        year.parse::<i32>()
+            .map(|year| year >= min && year <= max)
+            .unwrap_or(false)
+    }
+    fn validate_height(height: &str) -> bool {
+        enum Measure { Cm, In }
+        struct Height(u32, Measure);
+        fn try_parse_height(s: &str) -> Result<Height, AppErr> {
+            let unit = match s {
+                u if u.ends_with("cm") => Ok(Measure::Cm),
+                u if u.ends_with("in") => Ok(Measure::In),
+                u => Err(AppErr::from_debug("invalid measure", &u)),
+            }?;
+            let value = s[..s.len() - 2].parse::<u32>()?;
+            Ok(Height(value, unit))
+        }
+        try_parse_height(height)
+            .map(|h| match h {
+                Height(v, Measure::Cm) => (150..=193).contains(&v),
+                Height(v, Measure::In) => (59..=76).contains(&v),
+            })
+            .unwrap_or(false)
+    }
+    fn validate_hair_color(color: &str) -> bool {
+        color.starts_with('#') && {
+            let hex = &color[1..];
+            hex.len() == 6 &&
+            hex.chars().all(|c| c.is_ascii_hexdigit())
+        }
+    }
+    fn validate_eye_color(color: &str) -> bool {
+        matches!(color, "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth")
+    }
+    fn validate_passport_id(pid: &str) -> bool {
+        pid.len() == 9 &&
+        pid.parse::<i32>().is_ok()
+    }
+
+    let byr = passport.get("byr").filter(|s| validate_year(s, 1920, 2002));
+    let iyr = passport.get("iyr").filter(|s| validate_year(s, 2010, 2020));
+    let eyr = passport.get("eyr").filter(|s| validate_year(s, 2020, 2030));
+    let hgt = passport.get("hgt").filter(|s| validate_height(s));
+    let hcl = passport.get("hcl").filter(|s| validate_hair_color(s));
+    let ecl = passport.get("ecl").filter(|s| validate_eye_color(s));
+    let pid = passport.get("pid").filter(|s| validate_passport_id(s));
+    // NOTE: cid is optional
+
+    byr.and(iyr).and(eyr).and(hgt).and(hcl).and(ecl).and(pid).is_some()
+}
+
+pub struct Day4;
+
+pub const SOLUTION: Registration = Registration { year: 2020, day: 4, solver: &Day4 };
+
+impl Solution for Day4 {
+    type Model<'a> = Vec<PassportData<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        let mut input_reader = input.lines();
+        let mut passports = Vec::new();
+        while let Some(passport) = try_read_passport(&mut input_reader)? {
+            passports.push(passport);
+        }
+        Ok(passports)
+    }
+
+    /*
+    --- Part One ---
+
+    The automatic passport scanners are slow because they're having trouble
+    detecting which passports have all required fields.
+    The expected fields are as follows:
+
+        byr (Birth Year)
+        iyr (Issue Year)
+        eyr (Expiration Year)
+        hgt (Height)
+        hcl (Hair Color)
+        ecl (Eye Color)
+        pid (Passport ID)
+        cid (Country ID)
+
+    Passport data is validated in batch files (your puzzle input).
+    Each passport is represented as a sequence of key:value pairs separated
+    by spaces or newlines. Passports are separated by blank lines.
+
+    E.g. one passport:
+        ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+        byr:1937 iyr:2017 cid:147 hgt:183cm
+
+    Count the number of valid passports - those that have all required fields.
+    Treat cid as optional. In your batch file, how many passports are valid?
+    */
+    fn part1(&self, passports: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let valid_count_part_1 = passports.iter().filter(|p| is_passport_valid_part_1(p)).count();
+        Ok(valid_count_part_1.into())
+    }
+
+    /*
+    --- Part Two ---
+    You can continue to ignore the cid field, but each other field has strict
+    rules about what values are valid for automatic validation:
+
+    byr (Birth Year) - four digits; at least 1920 and at most 2002.
+    iyr (Issue Year) - four digits; at least 2010 and at most 2020.
+    eyr (Expiration Year) - four digits; at least 2020 and at most 2030.
+    hgt (Height) - a number followed by either cm or in:
+        If cm, the number must be at least 150 and at most 193.
+        If in, the number must be at least 59 and at most 76.
+    hcl (Hair Color) - a # followed by exactly six characters 0-9 or a-f.
+    ecl (Eye Color) - exactly one of: amb blu brn gry grn hzl oth.
+    pid (Passport ID) - a nine-digit number, including leading zeroes.
+    cid (Country ID) - ignored, missing or not.
+
+    */
+    fn part2(&self, passports: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let valid_count_part_2 = passports.iter().filter(|p| is_passport_valid_part_2(p)).count();
+        Ok(valid_count_part_2.into())
+    }
+}
@@ -0,0 +1,116 @@
+extern crate util;
+
+use std::collections::{ HashMap };
+
+use util::error::{ AppErr };
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Debug)]
+struct AnswerGroup {
+    answers: Vec<Vec<char>>,
+}
+
+fn try_read_answer_group(input: &mut dyn Iterator<Item=&str>) -> Result<Option<AnswerGroup>, AppErr> {
+
+    let answers = input
+        .take_while(|line| !line.trim().is_empty())
+        .map(|line| line.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    if answers.is_empty() {
+        return Ok(None);
+    }
+    let answer_group = AnswerGroup {
+        answers
+    };
+    Ok(Some(answer_group))
+}
+
+pub struct AnswerMap {
+    persons: u32,
+    answer_counts: HashMap<char, u32>,
+}
+
+pub struct Day6;
+
+pub const SOLUTION: Registration = Registration { year: 2020, day: 6, solver: &Day6 };
+
+impl Solution for Day6 {
+    type Model<'a> = Vec<AnswerMap>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        let mut input_reader = input.lines();
+        let mut answer_groups = Vec::new();
+        while let Some(group) = try_read_answer_group(&mut input_reader)? {
+            answer_groups.push(group);
+        }
+
+        let answer_counts =
+            answer_groups.iter()
+                .map(|g| {
+                    let mut answer_counts = HashMap::new();
+                    for answers in &g.answers {
+                        for key in answers {
+                            *answer_counts.entry(*key).or_insert(0) += 1;
+                        }
+                    }
+                    AnswerMap {
+                        persons: g.answers.len() as u32,
+                        answer_counts,
+                    }
+                })
+                .collect::<Vec<_>>();
+
+        Ok(answer_counts)
+    }
+
+    /*
+    --- Part One ---
+
+    For each of the people in their group, you write down the questions for which they answer "yes", one per line.
+    For example:
+
+        abcx
+        abcy
+        abcz
+
+    In this group, there are 6 questions to which anyone answered "yes": a, b, c, x, y, and z.
+    (Duplicate answers to the same question don't count extra; each question counts at most once.)
+
+
+
+    For each group, count the number of questions to which anyone answered "yes". What is the sum of those counts?
+
+    */
+    fn part1(&self, answer_counts: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let group_answer_counts_sum =
+            answer_counts.iter()
+                .map(|amap| {
+                    let questions_answered = amap.answer_counts.keys().len() as u32;
+                    questions_answered
+                })
+                .sum::<u32>();
+
+        Ok(group_answer_counts_sum.into())
+    }
+
+    /*
+    --- Part 2 ---
+
+    You don't need to identify the questions to which anyone answered "yes";
+    you need to identify the questions to which everyone answered "yes"!
+    */
+    fn part2(&self, answer_counts: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let group_answer_counts_sum =
+            answer_counts.iter()
+                .map(|amap| {
+                    let questions_answered_by_everyone = amap.answer_counts.values()
+                        .filter(|&&value| value == amap.persons)
+                        .count() as u32;
+                    questions_answered_by_everyone
+                })
+                .sum::<u32>();
+
+        Ok(group_answer_counts_sum.into())
+    }
+}
@@ -0,0 +1,153 @@
+extern crate util;
+
+use std::collections::{ HashSet };
+
+use util::parse::{ Input, ParseResult, ParseResultEx };
+use util::error::{ AppErr };
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Op { Acc, Jmp, Nop }
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Instruction(Op, isize);
+
+fn parse_instruction<'a>(input: Input<'a>) -> ParseResult<'a, Instruction> {
+    let (input, op) = input.parse_token("acc").map_val(|_| Op::Acc)
+        .or_try(|| input.parse_token("jmp").map_val(|_| Op::Jmp))
+        .or_try(|| input.parse_token("nop").map_val(|_| Op::Nop))?;
+
+    enum Sign { Neg, Pos }
+    let (input, sign) = input.parse_token("+").val(Sign::Pos)
+        .or_try(|| input.parse_token("-").val(Sign::Neg))?;
+
+    let (input, value) = input.parse_i32()?;
+    let value = match sign {
+        Sign::Neg => (-value) as isize,
+        Sign::Pos => value as isize,
+    };
+    Ok((input, Instruction(op, value)))
+}
+
+enum Step { Continue, Break }
+
+enum Outcome {
+    // The program ran past its last instruction
+    Terminated(isize),
+    // The step callback stopped the program
+    Interrupted(isize),
+}
+
+fn run_program(instructions: &[Instruction], mut step: impl FnMut(Instruction, usize) -> Step) -> Outcome {
+    let mut acc: isize = 0;
+    let mut pc: usize = 0;
+    loop {
+        let inst = instructions[pc];
+        if let Step::Break = step(inst, pc) {
+            return Outcome::Interrupted(acc);
+        }
+        match inst {
+            Instruction(Op::Acc, value) => {
+                acc += value;
+                pc += 1;
+            },
+            Instruction(Op::Jmp, value) => {
+                pc = (value + pc as isize) as usize;
+            },
+            Instruction(Op::Nop, _) => {
+                pc += 1;
+            },
+        }
+        // check for program termination
+        if pc >= instructions.len() {
+            return Outcome::Terminated(acc);
+        }
+    }
+}
+
+// Runs the program until it terminates or repeats an instruction
+fn test_program(instructions: &[Instruction]) -> Outcome {
+    let mut seen = HashSet::new();
+    run_program(instructions, |_, pc| {
+        if seen.insert(pc) { Step::Continue } else { Step::Break }
+    })
+}
+
+/// Flip Nop to Jmp and Jmp to Nop, ignore Acc
+fn flip_instruction(inst: &mut Instruction) {
+    inst.0 = match inst.0 {
+        Op::Nop => Op::Jmp,
+        Op::Jmp => Op::Nop,
+        Op::Acc => Op::Acc,
+    };
+}
+
+pub struct Day8;
+
+pub const SOLUTION: Registration = Registration { year: 2020, day: 8, solver: &Day8 };
+
+impl Solution for Day8 {
+    type Model<'a> = Vec<Instruction>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        parse_lines(input, |line| parse_instruction(Input::new(line)).map(|x| x.1))
+    }
+
+    /*
+    --- Part One ---
+
+    The boot code is represented as a text file with one instruction per line of text.
+    Each instruction consists of an operation (acc, jmp, or nop) and an argument (a signed number like +4 or -20).
+
+    - `acc`
+        increases or decreases a single global value called the accumulator by the
+        value given in the argument. For example, acc +7 would increase the accumulator
+        by 7. The accumulator starts at 0. After an acc instruction, the instruction
+        immediately below it is executed next.
+    - `jmp`
+        jumps to a new instruction relative to itself. The next instruction to execute
+        is found using the argument as an offset from the jmp instruction; for example,
+        jmp +2 would skip the next instruction, jmp +1 would continue to the instruction
+        immediately below it, and jmp -20 would cause the instruction 20 lines above to
+        be executed next.
+    - `nop`
+        stands for No OPeration - it does nothing.
+        The instruction immediately below it is executed next.
+
+    Run your copy of the boot code.
+    Immediately before any instruction is executed a second time, what value is in the accumulator?
+    */
+    fn part1(&self, instructions: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        match test_program(instructions) {
+            Outcome::Interrupted(acc) => Ok(acc.into()),
+            Outcome::Terminated(_) => Err(AppErr::no_solution("program terminated without repeating a command")),
+        }
+    }
+
+    /*
+    --- Part Two ---
+
+    The program is supposed to terminate by attempting to execute an instruction immediately
+    after the last instruction in the file. By changing exactly one jmp or nop, you can
+    repair the boot code and make it terminate correctly.
+
+    Fix the program so that it terminates normally by changing exactly one jmp (to nop)
+    or nop (to jmp). What is the value of the accumulator after the program terminates?
+    */
+    fn part2(&self, instructions: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let mut instructions = instructions.clone();
+        for i in 0..instructions.len() {
+
+            flip_instruction(&mut instructions[i]);
+
+            if let Outcome::Terminated(acc) = test_program(&instructions) {
+                return Ok(acc.into());
+            }
+
+            flip_instruction(&mut instructions[i]);
+        }
+
+        Err(AppErr::no_solution("no single flipped instruction fixes the program"))
+    }
+}
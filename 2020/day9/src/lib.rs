@@ -0,0 +1,94 @@
+extern crate util;
+
+use util::{ ConsumeIterator };
+use util::error::{ AppErr };
+use util::input::parse_lines;
+use util::iter::pairs;
+use util::params::Param;
+use util::solution::{ Answer, Registration, Solution };
+
+const PREAMBLE_SIZE: Param = Param::new("preamble_size", "25", "The number of previous numbers which each number must be a sum of two of");
+
+fn find_first_number_without_sum_in_preamble(input: &[u64], preamble_size: usize) -> Result<u64, AppErr> {
+    for i in preamble_size..input.len() {
+        let v = input[i];
+        let preamble = &input[i-preamble_size..i];
+        let x = pairs(preamble)
+            .find(|&(&a, &b)| a + b == v);
+        if x.is_none() {
+            return Ok(v);
+        }
+    }
+    Err(AppErr::no_solution("Unable to find number without sum in preamble"))
+}
+
+fn find_range_which_sums_to_invalid_number(input: &[u64], num: u64) -> Result<(u64, u64), AppErr> {
+    for a in 0..(input.len() - 1) {
+        let mut sum = input[a];
+        for b in (a + 1)..input.len() {
+            sum += input[b];
+            if sum == num {
+                let range = &input[a..=b];
+                let (min, max) = range.iter().copied().minmax().ok_or("empty range")?;
+                return Ok((min, max));
+            }
+        }
+    }
+    Err(AppErr::no_solution("Unable to find range which sums to input"))
+}
+
+pub struct Day9;
+
+pub const SOLUTION: Registration = Registration { year: 2020, day: 9, solver: &Day9 };
+
+impl Solution for Day9 {
+    type Model<'a> = Vec<u64>;
+
+    const PARAMS: &'static [Param] = &[PREAMBLE_SIZE];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        parse_lines(input, |line| line.parse::<u64>())
+    }
+
+    /*
+    --- Part One ---
+
+    XMAS starts by transmitting a preamble of 25 numbers.
+    After that, each number you receive should be the sum of any two of the 25 immediately previous numbers.
+    The two numbers will have different values, and there might be more than one such pair.
+
+    The first step of attacking the weakness in the XMAS data is to find the first number in the list
+    (after the preamble) which is not the sum of two of the 25 numbers before it.
+    What is the first number that does not have this property?
+    */
+    fn part1(&self, input: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        Ok(find_first_number_without_sum_in_preamble(input, PREAMBLE_SIZE.parse()?)?.into())
+    }
+
+    /*
+    --- Part Two ---
+
+    The final step in breaking the XMAS encryption relies on the invalid number you just
+    found: you must find a contiguous set of at least two numbers in your list which sum
+    to the invalid number from step 1.
+
+    To find the encryption weakness, add together the smallest and largest number in this
+    contiguous range. What is the encryption weakness in your XMAS-encrypted list of numbers?
+    */
+    fn part2(&self, input: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let invalid_number = find_first_number_without_sum_in_preamble(input, PREAMBLE_SIZE.parse()?)?;
+        let (min, max) = find_range_which_sums_to_invalid_number(input, invalid_number)?;
+        Ok((min + max).into())
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    use super::*;
+    use util::aoc_example;
+
+    #[test]
+    fn example() {
+        aoc_example!(include_str!("../tests/fixtures/part1-1.txt"), params(preamble_size = 5), part1 = 127, part2 = 62);
+    }
+}
@@ -0,0 +1,58 @@
+extern crate util;
+
+use util::{ ConsumeIterator };
+use util::error::{ AppErr };
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+fn parse_item(s: &str) -> Result<i32, AppErr> {
+    let i = s.parse::<i32>()?;
+    Ok(i)
+}
+
+fn count_increases(mut seq: impl Iterator<Item=i32>) -> u32 {
+    let mut curr = match seq.next() {
+        Some(v) => v,
+        None    => return 0,
+    };
+    let mut count = 0;
+
+    for v in seq {
+        if v > curr {
+            count += 1;
+        }
+        curr = v;
+    }
+
+    count
+}
+
+pub struct Day1;
+
+pub const SOLUTION: Registration = Registration { year: 2021, day: 1, solver: &Day1 };
+
+impl Solution for Day1 {
+    type Model<'a> = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>, AppErr> {
+        parse_lines(input, parse_item)
+    }
+
+    /*
+    --- Part One ---
+    count the number of times a depth measurement increases from the previous measurement.
+    (There is no measurement before the first measurement.)
+    In the example above, the changes are as follows:
+    */
+    fn part1(&self, input: &Vec<i32>) -> Result<Answer, AppErr> {
+        Ok(count_increases(input.iter().copied()).into())
+    }
+
+    fn part2(&self, input: &Vec<i32>) -> Result<Answer, AppErr> {
+        let seq = input.iter()
+            .array_windows::<3>()
+            .map(|[a, b, c]| a + b + c);
+
+        Ok(count_increases(seq).into())
+    }
+}
@@ -0,0 +1,78 @@
+extern crate util;
+
+use util::error::{ AppErr };
+use util::input::parse_lines;
+use util::solution::{ Answer, Registration, Solution };
+
+fn parse_instruction(s: &str) -> Result<(&str, i32), AppErr> {
+    let mut parts = s.split(" ");
+    Ok((parts.next().ok_or("Expected direction")?,
+        parts.next().and_then(|s| s.parse::<i32>().ok()).ok_or("Invalid distance")?))
+}
+
+pub struct Day2;
+
+pub const SOLUTION: Registration = Registration { year: 2021, day: 2, solver: &Day2 };
+
+impl Solution for Day2 {
+    type Model<'a> = Vec<(&'a str, i32)>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        parse_lines(input, parse_instruction)
+    }
+
+    /*
+        --- Part One ---
+        Calculate the horizontal position and depth you would have after following the planned course.
+        What do you get if you multiply your final horizontal position by your final depth?
+    */
+    fn part1(&self, input: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let mut h = 0;
+        let mut d = 0;
+
+        for &(inst, dist) in input.iter() {
+            match inst {
+                "forward" => h += dist,
+                "up"      => d -= dist,
+                "down"    => d += dist,
+                _         => return Err(AppErr::from("Invalid instruction")),
+            }
+        }
+
+        Ok((h * d).into())
+    }
+
+    /*
+        --- Part Two ---
+
+        In addition to horizontal position and depth, you'll also need to track a third value, aim, which also starts at 0. The commands also mean something entirely different than you first thought:
+
+        - down X increases your aim by X units.
+        - up X decreases your aim by X units.
+        - forward X does two things:
+            - It increases your horizontal position by X units.
+            - It increases your depth by your aim multiplied by X.
+
+        Using this new interpretation of the commands, calculate the horizontal position and depth you would have after
+        following the planned course. What do you get if you multiply your final horizontal position by your final depth?
+    */
+    fn part2(&self, input: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let mut h = 0;
+        let mut d = 0;
+        let mut aim = 0;
+
+        for &(inst, dist) in input.iter() {
+            match inst {
+                "forward" => {
+                    h += dist;
+                    d += aim * dist;
+                }
+                "up"      => aim -= dist,
+                "down"    => aim += dist,
+                _         => return Err(AppErr::from("Invalid instruction")),
+            }
+        }
+
+        Ok((h * d).into())
+    }
+}
@@ -0,0 +1,144 @@
+extern crate util;
+
+use util::error::AppErr;
+use util::solution::{ Answer, Registration, Solution };
+
+fn count_bits_at_index(input: &[&str], at_bit_index: usize) -> (usize, usize) {
+    let mut ones_count = 0;
+    let mut zeroes_count = 0;
+    for line in input.iter() {
+        match &line[at_bit_index..=at_bit_index] {
+            "1" => ones_count += 1,
+            "0" => zeroes_count += 1,
+            _ => unreachable!(),
+        }
+    }
+    (ones_count, zeroes_count)
+}
+
+enum FindBehavior {
+    MostCommonPreferOnes,
+    LeastCommonPreferZeroes,
+}
+
+fn find_entry_with_most_common_bits<'a>(
+    input: &[&'a str],
+    behavior: FindBehavior,
+) -> Option<&'a str> {
+    let column_count = input.first()?.len();
+    let mut remaining: Option<Vec<&'a str>> = None;
+    for i in 0..column_count {
+        let candidates = remaining.as_deref().unwrap_or(input);
+        let (ones, zeroes) = count_bits_at_index(candidates, i);
+        let filter_char = match behavior {
+            FindBehavior::MostCommonPreferOnes => {
+                if ones >= zeroes {
+                    "1"
+                } else {
+                    "0"
+                }
+            }
+            FindBehavior::LeastCommonPreferZeroes => {
+                if zeroes <= ones {
+                    "0"
+                } else {
+                    "1"
+                }
+            }
+        };
+        // Filter to strings which match the character at this position
+        let filtered = candidates.iter()
+            .copied()
+            .filter(|line| &line[i..=i] == filter_char)
+            .collect::<Vec<_>>();
+        // Got one? Found our result
+        if filtered.len() == 1 {
+            return filtered.into_iter().next();
+        }
+        remaining = Some(filtered);
+    }
+    None
+}
+
+fn input_binary_to_usize(line: &str) -> usize {
+    let len = line.len();
+    line.char_indices().fold(0_usize, |r, (i, c)| {
+        r | match c {
+            '1' => 1 << (len - i - 1),
+            '0' => 0,
+            _ => unreachable!(),
+        }
+    })
+}
+
+pub struct Day3;
+
+pub const SOLUTION: Registration = Registration { year: 2021, day: 3, solver: &Day3 };
+
+impl Solution for Day3 {
+    type Model<'a> = Vec<&'a str>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        let input = input.lines().map(str::trim).collect::<Vec<_>>();
+        // Every entry is a binary number of the same width
+        let column_count = input.first().ok_or("Empty input")?.len();
+        for line in &input {
+            if line.len() != column_count || line.chars().any(|c| c != '0' && c != '1') {
+                return Err(AppErr::validation(&format!("Invalid entry: {:?}", line)));
+            }
+        }
+        Ok(input)
+    }
+
+    /*
+        --- Part One ---
+        Gamma rate: For each column, select the most common bit
+        Epsilon rate: For each column, select the least common bit
+
+        E.g.
+        010   G = 110
+        110   E = 001
+        101
+
+        Use the binary numbers in your diagnostic report to calculate the gamma rate and epsilon rate, then multiply them together.
+        What is the power consumption of the submarine? (Be sure to represent your answer in decimal, not binary.)
+    */
+    fn part1(&self, input: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let column_count = input.first().ok_or("Empty input")?.len();
+
+        let mut gamma = 0;
+        let mut epsilon = 0;
+
+        for i in 0..column_count {
+            let (ones, zeroes) = count_bits_at_index(input, i);
+            // Note: bits in input are LEAST SIGNIFICANT first
+            //  input("10110") => usize(01101)
+            let bit_index = column_count - i - 1;
+            gamma |= if ones > zeroes { 1 << bit_index } else { 0 };
+            epsilon |= if zeroes > ones { 1 << bit_index } else { 0 };
+        }
+
+        Ok((gamma * epsilon).into())
+    }
+
+    /*
+        --- Part Two ---
+        https://adventofcode.com/2021/day/3#part2
+
+        Use the binary numbers in your diagnostic report to calculate the oxygen generator rating and CO2 scrubber rating,
+        then multiply them together. What is the life support rating of the submarine?
+        (Be sure to represent your answer in decimal, not binary.)
+    */
+    fn part2(&self, input: &Self::Model<'_>) -> Result<Answer, AppErr> {
+        let oxy_generator_rating =
+            find_entry_with_most_common_bits(input, FindBehavior::MostCommonPreferOnes)
+                .map(input_binary_to_usize)
+                .ok_or_else(|| AppErr::no_solution("Unable to find oxygen generator rating"))?;
+        let co2_scrubber_rating =
+            find_entry_with_most_common_bits(input, FindBehavior::LeastCommonPreferZeroes)
+                .map(input_binary_to_usize)
+                .ok_or_else(|| AppErr::no_solution("Unable to find CO2 scrubber rating"))?;
+
+        Ok((oxy_generator_rating * co2_scrubber_rating).into())
+    }
+}
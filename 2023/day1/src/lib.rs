@@ -0,0 +1,79 @@
+extern crate util;
+
+use util::error::AppErr;
+use util::input::parse_lines;
+use util::solution::{Answer, Registration, Solution};
+
+pub struct Day1;
+
+pub const SOLUTION: Registration = Registration { year: 2023, day: 1, solver: &Day1 };
+
+impl Solution for Day1 {
+    // Each part reads the calibration values differently
+    type Model<'a> = &'a str;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<&'a str, AppErr> {
+        Ok(input)
+    }
+
+    /*
+    --- Part One ---
+    Consider your entire calibration document. What is the sum of all of the calibration values?
+    */
+
+    fn part1(&self, input: &&str) -> Result<Answer, AppErr> {
+        fn parse_item_part_1(s: &str) -> Result<u32, AppErr> {
+            let mut numbers = s.chars()
+                .filter_map(|c| c.to_digit(10));
+
+            let first = numbers.next().ok_or("No calibration numbers in input")?;
+            let last = numbers.next_back().unwrap_or(first);
+            Ok((first * 10) + last)
+        }
+
+        let input = parse_lines(input, parse_item_part_1)?;
+
+        let sum = input.iter().sum::<u32>();
+        Ok(sum.into())
+    }
+
+    /*
+    --- Part Two ---
+    Your calculation isn't quite right. It looks like some of the digits are actually spelled out with letters: one, two, three, four, five, six, seven, eight, and nine also count as valid "digits".
+    What is the sum of all of the calibration values?
+    */
+
+    fn part2(&self, input: &&str) -> Result<Answer, AppErr> {
+        const DIGIT_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+        fn extract_digit(s: &str) -> Option<u32> {
+            // Scan for 0-9
+            if let Some(d) = s.chars().next().and_then(|c| c.to_digit(10)) {
+                return Some(d);
+            }
+            // Scan for word
+            for (i, n) in DIGIT_WORDS.iter().enumerate() {
+                if s.starts_with(n) {
+                    return Some((i + 1) as u32);
+                }
+            }
+            // No digit
+            None
+        }
+
+        fn parse_item_part_2(s: &str) -> Result<u32, AppErr> {
+            // Need to find overlapping matches
+            let mut numbers = (0..s.len())
+                .filter_map(|offset| extract_digit(&s[offset..]));
+
+            let first = numbers.next().ok_or("No calibration numbers in input")?;
+            let last = numbers.next_back().unwrap_or(first);
+            Ok((first * 10) + last)
+        }
+
+        let input = parse_lines(input, parse_item_part_2)?;
+
+        let sum = input.iter().sum::<u32>();
+        Ok(sum.into())
+    }
+}
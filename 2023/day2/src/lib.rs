@@ -0,0 +1,120 @@
+extern crate util;
+
+use util::error::{AppErr, ErrorKind};
+use util::input::parse_lines;
+use util::solution::{Answer, Registration, Solution};
+
+use winnow::{ Parser, PResult };
+
+#[derive(Debug)]
+struct GameRound {
+    red: usize,
+    green: usize,
+    blue: usize,
+}
+
+#[derive(Debug)]
+pub struct GameRecord {
+    id: usize,
+    rounds: Vec<GameRound>,
+}
+
+// Let's give winnow a go
+
+fn parse_game(input: &mut &str) -> PResult<GameRecord> {
+    use winnow::ascii::{digit1, space0, space1, alpha1};
+    use winnow::token::literal;
+    use winnow::combinator::{preceded, separated};
+
+    // E.g.
+    // Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+
+    fn parse_score<'a>(input: &mut &'a str) -> PResult<(&'a str, usize)> {
+        let (_, digit, _, label, _) = (space0, digit1, space1, alpha1, space0).parse_next(input)?;
+        Ok((label, digit.parse().unwrap()))
+    }
+
+    fn parse_round(input: &mut &str) -> PResult<GameRound> {
+
+        let things: Vec<_> =
+            separated(1..=3, parse_score, literal(","))
+                .parse_next(input)?;
+
+        let (mut red, mut green, mut blue) = (0, 0, 0);
+        for (tag, score) in things {
+            match tag {
+                "red"   => red += score,
+                "green" => green += score,
+                "blue"  => blue += score,
+                _ => {}
+            }
+        }
+
+        Ok(GameRound { red, green, blue })
+    }
+
+    let id =
+        preceded(
+            (literal("Game"), space1),
+            digit1
+        )
+        .parse_next(input)?
+        .parse()
+        .unwrap();
+
+    let rounds: Vec<_> =
+        preceded(
+            (space0, literal(":"), space0),
+            separated(1.., parse_round, literal(";"))
+        )
+        .parse_next(input)?;
+
+    Ok(GameRecord { id, rounds })
+}
+
+pub struct Day2;
+
+pub const SOLUTION: Registration = Registration { year: 2023, day: 2, solver: &Day2 };
+
+impl Solution for Day2 {
+    type Model<'a> = Vec<GameRecord>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        parse_lines(input, |s| {
+            parse_game.parse(s).map_err(|err| AppErr::new("ParseError", &err.to_string()).with_kind(ErrorKind::Parse))
+        })
+    }
+
+    /*
+        --- Part One ---
+        The Elf would first like to know which games would have been possible if the bag contained only 12 red cubes, 13 green cubes, and 14 blue cubes?
+    */
+
+    fn part1(&self, input: &Vec<GameRecord>) -> Result<Answer, AppErr> {
+        let result: usize = input.iter()
+            .filter(|g| !g.rounds.iter().any(|r| r.red > 12 || r.green > 13 || r.blue > 14))
+            .map(|g| g.id)
+            .sum();
+
+        Ok(result.into())
+    }
+
+    /*
+        --- Part Two ---
+        For each game, find the minimum set of cubes that must have been present. What is the sum of the power of these sets?
+    */
+
+    fn part2(&self, input: &Vec<GameRecord>) -> Result<Answer, AppErr> {
+        let result: usize = input.iter()
+            // Find the minimum cubes required to play each game
+            .map(|g| g.rounds.iter()
+                    .map(|r| (r.red, r.green, r.blue))
+                    .reduce(|(r1, g1, b1), (r2, g2, b2)| (r1.max(r2), g1.max(g2), b1.max(b2)))
+                    .unwrap_or_default())
+            // Find the power for each minimum set
+            .map(|(r, g, b)| r * g * b)
+            .sum();
+
+        Ok(result.into())
+    }
+}
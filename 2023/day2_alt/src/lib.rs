@@ -0,0 +1,119 @@
+extern crate util;
+
+use util::error::AppErr;
+use util::input::parse_lines;
+use util::parse::{Input, ParseResult, ParseResultEx};
+use util::solution::{Answer, Registration, Solution};
+
+#[derive(Debug, Default)]
+struct GameRound {
+    red: i32,
+    green: i32,
+    blue: i32,
+}
+
+#[derive(Debug)]
+pub struct GameRecord {
+    id: i32,
+    rounds: Vec<GameRound>,
+}
+
+fn parse_game(input: Input) -> ParseResult<GameRecord> {
+    #[allow(clippy::enum_variant_names)]
+    enum _Term { EndScore, EndRound, EndRecord }
+    use _Term::*;
+
+    enum _Color { Red, Green, Blue }
+    use _Color::*;
+
+    // E.g.
+    // Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+    let (input, _) = input.parse_token("Game")?;
+    let (input, id) = input.parse_i32()?;
+    let (input, _) = input.parse_token(":")?;
+
+    // parse rounds
+    let mut rounds = Vec::new();
+    let mut input = input;
+    loop {
+        let mut round = GameRound::default();
+        // Parse scores
+        loop {
+            let next = input;
+            // {count} {color}
+            let (next, score) = next.parse_i32()?;
+            let (next, color) = next.parse_token("red").val(Red)
+                .or_try(|| next.parse_token("green").val(Green))
+                .or_try(|| next.parse_token("blue").val(Blue))?;
+            match color {
+                Red   => round.red += score,
+                Green => round.green += score,
+                Blue  => round.blue += score,
+            }
+            // scan for end of score, end of round or end of input
+            let (next, term) = next.parse_token(",").val(EndScore)
+                .or_try(|| next.parse_token(";").val(EndRound))
+                .or_try(|| next.parse_end().val(EndRecord))?;
+            input = next;
+            
+            match term {
+                EndScore => {
+                    continue;
+                },
+                EndRound => {
+                    rounds.push(round);
+                    break;
+                },
+                EndRecord => {
+                    rounds.push(round);
+                    return Ok((input, GameRecord { id, rounds }))
+                },
+            }
+        }
+    }
+}
+
+pub struct Day2;
+
+pub const SOLUTION: Registration = Registration { year: 2023, day: 2, solver: &Day2 };
+
+impl Solution for Day2 {
+    type Model<'a> = Vec<GameRecord>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+        parse_lines(input, |s| parse_game(Input::new(s)).complete())
+    }
+
+    /*
+        --- Part One ---
+        The Elf would first like to know which games would have been possible if the bag contained only 12 red cubes, 13 green cubes, and 14 blue cubes?
+    */
+
+    fn part1(&self, input: &Vec<GameRecord>) -> Result<Answer, AppErr> {
+        let result: i32 = input.iter()
+            .filter(|g| !g.rounds.iter().any(|r| r.red > 12 || r.green > 13 || r.blue > 14))
+            .map(|g| g.id)
+            .sum();
+
+        Ok(result.into())
+    }
+
+    /*
+        --- Part Two ---
+        For each game, find the minimum set of cubes that must have been present. What is the sum of the power of these sets?
+    */
+
+    fn part2(&self, input: &Vec<GameRecord>) -> Result<Answer, AppErr> {
+        let result: i32 = input.iter()
+            // Find the minimum cubes required to play each game
+            .map(|g| g.rounds.iter()
+                    .map(|r| (r.red, r.green, r.blue))
+                    .reduce(|(r1, g1, b1), (r2, g2, b2)| (r1.max(r2), g1.max(g2), b1.max(b2)))
+                    .unwrap_or_default())
+            // Find the power for each minimum set
+            .map(|(r, g, b)| r * g * b)
+            .sum();
+
+        Ok(result.into())
+    }
+}
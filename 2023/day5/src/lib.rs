@@ -0,0 +1,165 @@
+#![allow(unused)]
+
+extern crate util;
+
+use util::error::AppErr;
+use util::solution::{Answer, Registration, Solution};
+use util::parse::{ParseErr, Input, ParseResultEx, ParseResult};
+
+use rayon::iter::{ParallelIterator, IntoParallelRefIterator};
+
+fn parse_almanac(input: Input) -> Result<Almanac, ParseErr> {
+
+    fn parse_mapping(input: Input) -> ParseResult<Mapping> {
+        let (input, dest_start) = input.parse_i64()?;
+        let (input, source_start) = input.parse_i64()?;
+        let (input, length) = input.parse_i64()?;
+        let (input, ()) = input.parse_newline()?;
+        Ok((input, Mapping { source_start, dest_start, length }))
+    }
+
+    fn parse_mapping_set<'a>(input: Input<'a>) -> ParseResult<'a, MappingSet> {
+        let (input, label_from) = input.parse_alpha()?;
+        let (input, ()) = input.parse_token_sequence([ "-", "to", "-" ])?;
+        let (input, label_to) = input.parse_alpha()?;
+        let (input, ()) = input.parse_token_sequence([ "map", ":" ])?;
+        let (input, ()) = input.parse_newline()?;
+        let (input, ranges) = input.parse_repeated(parse_mapping)?;
+        Ok((input, MappingSet {
+            name: format!("{} to {}", label_from, label_to),
+            ranges
+        }))
+    }
+    
+    let (input, ())    = input.parse_token_sequence([ "seeds", ":" ])?;
+    let (input, seeds) = input.parse_repeated(|next| next.parse_i64())?;
+    let (input, ())    = input.parse_newline()?;
+    let (input, mappings) = input.parse_repeated(parse_mapping_set)?;
+    input.parse_end()?;
+
+    Ok(Almanac {
+        seeds,
+        mappings
+    })
+}
+
+#[derive(Debug)]
+struct Mapping {
+    pub source_start: i64,
+    pub dest_start: i64,
+    pub length: i64,
+}
+
+impl Mapping {
+    /// Translates {input} from the source range to the destination range
+    fn map(&self, input: i64) -> Option<i64> {
+        if input < self.source_start {
+            return None;
+        }
+        let shifted = input - self.source_start;
+        if shifted >= self.length {
+            return None;
+        }
+        Some(self.dest_start + shifted)
+    }
+}
+
+#[derive(Debug)]
+struct MappingSet {
+    name: String,
+    ranges: Vec<Mapping>,
+}
+
+impl MappingSet {
+    fn map(&self, input: i64) -> i64 {
+        self.ranges
+            .iter().filter_map(|r| r.map(input))
+            .next().unwrap_or(input)
+    }
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    mappings: Vec<MappingSet>,
+}
+
+impl Almanac {
+    fn map(&self, input: i64) -> i64 {
+        self.mappings
+            .iter()
+            .fold(input, |input, el| el.map(input))
+    }
+    
+    fn seed_ranges(&self) -> Vec<std::ops::Range<i64>> {
+        self.seeds.chunks(2)
+            .map(|w| w[0]..(w[0] + w[1]))
+            .collect()
+    }
+}
+
+/// The number of seeds mapped between each check for cancellation.
+const SEED_CHUNK: usize = 1 << 20;
+
+pub struct Day5;
+
+pub const SOLUTION: Registration = Registration { year: 2023, day: 5, solver: &Day5 };
+
+impl Solution for Day5 {
+    type Model<'a> = Almanac;
+
+    fn parse(&self, input: &str) -> Result<Almanac, AppErr> {
+        Ok(parse_almanac(Input::new(input))?)
+    }
+
+    /*
+        --- Part One ---
+        What is the lowest location number that corresponds to any of the initial seed numbers?
+    */
+
+    fn part1(&self, almanac: &Almanac) -> Result<Answer, AppErr> {
+        let result = almanac.seeds
+            .iter()
+            .map(|&input| almanac.map(input))
+            .min()
+            .ok_or_else(|| AppErr::no_solution("Expected minimum value"))?;
+
+        Ok(result.into())
+    }
+
+    /*
+        --- Part Two ---
+        The input seed numbers are actually pairs of numbers describing [start, length] ranges.
+
+        What is the lowest location number that corresponds to any of the initial seed numbers?
+    */
+
+    fn part2(&self, almanac: &Almanac) -> Result<Answer, AppErr> {
+        // Rayon's threads don't share this thread's cancellation token (see `util::cancel::check`), so each chunk of seeds checks it
+        let token = util::cancel::current();
+        let chunks = almanac
+            .seed_ranges()
+            .into_iter()
+            .flat_map(|range| {
+                let end = range.end;
+                range.step_by(SEED_CHUNK).map(move |start| start..end.min(start + SEED_CHUNK as i64))
+            })
+            .collect::<Vec<_>>();
+        let minimums = chunks
+            .par_iter()
+            .map(|chunk| {
+                if let Some(token) = &token {
+                    token.check()?;
+                }
+                Ok(chunk.clone().map(|input| almanac.map(input)).min())
+            })
+            .collect::<Result<Vec<_>, AppErr>>()?;
+        let result = minimums
+            .into_iter()
+            .flatten()
+            .min()
+            .ok_or_else(|| AppErr::no_solution("Expected minimum value"))?;
+
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,163 @@
+#![allow(unused)]
+
+extern crate util;
+
+use util::ConsumeIterator;
+use util::error::AppErr;
+use util::input::parse_lines;
+use util::solution::{Answer, Registration, Solution};
+
+type Card = i32;
+
+#[derive(Debug, Copy, Clone)]
+enum HandScore {
+    /// Five of a kind, where all five cards have the same label: AAAAA
+    FiveOfAKind = 6,
+    /// Four of a kind, where four cards have the same label and one card has a different label: AA8AA
+    FourOfAKind = 5,
+    /// Full house, where three cards have the same label, and the remaining two cards share a different label: 23332
+    FullHouse = 4,
+    /// Three of a kind, where three cards have the same label, and the remaining two cards are each different from any other card in the hand: TTT98
+    ThreeOfAKind = 3,
+    /// Two pair, where two cards share one label, two other cards share a second label, and the remaining card has a third label: 23432
+    TwoPair = 2,
+    /// One pair, where two cards share one label, and the other three cards have a different label from the pair and each other: A23A4
+    OnePair = 1,
+    /// High card, where all cards' labels are distinct: 23456
+    HighCard = 0,
+}
+
+impl HandScore {
+    fn rank(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    cards: [Card; 5],
+    score: HandScore,
+    bid: i32,
+}
+
+impl std::cmp::PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other)
+            .map(|ord| ord == std::cmp::Ordering::Equal)
+            .unwrap_or(false)
+    }
+}
+
+impl std::cmp::PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        use std::cmp::Ordering::*;
+        Some(self.score.rank().cmp(&other.score.rank())
+            .then(self.cards[0].cmp(&other.cards[0]))
+            .then(self.cards[1].cmp(&other.cards[1]))
+            .then(self.cards[2].cmp(&other.cards[2]))
+            .then(self.cards[3].cmp(&other.cards[3]))
+            .then(self.cards[4].cmp(&other.cards[4])))
+    }
+}
+
+fn score_hand(cards: &[Card; 5]) -> HandScore {
+    #[derive(Debug)]
+    struct Set {
+        card: Card,
+        count: usize,
+    }
+
+    let mut cards = *cards;
+    cards.sort_by(|a, b| a.cmp(b).reverse());
+
+    // Count groups of cards in the hand
+    let mut groups = cards.into_iter()
+        .run_lengths()
+        .map(|(card, count)| Set { card, count })
+        .collect::<Vec<_>>();
+    groups.sort_by(|a, b| a.count.cmp(&b.count).reverse());
+
+    match *groups.as_slice() {
+        [Set { count: 5, .. }]                          => HandScore::FiveOfAKind,
+        [Set { count: 4, .. }, _]                       => HandScore::FourOfAKind,
+        [Set { count: 3, .. }, Set { count: 2, .. }]    => HandScore::FullHouse,
+        [Set { count: 3, .. }, _, _]                    => HandScore::ThreeOfAKind,
+        [Set { count: 2, .. }, Set { count: 2, .. }, _] => HandScore::TwoPair,
+        [Set { count: 2, .. }, _, _, _]                 => HandScore::OnePair,
+        [Set { count: 1, .. }, _, _, _, _]              => HandScore::HighCard,
+        _ => panic!("Unexpected hand: {:?}", groups)
+    }
+}
+
+fn parse_hand(line: &str) -> Result<Hand, AppErr> {
+    fn char_to_card(char: char) -> Card {
+        match char {
+            'A' => 14,
+            'K' => 13,
+            'Q' => 12,
+            'J' => 11,
+            'T' => 10,
+            '9' => 9,
+            '8' => 8,
+            '7' => 7,
+            '6' => 6,
+            '5' => 5,
+            '4' => 4,
+            '3' => 3,
+            '2' => 2,
+            c => panic!("{} not a valid card", c)
+        }
+    }
+
+    let (left, right) = line.split_at(5);
+    let mut cards = [0; 5];
+    for (i, card) in left.chars().map(char_to_card).enumerate() {
+        cards[i] = card;
+    }
+
+    let bid = right.trim().parse::<i32>()?;
+    Ok(Hand {
+        cards,
+        score: score_hand(&cards),
+        bid
+    })
+}
+
+pub struct Day7;
+
+pub const SOLUTION: Registration = Registration { year: 2023, day: 7, solver: &Day7 };
+
+impl Solution for Day7 {
+    type Model<'a> = Vec<Hand>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Hand>, AppErr> {
+        parse_lines(input, parse_hand)
+    }
+
+    /*
+        --- Part One ---
+        Find the rank of every hand in your set. What are the total winnings?
+    */
+
+    fn part1(&self, input: &Vec<Hand>) -> Result<Answer, AppErr> {
+        // Sort the input by hand strength
+        let mut input = input.iter().collect::<Vec<_>>();
+        input.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let result = input.iter()
+            .enumerate()
+            .map(|(rank, hand)| (rank as i32 + 1) * hand.bid)
+            .sum::<i32>();
+
+        Ok(result.into())
+    }
+
+    /*
+        --- Part Two ---
+
+    */
+
+    fn part2(&self, _input: &Vec<Hand>) -> Result<Answer, AppErr> {
+        Ok("TODO".into())
+    }
+}
@@ -0,0 +1,88 @@
+// Hashes the source of each solution crate the runner depends on, along with its path dependencies,
+// so that cached answers are only discarded when the solution which gave them (or util) changes.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use sha2::{ Digest, Sha256 };
+
+fn main() {
+    let manifest_dir = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    let manifest_path = manifest_dir.join("Cargo.toml");
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+    let manifest = fs::read_to_string(&manifest_path).unwrap();
+
+    let mut hashes = String::new();
+    for (name, path) in solution_dependencies(&manifest) {
+        let mut hasher = Sha256::new();
+        hash_crate(&manifest_dir.join(path), &mut hasher, &mut Vec::new());
+        let hash = hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect::<String>();
+        writeln!(hashes, "    ({:?}, {:?}),", name, hash).unwrap();
+    }
+
+    let generated = format!("/// The SHA-256 of the source of each solution crate and its path dependencies (such as util), by package name, as of this build.\n\
+        const SOURCE_HASHES: &[(&str, &str)] = &[\n{}];\n", hashes);
+    let out_dir = PathBuf::from(std::env::var_os("OUT_DIR").unwrap());
+    fs::write(out_dir.join("source_hashes.rs"), generated).unwrap();
+}
+
+/// The name and path of each `aoc-<year>-day<day>` dependency, e.g. `aoc-2023-day2 = { path = "../2023/day2" }`.
+fn solution_dependencies(manifest: &str) -> Vec<(&str, &str)> {
+    path_dependencies(manifest).into_iter()
+        .filter(|(name, _)| name.starts_with("aoc-"))
+        .collect()
+}
+
+/// The name and path of each dependency given by a path, e.g. `util = { path = "../../util" }`.
+fn path_dependencies(manifest: &str) -> Vec<(&str, &str)> {
+    manifest.lines()
+        .filter_map(|line| {
+            let (name, rest) = line.split_once('=')?;
+            let path = rest.split_once("path = \"")?.1.split_once('"')?.0;
+            Some((name.trim(), path))
+        })
+        .collect()
+}
+
+/// Hashes the manifest and every file under `src` of the crate at `dir`, in order of their paths,
+/// and then each of its path dependencies (such as util) not already in `hashed`.
+fn hash_crate(dir: &Path, hasher: &mut Sha256, hashed: &mut Vec<PathBuf>) {
+    let dir = dir.canonicalize().unwrap();
+    if hashed.contains(&dir) {
+        return;
+    }
+    hashed.push(dir.clone());
+
+    // Cargo watches every file under a directory, including files added to it
+    println!("cargo:rerun-if-changed={}", dir.join("Cargo.toml").display());
+    println!("cargo:rerun-if-changed={}", dir.join("src").display());
+    let mut files = vec![dir.join("Cargo.toml")];
+    collect_files(&dir.join("src"), &mut files);
+    files.sort();
+    for file in files {
+        let relative = file.strip_prefix(&dir).unwrap();
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update(b"\0");
+        hasher.update(fs::read(&file).unwrap());
+    }
+
+    let manifest = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+    for (name, path) in path_dependencies(&manifest) {
+        hasher.update(name.as_bytes());
+        hasher.update(b"\0");
+        hash_crate(&dir.join(path), hasher, hashed);
+    }
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        }
+        else {
+            files.push(path);
+        }
+    }
+}
@@ -0,0 +1,669 @@
+// Command line parsing for the runner.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use util::error::AppErr;
+use util::params::{ parse_override, Overrides };
+use util::solution::Part;
+
+pub const USAGE: &str = "\
+Usage:
+    aoc run <year> <day> [--part <1|2>] [--impl <name>] [--input <file>] [--param <name>=<value>]... [--timeout <secs>] [--force] [--report <file>] [--junit <file>]
+    aoc watch <year> <day> [--part <1|2>] [--impl <name>] [--input <file>] [--param <name>=<value>]... [--timeout <secs>] [--force] [--report <file>] [--junit <file>]
+    aoc diff [<year> [<day>]] [--input <file>]...
+    aoc verify [<year> [<day>]] [--answers <file>] [--timeout <secs>] [--force] [--report <file>] [--junit <file>]
+    aoc bench [<year> [<day>]] [--iterations <n>] [--timeout <secs>]
+    aoc examples <year> <day>
+    aoc new <year> <day>
+    aoc fetch <year> <day>
+    aoc submit <year> <day> <1|2> [<answer>]
+    aoc puzzle <year> <day>
+    aoc leaderboard <year> [--id <id>] [--file <file>]
+    aoc calendar [<year>] [--answers <file>] [--report <file>]
+    aoc vault add <year> <day> [--input <file>]
+    aoc vault rotate
+    aoc serve [--port <port>] [--timeout <secs>] [--jobs <n>]
+    aoc help
+";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(RunArgs),
+    Watch(RunArgs),
+    Diff(DiffArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+    Examples(DayArgs),
+    New(DayArgs),
+    Fetch(DayArgs),
+    Submit(SubmitArgs),
+    Puzzle(DayArgs),
+    Leaderboard(LeaderboardArgs),
+    Calendar(CalendarArgs),
+    Vault(VaultArgs),
+    Serve(ServeArgs),
+    Help,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    pub year: u32,
+    pub day: u32,
+    /// Solve only this part, rather than both.
+    pub part: Option<Part>,
+    /// Run this implementation of the day, rather than the main one.
+    pub implementation: Option<String>,
+    /// Read the puzzle input from this file, rather than the day's `input.txt`.
+    pub input: Option<PathBuf>,
+    /// Values for the puzzle's parameters, in place of their defaults.
+    pub params: Overrides,
+    /// The time allowed for each part, or `None` for no limit.
+    pub timeout: Option<Duration>,
+    /// Solve each part even if its answer is cached.
+    pub force: bool,
+    pub report: ReportArgs,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DiffArgs {
+    /// Compare only this year, or only this day of the year.
+    pub year: Option<u32>,
+    pub day: Option<u32>,
+    /// Also compare on these inputs, as well as each day's `input.txt` and examples.
+    pub inputs: Vec<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VerifyArgs {
+    /// Verify only this year, or only this day of the year.
+    pub year: Option<u32>,
+    pub day: Option<u32>,
+    /// Read the expected answers from this file, rather than `answers.txt`.
+    pub answers: Option<PathBuf>,
+    pub timeout: Option<Duration>,
+    pub force: bool,
+    pub report: ReportArgs,
+}
+
+/// Where to write reports of each part which was run.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ReportArgs {
+    pub json: Option<PathBuf>,
+    pub junit: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BenchArgs {
+    /// Bench only this year, or only this day of the year.
+    pub year: Option<u32>,
+    pub day: Option<u32>,
+    /// How many times to run each day.
+    pub iterations: usize,
+    pub timeout: Option<Duration>,
+}
+
+/// A single day, for commands which need exactly one.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DayArgs {
+    pub year: u32,
+    pub day: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct SubmitArgs {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    /// Submit this answer, rather than solving the part.
+    pub answer: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct LeaderboardArgs {
+    pub year: u32,
+    /// Fetch this private leaderboard, rather than the one in the config file.
+    pub id: Option<String>,
+    /// Read the leaderboard from this file, rather than fetching it.
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CalendarArgs {
+    /// Show only this year, rather than every year and the summary.
+    pub year: Option<u32>,
+    /// Read the recorded answers from this file, rather than `answers.txt`.
+    pub answers: Option<PathBuf>,
+    /// Read a JSON report written by `aoc verify`, to find parts which return a placeholder.
+    pub report: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum VaultArgs {
+    /// Encrypt an input into the day's `input.txt.enc`.
+    Add {
+        year: u32,
+        day: u32,
+        /// Encrypt this file, rather than the day's `input.txt`.
+        input: Option<PathBuf>,
+    },
+    /// Re-encrypt every input with a new key.
+    Rotate,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ServeArgs {
+    /// The port to listen on, on localhost.
+    pub port: u16,
+    /// Always given, as a request must not be able to hang the server.
+    pub timeout: Duration,
+    /// The number of requests answered at once.
+    pub jobs: usize,
+}
+
+/// The number of times each day is run by `aoc bench`, unless given.
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+/// The time allowed for each part, unless given. `--timeout 0` allows any time.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// The port `aoc serve` listens on, unless given.
+pub const DEFAULT_PORT: u16 = 8000;
+
+/// The number of requests `aoc serve` answers at once, unless given.
+pub const DEFAULT_JOBS: usize = 4;
+
+pub fn parse<I>(args: I) -> Result<Command, AppErr>
+    where I: IntoIterator<Item=String>
+{
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("run")                         => parse_run(args).map(Command::Run),
+        Some("watch")                       => parse_run(args).map(Command::Watch),
+        Some("diff")                        => parse_diff(args).map(Command::Diff),
+        Some("verify")                      => parse_verify(args).map(Command::Verify),
+        Some("bench")                       => parse_bench(args).map(Command::Bench),
+        Some("examples")                    => parse_day(args).map(Command::Examples),
+        Some("new")                         => parse_day(args).map(Command::New),
+        Some("fetch")                       => parse_day(args).map(Command::Fetch),
+        Some("submit")                      => parse_submit(args).map(Command::Submit),
+        Some("puzzle")                      => parse_day(args).map(Command::Puzzle),
+        Some("leaderboard")                 => parse_leaderboard(args).map(Command::Leaderboard),
+        Some("calendar")                    => parse_calendar(args).map(Command::Calendar),
+        Some("vault")                       => parse_vault(args).map(Command::Vault),
+        Some("serve")                       => parse_serve(args).map(Command::Serve),
+        Some("help" | "--help" | "-h") | None => Ok(Command::Help),
+        Some(other)                         => Err(usage(&format!("unknown command `{}`", other))),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item=String>) -> Result<RunArgs, AppErr> {
+    let mut positional = Vec::new();
+    let mut part = None;
+    let mut implementation = None;
+    let mut input = None;
+    let mut params = Vec::new();
+    let mut timeout = Some(DEFAULT_TIMEOUT);
+    let mut force = false;
+    let mut report = ReportArgs::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--report" | "--junit" => parse_report_option(&arg, &mut args, &mut report)?,
+            "--part" => {
+                let value = option_value(&mut args, "--part")?;
+                let number = parse_number(&value, "part")?;
+                part = Some(Part::from_number(number).ok_or_else(|| usage(&format!("invalid part `{}`", value)))?);
+            }
+            "--impl" => {
+                implementation = Some(option_value(&mut args, "--impl")?);
+            }
+            "--input" => {
+                input = Some(PathBuf::from(option_value(&mut args, "--input")?));
+            }
+            "--param" => {
+                params.push(parse_override(&option_value(&mut args, "--param")?)?);
+            }
+            "--timeout" => timeout = parse_timeout(&mut args)?,
+            "--force" => force = true,
+            flag if flag.starts_with("--") => {
+                return Err(usage(&format!("unknown option `{}`", flag)));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    match positional.as_slice() {
+        [year, day] => Ok(RunArgs {
+            year: parse_number(year, "year")?,
+            day: parse_number(day, "day")?,
+            part,
+            implementation,
+            input,
+            params,
+            timeout,
+            force,
+            report,
+        }),
+        _ => Err(usage("expected a year and a day")),
+    }
+}
+
+fn parse_verify(mut args: impl Iterator<Item=String>) -> Result<VerifyArgs, AppErr> {
+    let mut positional = Vec::new();
+    let mut answers = None;
+    let mut timeout = Some(DEFAULT_TIMEOUT);
+    let mut force = false;
+    let mut report = ReportArgs::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--report" | "--junit" => parse_report_option(&arg, &mut args, &mut report)?,
+            "--answers" => {
+                answers = Some(PathBuf::from(option_value(&mut args, "--answers")?));
+            }
+            "--timeout" => timeout = parse_timeout(&mut args)?,
+            "--force" => force = true,
+            flag if flag.starts_with("--") => {
+                return Err(usage(&format!("unknown option `{}`", flag)));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let (year, day) = parse_year_day(&positional)?;
+    Ok(VerifyArgs { year, day, answers, timeout, force, report })
+}
+
+fn parse_diff(mut args: impl Iterator<Item=String>) -> Result<DiffArgs, AppErr> {
+    let mut positional = Vec::new();
+    let mut inputs = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                inputs.push(PathBuf::from(option_value(&mut args, "--input")?));
+            }
+            flag if flag.starts_with("--") => {
+                return Err(usage(&format!("unknown option `{}`", flag)));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let (year, day) = parse_year_day(&positional)?;
+    Ok(DiffArgs { year, day, inputs })
+}
+
+fn parse_report_option(option: &str, args: &mut impl Iterator<Item=String>, report: &mut ReportArgs) -> Result<(), AppErr> {
+    let path = Some(PathBuf::from(option_value(args, option)?));
+    match option {
+        "--report" => report.json = path,
+        _          => report.junit = path,
+    }
+    Ok(())
+}
+
+fn parse_bench(mut args: impl Iterator<Item=String>) -> Result<BenchArgs, AppErr> {
+    let mut positional = Vec::new();
+    let mut iterations = DEFAULT_ITERATIONS;
+    let mut timeout = Some(DEFAULT_TIMEOUT);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => {
+                let value = option_value(&mut args, "--iterations")?;
+                iterations = match parse_number(&value, "iterations")? {
+                    0 => return Err(usage("expected at least one iteration")),
+                    n => n as usize,
+                };
+            }
+            "--timeout" => timeout = parse_timeout(&mut args)?,
+            flag if flag.starts_with("--") => {
+                return Err(usage(&format!("unknown option `{}`", flag)));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let (year, day) = parse_year_day(&positional)?;
+    Ok(BenchArgs { year, day, iterations, timeout })
+}
+
+/// The value of `--timeout` in seconds, where 0 allows any time.
+fn parse_timeout(args: &mut impl Iterator<Item=String>) -> Result<Option<Duration>, AppErr> {
+    let value = option_value(args, "--timeout")?;
+    match parse_number(&value, "timeout")? {
+        0    => Ok(None),
+        secs => Ok(Some(Duration::from_secs(secs as u64))),
+    }
+}
+
+fn parse_day(args: impl Iterator<Item=String>) -> Result<DayArgs, AppErr> {
+    let positional = args.collect::<Vec<_>>();
+    if let Some(flag) = positional.iter().find(|arg| arg.starts_with("--")) {
+        return Err(usage(&format!("unknown option `{}`", flag)));
+    }
+    match parse_year_day(&positional)? {
+        (Some(year), Some(day)) => Ok(DayArgs { year, day }),
+        _ => Err(usage("expected a year and a day")),
+    }
+}
+
+fn parse_submit(args: impl Iterator<Item=String>) -> Result<SubmitArgs, AppErr> {
+    let positional = args.collect::<Vec<_>>();
+    let (year, day, part, answer) = match positional.as_slice() {
+        [year, day, part]         => (year, day, part, None),
+        [year, day, part, answer] => (year, day, part, Some(answer.clone())),
+        _ => return Err(usage("expected a year, a day, a part and optionally an answer")),
+    };
+    let part = Part::from_number(parse_number(part, "part")?).ok_or_else(|| usage(&format!("invalid part `{}`", part)))?;
+    Ok(SubmitArgs { year: parse_number(year, "year")?, day: parse_number(day, "day")?, part, answer })
+}
+
+fn parse_leaderboard(mut args: impl Iterator<Item=String>) -> Result<LeaderboardArgs, AppErr> {
+    let mut positional = Vec::new();
+    let mut id = None;
+    let mut file = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--id" => {
+                id = Some(option_value(&mut args, "--id")?);
+            }
+            "--file" => {
+                file = Some(PathBuf::from(option_value(&mut args, "--file")?));
+            }
+            flag if flag.starts_with("--") => {
+                return Err(usage(&format!("unknown option `{}`", flag)));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    match positional.as_slice() {
+        [year] => Ok(LeaderboardArgs { year: parse_number(year, "year")?, id, file }),
+        _ => Err(usage("expected a year")),
+    }
+}
+
+fn parse_calendar(mut args: impl Iterator<Item=String>) -> Result<CalendarArgs, AppErr> {
+    let mut positional = Vec::new();
+    let mut answers = None;
+    let mut report = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => {
+                answers = Some(PathBuf::from(option_value(&mut args, "--answers")?));
+            }
+            "--report" => {
+                report = Some(PathBuf::from(option_value(&mut args, "--report")?));
+            }
+            flag if flag.starts_with("--") => {
+                return Err(usage(&format!("unknown option `{}`", flag)));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    match parse_year_day(&positional)? {
+        (year, None) => Ok(CalendarArgs { year, answers, report }),
+        _ => Err(usage("expected at most a year")),
+    }
+}
+
+fn parse_vault(mut args: impl Iterator<Item=String>) -> Result<VaultArgs, AppErr> {
+    let command = args.next();
+    let mut positional = Vec::new();
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" if command.as_deref() == Some("add") => {
+                input = Some(PathBuf::from(option_value(&mut args, "--input")?));
+            }
+            flag if flag.starts_with("--") => {
+                return Err(usage(&format!("unknown option `{}`", flag)));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    match (command.as_deref(), positional.as_slice()) {
+        (Some("add"), [year, day]) => Ok(VaultArgs::Add { year: parse_number(year, "year")?, day: parse_number(day, "day")?, input }),
+        (Some("add"), _)           => Err(usage("expected a year and a day")),
+        (Some("rotate"), [])       => Ok(VaultArgs::Rotate),
+        (Some("rotate"), _)        => Err(usage("`vault rotate` takes no arguments")),
+        _                          => Err(usage("expected `vault add` or `vault rotate`")),
+    }
+}
+
+fn parse_serve(mut args: impl Iterator<Item=String>) -> Result<ServeArgs, AppErr> {
+    let mut port = DEFAULT_PORT;
+    let mut timeout = DEFAULT_TIMEOUT;
+    let mut jobs = DEFAULT_JOBS;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--port" => {
+                let value = option_value(&mut args, "--port")?;
+                port = value.parse().map_err(|_| usage(&format!("invalid port `{}`", value)))?;
+            }
+            "--timeout" => {
+                timeout = parse_timeout(&mut args)?
+                    .ok_or_else(|| usage("`serve` needs a time limit, so `--timeout` must be at least 1"))?;
+            }
+            "--jobs" => {
+                let value = option_value(&mut args, "--jobs")?;
+                jobs = match parse_number(&value, "jobs")? {
+                    0 => return Err(usage("expected at least one job")),
+                    n => n as usize,
+                };
+            }
+            flag if flag.starts_with("--") => {
+                return Err(usage(&format!("unknown option `{}`", flag)));
+            }
+            _ => return Err(usage("`serve` takes no arguments")),
+        }
+    }
+    Ok(ServeArgs { port, timeout, jobs })
+}
+
+/// An optional year, optionally followed by a day.
+fn parse_year_day(positional: &[String]) -> Result<(Option<u32>, Option<u32>), AppErr> {
+    match positional {
+        []          => Ok((None, None)),
+        [year]      => Ok((Some(parse_number(year, "year")?), None)),
+        [year, day] => Ok((Some(parse_number(year, "year")?), Some(parse_number(day, "day")?))),
+        _           => Err(usage("expected at most a year and a day")),
+    }
+}
+
+fn option_value(args: &mut impl Iterator<Item=String>, option: &str) -> Result<String, AppErr> {
+    args.next().ok_or_else(|| usage(&format!("missing value for `{}`", option)))
+}
+
+fn parse_number(value: &str, name: &str) -> Result<u32, AppErr> {
+    value.parse().map_err(|_| usage(&format!("invalid {} `{}`", name, value)))
+}
+
+fn usage(message: &str) -> AppErr {
+    AppErr::usage(&format!("{}\n\n{}", message, USAGE))
+}
+
+#[cfg(test)]
+mod args_tests {
+    use super::*;
+    use util::error::ErrorKind;
+
+    fn parse_str(args: &str) -> Result<Command, AppErr> {
+        parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn parses_run() {
+        let expected = RunArgs { year: 2020, day: 8, part: None, implementation: None, input: None, params: vec![], timeout: Some(DEFAULT_TIMEOUT), force: false, report: ReportArgs::default() };
+        assert_eq!(parse_str("run 2020 8").unwrap(), Command::Run(expected));
+    }
+
+    #[test]
+    fn parses_run_options() {
+        let expected = RunArgs {
+            year: 2018,
+            day: 3,
+            part: Some(Part::Two),
+            implementation: Some("alt".to_string()),
+            input: Some(PathBuf::from("example.txt")),
+            params: vec![("workers".to_string(), "2".to_string()), ("base_duration".to_string(), "0".to_string())],
+            timeout: Some(Duration::from_secs(5)),
+            force: true,
+            report: ReportArgs { json: Some(PathBuf::from("r.json")), junit: Some(PathBuf::from("r.xml")) },
+        };
+        assert_eq!(parse_str("run --part 2 2018 3 --impl alt --input example.txt --param workers=2 --param base_duration=0 --timeout 5 --force --report r.json --junit r.xml").unwrap(), Command::Run(expected));
+    }
+
+    #[test]
+    fn parses_watch() {
+        let expected = RunArgs { year: 2020, day: 8, part: Some(Part::One), implementation: None, input: None, params: vec![], timeout: None, force: false, report: ReportArgs::default() };
+        assert_eq!(parse_str("watch 2020 8 --part 1 --timeout 0").unwrap(), Command::Watch(expected));
+    }
+
+    #[test]
+    fn parses_diff() {
+        let expected = DiffArgs { year: None, day: None, inputs: vec![] };
+        assert_eq!(parse_str("diff").unwrap(), Command::Diff(expected));
+        let expected = DiffArgs { year: Some(2023), day: Some(2), inputs: vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")] };
+        assert_eq!(parse_str("diff 2023 2 --input a.txt --input b.txt").unwrap(), Command::Diff(expected));
+    }
+
+    #[test]
+    fn parses_verify() {
+        let expected = VerifyArgs { year: None, day: None, answers: None, timeout: Some(DEFAULT_TIMEOUT), force: false, report: ReportArgs::default() };
+        assert_eq!(parse_str("verify").unwrap(), Command::Verify(expected));
+        let expected = VerifyArgs {
+            year: Some(2018),
+            day: Some(3),
+            answers: Some(PathBuf::from("a.txt")),
+            timeout: Some(DEFAULT_TIMEOUT),
+            force: true,
+            report: ReportArgs { json: Some(PathBuf::from("r.json")), junit: None },
+        };
+        assert_eq!(parse_str("verify 2018 3 --answers a.txt --force --report r.json").unwrap(), Command::Verify(expected));
+    }
+
+    #[test]
+    fn parses_bench() {
+        let expected = BenchArgs { year: None, day: None, iterations: DEFAULT_ITERATIONS, timeout: Some(DEFAULT_TIMEOUT) };
+        assert_eq!(parse_str("bench").unwrap(), Command::Bench(expected));
+        let expected = BenchArgs { year: Some(2020), day: None, iterations: 3, timeout: Some(Duration::from_secs(60)) };
+        assert_eq!(parse_str("bench 2020 --iterations 3 --timeout 60").unwrap(), Command::Bench(expected));
+    }
+
+    #[test]
+    fn parses_day_commands() {
+        let expected = DayArgs { year: 2018, day: 3 };
+        assert_eq!(parse_str("examples 2018 3").unwrap(), Command::Examples(expected));
+        let expected = DayArgs { year: 2023, day: 8 };
+        assert_eq!(parse_str("new 2023 8").unwrap(), Command::New(expected));
+        let expected = DayArgs { year: 2023, day: 8 };
+        assert_eq!(parse_str("fetch 2023 8").unwrap(), Command::Fetch(expected));
+        let expected = DayArgs { year: 2018, day: 1 };
+        assert_eq!(parse_str("puzzle 2018 1").unwrap(), Command::Puzzle(expected));
+    }
+
+    #[test]
+    fn parses_submit() {
+        let expected = SubmitArgs { year: 2023, day: 8, part: Part::Two, answer: None };
+        assert_eq!(parse_str("submit 2023 8 2").unwrap(), Command::Submit(expected));
+        let expected = SubmitArgs { year: 2023, day: 8, part: Part::One, answer: Some("-12".to_string()) };
+        assert_eq!(parse_str("submit 2023 8 1 -12").unwrap(), Command::Submit(expected));
+    }
+
+    #[test]
+    fn parses_leaderboard() {
+        let expected = LeaderboardArgs { year: 2023, id: None, file: None };
+        assert_eq!(parse_str("leaderboard 2023").unwrap(), Command::Leaderboard(expected));
+        let expected = LeaderboardArgs { year: 2023, id: Some("123".to_string()), file: Some(PathBuf::from("l.json")) };
+        assert_eq!(parse_str("leaderboard --id 123 2023 --file l.json").unwrap(), Command::Leaderboard(expected));
+    }
+
+    #[test]
+    fn parses_calendar() {
+        let expected = CalendarArgs { year: None, answers: None, report: None };
+        assert_eq!(parse_str("calendar").unwrap(), Command::Calendar(expected));
+        let expected = CalendarArgs { year: Some(2023), answers: None, report: Some(PathBuf::from("r.json")) };
+        assert_eq!(parse_str("calendar 2023 --report r.json").unwrap(), Command::Calendar(expected));
+    }
+
+    #[test]
+    fn parses_vault() {
+        assert_eq!(parse_str("vault add 2020 8").unwrap(), Command::Vault(VaultArgs::Add { year: 2020, day: 8, input: None }));
+        let expected = VaultArgs::Add { year: 2020, day: 8, input: Some(PathBuf::from("input.txt")) };
+        assert_eq!(parse_str("vault add 2020 8 --input input.txt").unwrap(), Command::Vault(expected));
+        assert_eq!(parse_str("vault rotate").unwrap(), Command::Vault(VaultArgs::Rotate));
+    }
+
+    #[test]
+    fn parses_serve() {
+        assert_eq!(parse_str("serve").unwrap(), Command::Serve(ServeArgs { port: DEFAULT_PORT, timeout: DEFAULT_TIMEOUT, jobs: DEFAULT_JOBS }));
+        let expected = ServeArgs { port: 9000, timeout: Duration::from_secs(10), jobs: 2 };
+        assert_eq!(parse_str("serve --port 9000 --timeout 10 --jobs 2").unwrap(), Command::Serve(expected));
+    }
+
+    #[test]
+    fn defaults_to_help() {
+        assert_eq!(parse_str("").unwrap(), Command::Help);
+        assert_eq!(parse_str("--help").unwrap(), Command::Help);
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        let invalid = [
+            "frobnicate",
+            "run 2020",
+            "run 2020 8 9",
+            "run x 8",
+            "run 2020 8 --part 3",
+            "run 2020 8 --part",
+            "run 2020 8 --verbose",
+            "run 2020 8 --impl",
+            "run 2020 8 --timeout",
+            "run 2020 8 --param",
+            "run 2020 8 --param preamble_size",
+            "run 2020 8 --timeout 1.5",
+            "diff 2023 2 1",
+            "diff --input",
+            "verify 2020 8 1",
+            "verify --answers",
+            "verify --junit",
+            "verify --timeout -1",
+            "bench 2020 --iterations 0",
+            "bench 2020 --iterations x",
+            "examples 2018",
+            "examples 2018 3 --force",
+            "new 2023",
+            "submit 2023 8",
+            "submit 2023 8 3",
+            "leaderboard",
+            "leaderboard 2023 1",
+            "leaderboard 2023 --id",
+            "calendar 2023 7",
+            "calendar --report",
+            "vault",
+            "vault add 2020",
+            "vault add 2020 8 --input",
+            "vault rotate 2020",
+            "vault rotate --input a.txt",
+            "serve 2020",
+            "serve --port 70000",
+            "serve --port",
+            "serve --timeout 0",
+            "serve --jobs 0",
+        ];
+        for args in invalid {
+            let err = parse_str(args).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Usage, "{}", args);
+        }
+    }
+}
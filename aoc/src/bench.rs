@@ -0,0 +1,191 @@
+// Times reading, parsing and each part of a solution over several iterations.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::time::{ Duration, Instant };
+
+use util::error::{ AppErr, Context };
+use util::solution::{ solve_with_budget, Part, Registration };
+
+use crate::report;
+
+/// The spread of a set of timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Returns `None` if there are no samples.
+    pub fn of(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let median = match sorted.len() {
+            0 => return None,
+            n if n % 2 == 0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+            n => sorted[n / 2],
+        };
+        Some(Stats { min: sorted[0], median, max: sorted[sorted.len() - 1] })
+    }
+}
+
+/// The timings of each phase of one day, one sample per iteration.
+#[derive(Debug)]
+pub struct DayBench {
+    pub year: u32,
+    pub day: u32,
+    pub read: Vec<Duration>,
+    pub parse: Vec<Duration>,
+    pub parts: Vec<(Part, Vec<Duration>)>,
+}
+
+impl DayBench {
+    /// Each phase with its name, in the order they run.
+    pub fn phases(&self) -> Vec<(String, &[Duration])> {
+        let mut phases = vec![
+            ("read".to_string(), self.read.as_slice()),
+            ("parse".to_string(), self.parse.as_slice()),
+        ];
+        for (part, samples) in &self.parts {
+            phases.push((part.to_string().to_lowercase(), samples.as_slice()));
+        }
+        phases
+    }
+}
+
+/// Reads, parses and solves both parts of `registration` `iterations` times, allowing each part at most `budget` if given.
+/// Fails if the input cannot be read or parsed, or if either part fails or runs out of time.
+pub fn bench_day(registration: &Registration, input_path: &Path, iterations: usize, budget: Option<Duration>) -> Result<DayBench, AppErr> {
+    let mut bench = DayBench {
+        year: registration.year,
+        day: registration.day,
+        read: Vec::with_capacity(iterations),
+        parse: Vec::with_capacity(iterations),
+        parts: Part::ALL.iter().map(|&part| (part, Vec::with_capacity(iterations))).collect(),
+    };
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let input = report::read_input(input_path)?;
+        bench.read.push(start.elapsed());
+
+        let (results, timings) = match budget {
+            Some(budget) => solve_with_budget(registration, input.as_str().to_string(), &Part::ALL, budget)?,
+            None         => registration.solver.solve_timed(input.as_str(), &Part::ALL)?,
+        };
+        bench.parse.push(timings.parse);
+        for ((part, answer), elapsed) in results.into_iter().zip(timings.parts) {
+            answer.with_context(|| part.to_string())?;
+            bench.parts[part.number() as usize - 1].1.push(elapsed);
+        }
+    }
+    Ok(bench)
+}
+
+/// The min, median and max of each phase of `bench`.
+pub fn format_day(bench: &DayBench) -> String {
+    let mut text = String::new();
+    writeln!(text, "{} day {}", bench.year, bench.day).unwrap();
+    for (name, samples) in bench.phases() {
+        if let Some(stats) = Stats::of(samples) {
+            writeln!(text, "    {:<8} min {:>10}  median {:>10}  max {:>10}",
+                name, format_duration(stats.min), format_duration(stats.median), format_duration(stats.max)).unwrap();
+        }
+    }
+    text
+}
+
+/// A table of the median time of each phase of each day, with a total for the year.
+/// All of `benches` are expected to be from the same year.
+pub fn format_year(year: u32, benches: &[DayBench]) -> String {
+    const PHASES: [&str; 4] = ["read", "parse", "part 1", "part 2"];
+
+    let mut text = String::new();
+    write!(text, "{:<8}", year).unwrap();
+    for name in PHASES.iter().chain(&["total"]) {
+        write!(text, " {:>10}", name).unwrap();
+    }
+    writeln!(text).unwrap();
+
+    let mut totals = [Duration::ZERO; PHASES.len()];
+    for bench in benches {
+        write!(text, "{:<8}", format!("day {}", bench.day)).unwrap();
+        let mut day_total = Duration::ZERO;
+        for (total, (_, samples)) in totals.iter_mut().zip(bench.phases()) {
+            let median = Stats::of(samples).map_or(Duration::ZERO, |stats| stats.median);
+            *total += median;
+            day_total += median;
+            write!(text, " {:>10}", format_duration(median)).unwrap();
+        }
+        writeln!(text, " {:>10}", format_duration(day_total)).unwrap();
+    }
+
+    write!(text, "{:<8}", "total").unwrap();
+    for total in totals {
+        write!(text, " {:>10}", format_duration(total)).unwrap();
+    }
+    writeln!(text, " {:>10}", format_duration(totals.iter().sum())).unwrap();
+    text
+}
+
+/// Formats `duration` in the largest unit in which it is at least 1.
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    if micros < 1_000.0 {
+        format!("{:.1}µs", micros)
+    }
+    else if micros < 1_000_000.0 {
+        format!("{:.2}ms", micros / 1_000.0)
+    }
+    else {
+        format!("{:.2}s", micros / 1_000_000.0)
+    }
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn stats_of_odd_samples() {
+        let stats = Stats::of(&millis(&[5, 1, 3])).unwrap();
+        assert_eq!(stats, Stats { min: Duration::from_millis(1), median: Duration::from_millis(3), max: Duration::from_millis(5) });
+    }
+
+    #[test]
+    fn stats_of_even_samples() {
+        let stats = Stats::of(&millis(&[4, 1, 2, 8])).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(Stats::of(&[]), None);
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(Duration::from_nanos(1_500)), "1.5µs");
+        assert_eq!(format_duration(Duration::from_micros(2_345)), "2.35ms");
+        assert_eq!(format_duration(Duration::from_millis(3_210)), "3.21s");
+    }
+
+    #[test]
+    fn year_table_totals_medians() {
+        let bench = DayBench {
+            year: 2020,
+            day: 1,
+            read: millis(&[1]),
+            parse: millis(&[2]),
+            parts: vec![(Part::One, millis(&[3])), (Part::Two, millis(&[4]))],
+        };
+        let table = format_year(2020, &[bench]);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("2020"));
+        assert!(lines[1].starts_with("day 1") && lines[1].ends_with("10.00ms"));
+        assert!(lines[2].starts_with("total") && lines[2].ends_with("10.00ms"));
+    }
+}
@@ -0,0 +1,419 @@
+// A local cache of answers, so that slow days are not solved again for the same input and build.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{ Path, PathBuf };
+use std::time::Duration;
+
+use util::answers::canonical;
+use util::error::{ AppErr, Context, ErrorKind };
+use util::json::{ self, quote };
+use util::solution::{ Answer, Part, Registration, MAIN_IMPLEMENTATION };
+
+use crate::report::{ self, DayRun, PartRun };
+
+include!(concat!(env!("OUT_DIR"), "/source_hashes.rs"));
+
+/// Overrides the path of the cache file.
+pub const CACHE_VAR: &str = "AOC_CACHE";
+
+/// Identifies a cached answer: the part, the input it was solved for and the build which solved it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub input_hash: String,
+    pub build: Build,
+}
+
+/// What solved an answer: an implementation of a day, with its parameter values, built from its source.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Build {
+    pub implementation: String,
+    /// The value of each of the day's parameters, in the order they are declared.
+    pub params: Vec<(String, String)>,
+    /// As given by `source_hash`.
+    pub source_hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cached {
+    /// The answer as it would be recorded in `answers.txt`.
+    pub answer: String,
+    /// How long the part took to solve when it was cached.
+    pub time: Duration,
+}
+
+impl Cached {
+    /// The answer, as a picture if it has more than one line.
+    pub fn to_answer(&self) -> Answer {
+        if self.answer.contains('\n') {
+            Answer::Grid(self.answer.lines().map(String::from).collect())
+        }
+        else {
+            Answer::Str(self.answer.clone())
+        }
+    }
+}
+
+/// Answers by key, read from and written to a file of one JSON object per line.
+#[derive(Debug, Default)]
+pub struct Cache {
+    entries: BTreeMap<Key, Cached>,
+    changed: bool,
+}
+
+impl Cache {
+    /// Reads the cache file at `path`, which is empty if the file does not exist.
+    pub fn read(path: &Path) -> Result<Cache, AppErr> {
+        if !path.exists() {
+            return Ok(Cache::default());
+        }
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read answer cache {}", path.display()))?;
+        Ok(Cache::parse(&text))
+    }
+
+    /// Lines which cannot be parsed, such as those written by a build which cached other fields, are dropped,
+    /// and the cache marked as changed so that they are not written again.
+    pub fn parse(text: &str) -> Cache {
+        let mut cache = Cache::default();
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            match parse_entry(line) {
+                Ok((key, cached)) => { cache.entries.insert(key, cached); }
+                Err(_) => cache.changed = true,
+            }
+        }
+        cache
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&Cached> {
+        self.entries.get(key)
+    }
+
+    /// Records the answer for `key`.
+    /// Answers to the same part and input from the same implementation and parameter values,
+    /// but built from other source, are dropped, as they are unlikely to be needed again.
+    pub fn insert(&mut self, key: Key, cached: Cached) {
+        let config = |k: &Key| (k.year, k.day, k.part, k.input_hash.clone(), k.build.implementation.clone(), k.build.params.clone());
+        let replaced = config(&key);
+        self.entries.retain(|k, _| config(k) != replaced);
+        self.entries.insert(key, cached);
+        self.changed = true;
+    }
+
+    /// One JSON object per line, for each answer.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for (key, cached) in &self.entries {
+            let params = key.build.params.iter()
+                .map(|(name, value)| format!("{}:{}", quote(name), quote(value)))
+                .collect::<Vec<_>>();
+            writeln!(text, "{{\"year\":{},\"day\":{},\"part\":{},\"input_hash\":{},\"implementation\":{},\"params\":{{{}}},\"source_hash\":{},\"answer\":{},\"time_ns\":{}}}",
+                key.year,
+                key.day,
+                key.part.number(),
+                quote(&key.input_hash),
+                quote(&key.build.implementation),
+                params.join(","),
+                quote(&key.build.source_hash),
+                quote(&cached.answer),
+                cached.time.as_nanos()).unwrap();
+        }
+        text
+    }
+
+    /// Writes the cache to `path` if any answers were added since it was read.
+    pub fn write(&self, path: &Path) -> Result<(), AppErr> {
+        if !self.changed {
+            return Ok(());
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        fs::write(path, self.to_text())
+            .with_context(|| format!("failed to write answer cache {}", path.display()))
+    }
+}
+
+fn parse_entry(line: &str) -> Result<(Key, Cached), AppErr> {
+    fn invalid(message: &str) -> AppErr {
+        AppErr::new("Cache", message).with_kind(ErrorKind::Parse)
+    }
+
+    let value = json::parse(line)?;
+    let field = |key: &str| value.get(key).ok_or_else(|| invalid(&format!("missing `{}`", key)));
+    let number_field = |key: &str| field(key)?.as_u64().ok_or_else(|| invalid(&format!("`{}` is not a number", key)));
+    let string_field = |key: &str| field(key)?.as_str().map(String::from).ok_or_else(|| invalid(&format!("`{}` is not a string", key)));
+
+    let params = field("params")?.as_object()
+        .ok_or_else(|| invalid("`params` is not an object"))?
+        .iter()
+        .map(|(name, value)| {
+            let value = value.as_str().ok_or_else(|| invalid(&format!("parameter `{}` is not a string", name)))?;
+            Ok((name.clone(), value.to_string()))
+        })
+        .collect::<Result<Vec<_>, AppErr>>()?;
+
+    let part = number_field("part")?;
+    let key = Key {
+        year: number_field("year")? as u32,
+        day: number_field("day")? as u32,
+        part: Part::from_number(part as u32).ok_or_else(|| invalid(&format!("invalid part {}", part)))?,
+        input_hash: string_field("input_hash")?,
+        build: Build {
+            implementation: string_field("implementation")?,
+            params,
+            source_hash: string_field("source_hash")?,
+        },
+    };
+    let cached = Cached {
+        answer: string_field("answer")?,
+        time: Duration::from_nanos(number_field("time_ns")?),
+    };
+    Ok((key, cached))
+}
+
+/// The answer cache as used by the runner, keyed by the source each solution was built from.
+pub struct Store {
+    /// The cache file, unless the cache could not be opened.
+    file: Option<PathBuf>,
+    cache: Cache,
+    /// Solve each part even if its answer is cached, replacing the cached answer.
+    force: bool,
+}
+
+impl Store {
+    /// Opens the cache file given by `path`.
+    /// If the cache cannot be used the reason is printed, and every part is solved.
+    pub fn open(force: bool) -> Store {
+        let opened = path()
+            .ok_or_else(|| AppErr::new("Cache", "no cache directory: set HOME or AOC_CACHE"))
+            .and_then(|path| {
+                let cache = Cache::read(&path)?;
+                Ok((path, cache))
+            });
+        match opened {
+            Ok((path, cache)) => Store { file: Some(path), cache, force },
+            Err(err) => {
+                eprintln!("Not using the answer cache: {}", err);
+                Store { file: None, cache: Cache::default(), force }
+            }
+        }
+    }
+
+    /// As `report::run_day`, using the cache for `implementation` of the day with the current parameter values.
+    /// Days whose source is not known, e.g. those registered after this build, are always solved.
+    pub fn run_day(&mut self, implementation: &str, registration: &Registration, input_path: &Path, parts: &[Part], budget: Option<Duration>) -> DayRun {
+        let source_hash = source_hash(registration.year, registration.day, implementation);
+        match (&self.file, source_hash) {
+            (Some(_), Some(source_hash)) => {
+                let build = Build {
+                    implementation: implementation.to_string(),
+                    params: registration.solver.params().iter().map(|p| (p.name.to_string(), p.value())).collect(),
+                    source_hash: source_hash.to_string(),
+                };
+                run_day(&mut self.cache, &build, registration, input_path, parts, budget, self.force)
+            }
+            _ => report::run_day(registration, input_path, parts, budget),
+        }
+    }
+
+    /// Writes any new answers to the cache file, printing any failure.
+    pub fn save(&self) {
+        if let Some(ref path) = self.file {
+            if let Err(err) = self.cache.write(path) {
+                eprintln!("{}", err);
+            }
+        }
+    }
+}
+
+/// The cache file: `$AOC_CACHE`, or `aoc/answers.jsonl` in the user's cache directory.
+pub fn path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(CACHE_VAR) {
+        return Some(PathBuf::from(path));
+    }
+    let cache_dir = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+    Some(cache_dir.join("aoc").join("answers.jsonl"))
+}
+
+/// The SHA-256 of the source of an implementation of a day and of its path dependencies (such as util), as of this build,
+/// which changes only when that implementation (or one of those dependencies) changes.
+/// Implementations other than the main one are the package `aoc-<year>-day<day>-<implementation>`.
+pub fn source_hash(year: u32, day: u32, implementation: &str) -> Option<&'static str> {
+    let package = match implementation {
+        MAIN_IMPLEMENTATION => format!("aoc-{}-day{}", year, day),
+        _                   => format!("aoc-{}-day{}-{}", year, day, implementation),
+    };
+    SOURCE_HASHES.iter()
+        .find(|&&(name, _)| name == package)
+        .map(|&(_, hash)| hash)
+}
+
+/// As `report::run_day`, taking each answer from `cache` if it is there (unless `force`), and caching the answers it solves.
+pub fn run_day(cache: &mut Cache, build: &Build, registration: &Registration, input_path: &Path, parts: &[Part], budget: Option<Duration>, force: bool) -> DayRun {
+    let input = match report::read_input(input_path) {
+        Ok(input) => input,
+        Err(err)  => return DayRun::failed(registration, err),
+    };
+    let input_hash = report::hash(input.as_str());
+    let key = |part| Key {
+        year: registration.year,
+        day: registration.day,
+        part,
+        input_hash: input_hash.clone(),
+        build: build.clone(),
+    };
+
+    let hits = parts.iter()
+        .filter(|_| !force)
+        .filter_map(|&part| Some((part, cache.get(&key(part))?.clone())))
+        .collect::<Vec<_>>();
+    let missing = parts.iter()
+        .copied()
+        .filter(|&part| !hits.iter().any(|&(p, _)| p == part))
+        .collect::<Vec<_>>();
+
+    // Only parse the input if a part needs solving
+    let mut run = if missing.is_empty() {
+        DayRun { input_hash: Some(input_hash.clone()), ..DayRun::new(registration) }
+    }
+    else {
+        report::solve_input(registration, &input, &missing, budget)
+            .with_context(|| format!("failed to parse {}", input_path.display()))
+    };
+    if let Ok(ref mut solved) = run.parts {
+        for result in solved.iter() {
+            if let Ok(ref answer) = result.answer {
+                cache.insert(key(result.part), Cached { answer: canonical(answer), time: result.time });
+            }
+        }
+        let mut hits = hits.into_iter()
+            .map(|(part, cached)| PartRun { part, answer: Ok(cached.to_answer()), time: cached.time, memory: None, cached: true })
+            .collect::<Vec<_>>();
+        hits.append(solved);
+        hits.sort_by_key(|result| parts.iter().position(|&p| p == result.part));
+        *solved = hits;
+    }
+    run
+}
+
+#[cfg(test)]
+mod cache_tests {
+    use super::*;
+
+    fn build(source_hash: &str) -> Build {
+        Build { implementation: "main".to_string(), params: Vec::new(), source_hash: source_hash.to_string() }
+    }
+
+    fn key(part: Part, input_hash: &str, source_hash: &str) -> Key {
+        Key { year: 2023, day: 2, part, input_hash: input_hash.to_string(), build: build(source_hash) }
+    }
+
+    fn cached(answer: &str) -> Cached {
+        Cached { answer: answer.to_string(), time: Duration::from_micros(5) }
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut cache = Cache::default();
+        cache.insert(key(Part::One, "in", "build"), cached("8"));
+        cache.insert(key(Part::Two, "in", "build"), cached("# #\n###"));
+        let with_params = Key { build: Build { params: vec![("workers".to_string(), "2".to_string())], ..build("build") }, ..key(Part::One, "in", "build") };
+        cache.insert(with_params.clone(), cached("9"));
+        let text = cache.to_text();
+        assert!(text.starts_with("{\"year\":2023,\"day\":2,\"part\":1,\"input_hash\":\"in\",\"implementation\":\"main\",\"params\":{},\"source_hash\":\"build\",\"answer\":\"8\",\"time_ns\":5000}\n"));
+        assert!(text.contains("\"params\":{\"workers\":\"2\"}"));
+
+        let read = Cache::parse(&text);
+        assert!(!read.changed);
+        assert_eq!(read.entries.len(), 3);
+        assert_eq!(read.get(&with_params), Some(&cached("9")));
+        assert_eq!(read.get(&key(Part::Two, "in", "build")), Some(&cached("# #\n###")));
+        assert_eq!(read.get(&key(Part::Two, "in", "build")).unwrap().to_answer(), Answer::Grid(vec!["# #".to_string(), "###".to_string()]));
+        assert_eq!(read.get(&key(Part::One, "in", "other")), None);
+    }
+
+    #[test]
+    fn drops_unreadable_entries() {
+        let path = std::env::temp_dir().join(format!("aoc-cache-drop-test-{}", std::process::id())).join("answers.jsonl");
+        let mut cache = Cache::default();
+        cache.insert(key(Part::One, "in", "build"), cached("8"));
+        let text = cache.to_text();
+
+        // Such as an entry from before answers were keyed by source hash
+        let older = "{\"year\":2023,\"day\":2,\"part\":2,\"input_hash\":\"in\",\"build\":\"0.1.0\",\"answer\":\"9\",\"time_ns\":5000}\n";
+        let read = Cache::parse(&format!("{}{}not json\n", older, text));
+        assert_eq!(read.entries.len(), 1);
+        assert_eq!(read.get(&key(Part::One, "in", "build")), Some(&cached("8")));
+        assert!(read.changed);
+        read.write(&path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), text);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn replaces_answers_from_older_source() {
+        let mut cache = Cache::default();
+        let with_params = |source_hash: &str| Key {
+            build: Build { params: vec![("workers".to_string(), "2".to_string())], ..build(source_hash) },
+            ..key(Part::One, "in", source_hash)
+        };
+        let alt = Key { build: Build { implementation: "alt".to_string(), ..build("old") }, ..key(Part::One, "in", "old") };
+        cache.insert(key(Part::One, "in", "old"), cached("7"));
+        cache.insert(key(Part::One, "other", "old"), cached("9"));
+        cache.insert(with_params("old"), cached("6"));
+        cache.insert(alt.clone(), cached("7"));
+        cache.insert(key(Part::One, "in", "new"), cached("8"));
+        assert_eq!(cache.entries.len(), 4);
+        assert_eq!(cache.get(&key(Part::One, "in", "old")), None);
+        assert_eq!(cache.get(&key(Part::One, "other", "old")), Some(&cached("9")));
+        assert_eq!(cache.get(&with_params("old")), Some(&cached("6")));
+        assert_eq!(cache.get(&alt), Some(&cached("7")));
+
+        cache.insert(with_params("new"), cached("5"));
+        assert_eq!(cache.get(&with_params("old")), None);
+        assert_eq!(cache.get(&key(Part::One, "in", "new")), Some(&cached("8")));
+    }
+
+    #[test]
+    fn hashes_each_solution_source() {
+        let main = source_hash(2023, 2, "main").unwrap();
+        assert_eq!(main.len(), 64);
+        assert_ne!(Some(main), source_hash(2023, 2, "alt"));
+        assert_ne!(Some(main), source_hash(2023, 1, "main"));
+        assert!(source_hash(2023, 2, "alt").is_some());
+        assert_eq!(source_hash(2023, 2, "missing"), None);
+        assert_eq!(source_hash(2023, 30, "main"), None);
+    }
+
+    #[test]
+    fn skips_cached_parts() {
+        let path = std::env::temp_dir().join(format!("aoc-cache-test-{}.txt", std::process::id()));
+        fs::write(&path, "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n").unwrap();
+        let registration = aoc_2023_day2::SOLUTION;
+        let input_hash = report::hash(&fs::read_to_string(&path).unwrap());
+
+        let mut cache = Cache::default();
+        cache.insert(key(Part::Two, &input_hash, "build"), cached("12345"));
+        let run = run_day(&mut cache, &build("build"), &registration, &path, &Part::ALL, None, false);
+        let parts = run.parts.unwrap();
+        assert_eq!((parts[0].part, parts[0].cached), (Part::One, false));
+        assert_eq!(parts[0].answer.as_ref().unwrap(), &Answer::Int(1));
+        assert_eq!((parts[1].part, parts[1].cached), (Part::Two, true));
+        assert_eq!(parts[1].answer.as_ref().unwrap(), &Answer::Str("12345".to_string()));
+        assert_eq!(cache.get(&key(Part::One, &input_hash, "build")).map(|c| c.answer.as_str()), Some("1"));
+
+        let run = run_day(&mut cache, &build("build"), &registration, &path, &[Part::Two], None, true);
+        let parts = run.parts.unwrap();
+        assert!(!parts[0].cached);
+        assert_eq!(parts[0].answer.as_ref().unwrap(), &Answer::Int(48));
+        fs::remove_file(&path).unwrap();
+    }
+}
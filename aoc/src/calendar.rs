@@ -0,0 +1,219 @@
+// A calendar of each year's progress: which parts are solved, implemented but unverified, or missing.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use util::answers::Answers;
+use util::solution::Part;
+
+use crate::report::{ Entry, Status };
+
+/// The years shown in the summary.
+pub const FIRST_YEAR: u32 = 2015;
+pub const LAST_YEAR: u32 = 2023;
+
+const DAYS: u32 = 25;
+
+/// Answers returned by solutions to parts which have not been implemented yet.
+const PLACEHOLDERS: &[&str] = &["", "TODO"];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Progress {
+    /// An answer has been recorded, and the report does not show the part failing.
+    Solved,
+    /// The report shows the part giving an answer which has not been recorded, giving the wrong answer, or running out of time.
+    Unverified,
+    /// The part is not in the report and has no recorded answer, or it returns a placeholder or an error.
+    Missing,
+}
+
+impl Progress {
+    const ALL: [Progress; 3] = [Progress::Solved, Progress::Unverified, Progress::Missing];
+
+    fn symbol(self) -> char {
+        match self {
+            Progress::Solved     => '*',
+            Progress::Unverified => '?',
+            Progress::Missing    => '.',
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Progress::Solved     => "solved",
+            Progress::Unverified => "unverified",
+            Progress::Missing    => "missing",
+        }
+    }
+}
+
+pub struct Calendar {
+    parts: BTreeMap<(u32, u32, Part), Progress>,
+}
+
+impl Calendar {
+    /// The progress of each part, given the recorded answers and the `entries` of a verification report, if there is one.
+    /// A failure in the report outweighs a recorded answer, and a placeholder answer counts as no answer.
+    pub fn new(answers: &Answers, entries: &[Entry]) -> Calendar {
+        let is_placeholder = |answer: Option<&str>| PLACEHOLDERS.contains(&answer.unwrap_or(""));
+        let mut parts = BTreeMap::new();
+        for year in FIRST_YEAR..=LAST_YEAR {
+            for day in 1..=DAYS {
+                for part in Part::ALL {
+                    let entry = entries.iter().rev().find(|e| (e.year, e.day, e.part) == (year, day, part));
+                    let recorded = answers.get(year, day, part).filter(|&answer| !is_placeholder(Some(answer)));
+                    let progress = match (entry, recorded) {
+                        (Some(entry), _) if entry.status == Status::Fail => Progress::Unverified,
+                        (Some(entry), _) if entry.status == Status::Error => Progress::Missing,
+                        (_, Some(_)) => Progress::Solved,
+                        (Some(entry), None) if entry.status == Status::Timeout => Progress::Unverified,
+                        (Some(entry), None) if !is_placeholder(entry.answer.as_deref()) => Progress::Unverified,
+                        _ => Progress::Missing,
+                    };
+                    parts.insert((year, day, part), progress);
+                }
+            }
+        }
+        Calendar { parts }
+    }
+
+    pub fn progress(&self, year: u32, day: u32, part: Part) -> Progress {
+        self.parts.get(&(year, day, part)).cloned().unwrap_or(Progress::Missing)
+    }
+
+    fn count(&self, year: u32, progress: Progress) -> usize {
+        (1..=DAYS)
+            .flat_map(|day| Part::ALL.map(|part| self.progress(year, day, part)))
+            .filter(|&p| p == progress)
+            .count()
+    }
+
+    fn counts(&self, year: u32) -> [usize; 3] {
+        Progress::ALL.map(|progress| self.count(year, progress))
+    }
+}
+
+/// A row for each part with a column for each day:
+/// `*` when solved, `?` when implemented but unverified and `.` when missing.
+pub fn format_year(calendar: &Calendar, year: u32) -> String {
+    let mut text = String::new();
+    let tens = (1..=DAYS).map(|day| if day >= 10 { char::from(b'0' + (day / 10) as u8) } else { ' ' });
+    let units = (1..=DAYS).map(|day| char::from(b'0' + (day % 10) as u8));
+    writeln!(text, "{:<8}{}", year, tens.collect::<String>()).unwrap();
+    writeln!(text, "{:<8}{}", "", units.collect::<String>()).unwrap();
+    for part in Part::ALL {
+        let days = (1..=DAYS).map(|day| calendar.progress(year, day, part).symbol());
+        writeln!(text, "{:<8}{}", part.to_string(), days.collect::<String>()).unwrap();
+    }
+    let counts = Progress::ALL.iter()
+        .zip(calendar.counts(year))
+        .map(|(progress, count)| format!("{} {}", count, progress.name()))
+        .collect::<Vec<_>>();
+    writeln!(text, "{:<8}{}", "", counts.join(", ")).unwrap();
+    text
+}
+
+/// The number of parts in each state for each year, with a total row.
+pub fn format_summary(calendar: &Calendar) -> String {
+    let mut text = String::new();
+    write!(text, "{:<8}", "year").unwrap();
+    for progress in Progress::ALL {
+        write!(text, " {:>10}", progress.name()).unwrap();
+    }
+    writeln!(text).unwrap();
+
+    let mut totals = [0; 3];
+    for year in FIRST_YEAR..=LAST_YEAR {
+        write!(text, "{:<8}", year).unwrap();
+        for (total, count) in totals.iter_mut().zip(calendar.counts(year)) {
+            *total += count;
+            write!(text, " {:>10}", count).unwrap();
+        }
+        writeln!(text).unwrap();
+    }
+
+    write!(text, "{:<8}", "total").unwrap();
+    for total in totals {
+        write!(text, " {:>10}", total).unwrap();
+    }
+    writeln!(text).unwrap();
+    text
+}
+
+#[cfg(test)]
+mod calendar_tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn entry(year: u32, day: u32, part: Part, status: Status, answer: &str) -> Entry {
+        Entry {
+            year,
+            day,
+            part,
+            status,
+            answer: Some(answer.to_string()),
+            expected: None,
+            error: None,
+            parse_time: Duration::ZERO,
+            time: Duration::ZERO,
+            parse_memory: None,
+            memory: None,
+            input_hash: None,
+            cached: false,
+        }
+    }
+
+    fn calendar() -> Calendar {
+        let answers = Answers::parse("2023 7 1 251121738\n2023 1 1 1\n2023 1 2 2\n2016 1 1 3\n2015 9 2 TODO\n2020 12 1 5\n").unwrap();
+        let entries = [
+            entry(2023, 7, Part::Two, Status::Missing, "TODO"),
+            entry(2017, 3, Part::One, Status::Missing, ""),
+            entry(2017, 3, Part::Two, Status::Missing, ""),
+            entry(2020, 13, Part::One, Status::Missing, "4135"),
+            entry(2020, 13, Part::Two, Status::Error, ""),
+            entry(2020, 12, Part::One, Status::Fail, "6"),
+            entry(2020, 12, Part::Two, Status::Timeout, ""),
+        ];
+        Calendar::new(&answers, &entries)
+    }
+
+    #[test]
+    fn classifies_parts() {
+        let calendar = calendar();
+        assert_eq!(calendar.progress(2023, 7, Part::One), Progress::Solved);
+        assert_eq!(calendar.progress(2023, 7, Part::Two), Progress::Missing);
+        assert_eq!(calendar.progress(2017, 3, Part::One), Progress::Missing);
+        assert_eq!(calendar.progress(2020, 13, Part::One), Progress::Unverified);
+        assert_eq!(calendar.progress(2020, 13, Part::Two), Progress::Missing);
+        // A failure outweighs a recorded answer
+        assert_eq!(calendar.progress(2020, 12, Part::One), Progress::Unverified);
+        assert_eq!(calendar.progress(2020, 12, Part::Two), Progress::Unverified);
+        // A recorded placeholder is no answer, and a part which was not run is missing
+        assert_eq!(calendar.progress(2015, 9, Part::Two), Progress::Missing);
+        assert_eq!(calendar.progress(2015, 9, Part::One), Progress::Missing);
+        // Answers found without a solution in the repository still count
+        assert_eq!(calendar.progress(2016, 1, Part::One), Progress::Solved);
+        assert_eq!(calendar.progress(2023, 8, Part::One), Progress::Missing);
+    }
+
+    #[test]
+    fn formats_year() {
+        assert_eq!(format_year(&calendar(), 2023), concat!(
+            "2023             1111111111222222\n",
+            "        1234567890123456789012345\n",
+            "Part 1  *.....*..................\n",
+            "Part 2  *........................\n",
+            "        3 solved, 0 unverified, 47 missing\n"));
+    }
+
+    #[test]
+    fn formats_summary() {
+        let summary = format_summary(&calendar());
+        let lines = summary.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 11);
+        assert_eq!(lines[0], "year         solved unverified    missing");
+        assert_eq!(lines[1], "2015              0          0         50");
+        assert_eq!(lines[10], "total             4          3        443");
+    }
+}
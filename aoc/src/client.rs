@@ -0,0 +1,178 @@
+// Talks to the Advent of Code website, or a stand-in for it.
+
+use std::time::Duration;
+
+use util::error::{ AppErr, ErrorKind };
+
+use crate::config::Config;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Overrides the session token in the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Overrides the base URL in the config file.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const USER_AGENT: &str = "github.com/deadalusai/advent-of-code";
+
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Client {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(USER_AGENT)
+            .build();
+        Client { base_url: base_url.trim_end_matches('/').to_string(), session, agent }
+    }
+
+    /// Uses the `session` and `base_url` settings, which may be overridden by
+    /// `$AOC_SESSION` and `$AOC_BASE_URL`.
+    pub fn from_config(config: &Config) -> Client {
+        let base_url = config.get_or_env("base_url", BASE_URL_VAR)
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        Client::new(&base_url, config.get_or_env("session", SESSION_VAR))
+    }
+
+    /// Fetches `path` (e.g. `/2023/day/8/input`) with the session cookie.
+    pub fn get(&self, path: &str) -> Result<String, AppErr> {
+        let request = self.agent.get(&self.url(path))
+            .set("Cookie", &self.cookie()?);
+        read_response("GET", path, request.call())
+    }
+
+    /// Fetches a page which can be read without logging in, with the session cookie if there is one.
+    pub fn get_public(&self, path: &str) -> Result<String, AppErr> {
+        let mut request = self.agent.get(&self.url(path));
+        if self.session.is_some() {
+            request = request.set("Cookie", &self.cookie()?);
+        }
+        read_response("GET", path, request.call())
+    }
+
+    /// Posts `fields` as a form to `path`, with the session cookie.
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> Result<String, AppErr> {
+        let request = self.agent.post(&self.url(path))
+            .set("Cookie", &self.cookie()?);
+        read_response("POST", path, request.send_form(fields))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn cookie(&self) -> Result<String, AppErr> {
+        let session = self.session.as_deref().ok_or_else(|| {
+            AppErr::usage(&format!("no session token: set ${} or `session` in the config file", SESSION_VAR))
+        })?;
+        Ok(format!("session={}", session))
+    }
+}
+
+fn read_response(method: &str, path: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, AppErr> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(code, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(AppErr::new("Http", &format!("{} {} returned {}: {}", method, path, code, body.trim())))
+        }
+        Err(err) => Err(AppErr::from_display("Http", &err).with_kind(ErrorKind::Io)),
+    }
+}
+
+/// A stand-in for the website, serving canned responses on a local port.
+#[cfg(test)]
+pub mod stand_in {
+    use std::io::{ BufRead, BufReader, Read, Write };
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// A request received by the stand-in: the request line, the headers and the body.
+    #[derive(Debug)]
+    pub struct Request {
+        pub line: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers.iter()
+                .filter_map(|h| h.split_once(": "))
+                .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v)
+        }
+    }
+
+    /// Serves each of `responses` (status code and body) to one request, in order.
+    /// Returns the base URL, and a channel which receives each request.
+    pub fn serve(responses: Vec<(u16, String)>) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut headers = Vec::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end().to_string();
+                    if header.is_empty() {
+                        break;
+                    }
+                    headers.push(header);
+                }
+                let mut request = Request { line: line.trim_end().to_string(), headers, body: String::new() };
+                let length = request.header("Content-Length").map_or(0, |v| v.parse().unwrap());
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+                request.body = String::from_utf8(request_body).unwrap();
+                let _ = sender.send(request);
+
+                let mut stream = reader.into_inner();
+                write!(stream, "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+            }
+        });
+        (base_url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod client_tests {
+    use super::*;
+
+    #[test]
+    fn sends_session_cookie() {
+        let (base_url, requests) = stand_in::serve(vec![(200, "hello".to_string())]);
+        let client = Client::new(&base_url, Some("abc".to_string()));
+        assert_eq!(client.get("/2023/day/8/input").unwrap(), "hello");
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "GET /2023/day/8/input HTTP/1.1");
+        assert_eq!(request.header("Cookie"), Some("session=abc"));
+        assert_eq!(request.header("User-Agent"), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn reports_error_status() {
+        let (base_url, _requests) = stand_in::serve(vec![(404, "Not found".to_string())]);
+        let client = Client::new(&base_url, Some("abc".to_string()));
+        let err = client.get("/2023/day/30/input").unwrap_err();
+        assert_eq!(err.to_string(), "[Http] GET /2023/day/30/input returned 404: Not found");
+    }
+
+    #[test]
+    fn requires_session() {
+        let client = Client::new("http://127.0.0.1:1", None);
+        let err = client.get("/2023/day/8/input").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Usage);
+    }
+}
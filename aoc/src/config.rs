@@ -0,0 +1,113 @@
+// User settings which should not be committed, such as the session token.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use util::error::{ AppErr, Context, ErrorKind };
+use util::input::lines;
+
+/// Overrides the path of the config file.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Settings read from the config file, one `<key> = <value>` per line.
+/// Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Config {
+    values: BTreeMap<String, String>,
+}
+
+impl Config {
+    /// Reads the config file, if there is one.
+    /// This is `$AOC_CONFIG`, or `aoc/config` in the user's config directory.
+    pub fn load() -> Result<Config, AppErr> {
+        let path = match path() {
+            Some(path) if path.exists() => path,
+            _ => return Ok(Config::default()),
+        };
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        Config::parse(&text)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Config, AppErr> {
+        let mut values = BTreeMap::new();
+        for (number, line) in lines(text) {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line.split_once('=')
+                .ok_or_else(|| AppErr::new("Config", &format!("line {}: expected `<key> = <value>`", number)).with_kind(ErrorKind::Parse))?;
+            values.insert(key.trim().to_string(), value.trim().to_string());
+        }
+        Ok(Config { values })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// Each key starting with `prefix` with its value, without the prefix.
+    pub fn with_prefix(&self, prefix: &str) -> Vec<(String, String)> {
+        self.values.iter()
+            .filter_map(|(key, value)| Some((key.strip_prefix(prefix)?.to_string(), value.clone())))
+            .collect()
+    }
+
+    /// The value of the environment variable `var` if it is set, otherwise the value of `key`.
+    pub fn get_or_env(&self, key: &str, var: &str) -> Option<String> {
+        std::env::var(var).ok()
+            .filter(|value| !value.is_empty())
+            .or_else(|| self.get(key).map(String::from))
+    }
+}
+
+fn path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(CONFIG_VAR) {
+        return Some(PathBuf::from(path));
+    }
+    Some(config_dir()?.join("aoc").join("config"))
+}
+
+/// The user's config directory: `$XDG_CONFIG_HOME`, or `~/.config`.
+fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+}
+
+#[cfg(test)]
+mod config_tests {
+    use super::*;
+
+    #[test]
+    fn parses_values() {
+        let config = Config::parse("# comment\nsession = abc=123\n\n base_url=http://localhost:8080 \n").unwrap();
+        assert_eq!(config.get("session"), Some("abc=123"));
+        assert_eq!(config.get("base_url"), Some("http://localhost:8080"));
+        assert_eq!(config.get("missing"), None);
+    }
+
+    #[test]
+    fn finds_keys_by_prefix() {
+        let config = Config::parse("param.2018.7.workers = 2\nparam.2018.70.workers = 3\nparam.2018.7.base_duration = 0\n").unwrap();
+        assert_eq!(config.with_prefix("param.2018.7."), [
+            ("base_duration".to_string(), "0".to_string()),
+            ("workers".to_string(), "2".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn rejects_invalid_lines() {
+        let err = Config::parse("session abc").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Parse);
+    }
+
+    #[test]
+    fn config_is_used_without_env() {
+        let config = Config::parse("key = from config").unwrap();
+        assert_eq!(config.get_or_env("key", "AOC_TEST_UNSET_VARIABLE"), Some("from config".to_string()));
+    }
+}
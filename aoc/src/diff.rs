@@ -0,0 +1,141 @@
+// Runs every implementation of a day on the same inputs, reporting where they disagree.
+
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use util::answers::canonical;
+use util::solution::{ Part, Registration };
+
+use util::vault;
+
+use crate::examples;
+
+/// The answer to a part, or the error if it could not be solved.
+pub type PartOutcome = (Part, Result<String, String>);
+
+/// The result of one implementation on one input: the outcome of each part, or the error if it could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub name: String,
+    pub parts: Result<Vec<PartOutcome>, String>,
+}
+
+/// Solves both parts of `input` with each of `implementations`.
+pub fn run(implementations: &[(&str, &Registration)], input: &str) -> Vec<Outcome> {
+    implementations.iter()
+        .map(|&(name, registration)| Outcome {
+            name: name.to_string(),
+            parts: registration.solver.solve(input, &Part::ALL)
+                .map(|results| results.into_iter()
+                    .map(|(part, answer)| (part, answer.map(|a| canonical(&a)).map_err(|e| e.to_string())))
+                    .collect())
+                .map_err(|err| err.to_string()),
+        })
+        .collect()
+}
+
+/// Each way in which the other outcomes disagree with the first.
+/// Implementations agree if they give the same answer, or both fail; errors are not compared.
+pub fn disagreements(outcomes: &[Outcome]) -> Vec<String> {
+    let Some((first, others)) = outcomes.split_first() else { return Vec::new() };
+    let mut found = Vec::new();
+    for other in others {
+        match (&first.parts, &other.parts) {
+            (Ok(first_parts), Ok(other_parts)) => {
+                for ((part, a), (_, b)) in first_parts.iter().zip(other_parts) {
+                    let describe = |name: &str, result: &Result<String, String>| match *result {
+                        Ok(ref answer) => format!("{} gave {:?}", name, answer),
+                        Err(ref err)   => format!("{} failed: {}", name, err),
+                    };
+                    let agree = match (a, b) {
+                        (Ok(a), Ok(b)) => a == b,
+                        (Err(_), Err(_)) => true,
+                        _ => false,
+                    };
+                    if !agree {
+                        found.push(format!("{}: {}, {}", part, describe(&first.name, a), describe(&other.name, b)));
+                    }
+                }
+            }
+            (Ok(_), Err(err)) => found.push(format!("{} parsed the input, {} failed: {}", first.name, other.name, err)),
+            (Err(err), Ok(_)) => found.push(format!("{} failed to parse the input ({}), {} parsed it", first.name, err, other.name)),
+            (Err(_), Err(_)) => {}
+        }
+    }
+    found
+}
+
+/// The day's `input.txt` if there is one, then each example in its fixtures directory.
+pub fn inputs(day_dir: &Path) -> Vec<PathBuf> {
+    let mut inputs = Vec::new();
+    let input_path = day_dir.join("input.txt");
+    let encrypted = vault::encrypted_path(&input_path);
+    if input_path.exists() {
+        inputs.push(input_path);
+    }
+    else if encrypted.exists() {
+        inputs.push(encrypted);
+    }
+    if let Ok(entries) = fs::read_dir(examples::fixtures_dir(day_dir)) {
+        let mut examples = entries
+            .filter_map(Result::ok)
+            .map(|e| e.path())
+            .filter(|path| path.extension().is_some_and(|e| e == "txt"))
+            .collect::<Vec<_>>();
+        examples.sort();
+        inputs.extend(examples);
+    }
+    inputs
+}
+
+#[cfg(test)]
+mod diff_tests {
+    use util::solution::MAIN_IMPLEMENTATION;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    type Parts<'a> = Result<Vec<(Part, Result<&'a str, &'a str>)>, &'a str>;
+
+    fn outcome(name: &str, parts: Parts) -> Outcome {
+        Outcome {
+            name: name.to_string(),
+            parts: parts
+                .map(|parts| parts.into_iter().map(|(p, r)| (p, r.map(String::from).map_err(String::from))).collect())
+                .map_err(String::from),
+        }
+    }
+
+    #[test]
+    fn implementations_of_2023_day2_agree() {
+        let implementations = [(MAIN_IMPLEMENTATION, &aoc_2023_day2::SOLUTION), ("alt", &aoc_2023_day2_alt::SOLUTION)];
+        let outcomes = run(&implementations, EXAMPLE);
+        assert_eq!(outcomes[0].parts, Ok(vec![(Part::One, Ok("8".to_string())), (Part::Two, Ok("2286".to_string()))]));
+        assert_eq!(disagreements(&outcomes), Vec::<String>::new());
+
+        let outcomes = run(&implementations, "Game one: 3 blue");
+        assert!(outcomes.iter().all(|o| o.parts.is_err()));
+        assert_eq!(disagreements(&outcomes), Vec::<String>::new());
+    }
+
+    #[test]
+    fn reports_disagreements() {
+        let main = outcome("main", Ok(vec![(Part::One, Ok("8")), (Part::Two, Err("[NoSolution] none"))]));
+        let same = outcome("same", Ok(vec![(Part::One, Ok("8")), (Part::Two, Err("[Other] different error"))]));
+        let wrong = outcome("wrong", Ok(vec![(Part::One, Ok("9")), (Part::Two, Ok("1"))]));
+        let strict = outcome("strict", Err("[Parse] bad"));
+        assert_eq!(disagreements(&[main.clone(), same]), Vec::<String>::new());
+        assert_eq!(disagreements(&[main.clone(), wrong, strict.clone()]), [
+            "Part 1: main gave \"8\", wrong gave \"9\"",
+            "Part 2: main failed: [NoSolution] none, wrong gave \"1\"",
+            "main parsed the input, strict failed: [Parse] bad",
+        ]);
+        assert_eq!(disagreements(&[strict, main]), ["strict failed to parse the input ([Parse] bad), main parsed it"]);
+    }
+}
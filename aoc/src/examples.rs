@@ -0,0 +1,172 @@
+// Extracts the example inputs from the puzzle text embedded in a day's source.
+
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use util::error::{ AppErr, Context };
+use util::solution::Part;
+
+/// An example input found in the puzzle text for one part.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part: Part,
+    pub input: String,
+}
+
+/// Finds each example input in the block comments of `source`.
+///
+/// An example is a block of input lines directly after a line introducing it with
+/// "For example ...:", e.g. the claims in 2018 day 3. Examples which are only written
+/// inline in a sentence are not found.
+pub fn extract(source: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut part = Part::One;
+    let mut in_comment = false;
+    let mut block: Option<Vec<&str>> = None;
+
+    for line in source.lines() {
+        let trimmed = line.trim();
+        if !in_comment {
+            // A comment closed on the line it opens, e.g. `/* ... */`, holds no examples
+            in_comment = trimmed.starts_with("/*") && !trimmed[2..].contains("*/");
+            continue;
+        }
+        if trimmed.starts_with("*/") {
+            in_comment = false;
+            finish(&mut block, part, &mut examples);
+            continue;
+        }
+
+        if let Some(lines) = block.as_mut() {
+            if looks_like_input(trimmed) {
+                lines.push(line);
+                continue;
+            }
+            if !(trimmed.is_empty() && lines.is_empty()) {
+                finish(&mut block, part, &mut examples);
+            }
+        }
+
+        if trimmed.starts_with("--- Part One") {
+            part = Part::One;
+        }
+        else if trimmed.starts_with("--- Part Two") {
+            part = Part::Two;
+        }
+        else if trimmed.to_lowercase().contains("for example") && trimmed.ends_with(':') {
+            block = Some(Vec::new());
+        }
+    }
+    examples
+}
+
+/// Records the example in `block`, if any input lines were found.
+fn finish(block: &mut Option<Vec<&str>>, part: Part, examples: &mut Vec<Example>) {
+    let lines = match block.take() {
+        Some(lines) if !lines.is_empty() => lines,
+        _ => return,
+    };
+    // Strip the comment's indentation, keeping any indentation which is part of the input
+    let indent = lines.iter()
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let input = lines.iter()
+        .map(|line| line[indent..].trim_end())
+        .collect::<Vec<_>>()
+        .join("\n");
+    examples.push(Example { part, input });
+}
+
+/// Whether `line` reads as puzzle input rather than as a sentence.
+fn looks_like_input(line: &str) -> bool {
+    !line.is_empty() && !line.ends_with(|c| ".:?!)".contains(c))
+}
+
+/// The directory of a day's example fixtures, `tests/fixtures`, kept out of `examples`, where Cargo expects example programs.
+pub fn fixtures_dir(day_dir: &Path) -> PathBuf {
+    day_dir.join("tests").join("fixtures")
+}
+
+/// The fixture file for the `n`th example (counting from 1) of `part`, e.g. `tests/fixtures/part1-1.txt`.
+pub fn fixture_path(day_dir: &Path, part: Part, n: usize) -> PathBuf {
+    fixtures_dir(day_dir).join(format!("part{}-{}.txt", part.number(), n))
+}
+
+/// Writes each example found in the day's `src/lib.rs` to a fixture file in its fixtures directory.
+/// Existing fixtures are left untouched. Returns the paths of the fixtures which were written.
+pub fn write_fixtures(day_dir: &Path) -> Result<Vec<PathBuf>, AppErr> {
+    let source_path = day_dir.join("src").join("lib.rs");
+    let source = fs::read_to_string(&source_path)
+        .with_context(|| format!("failed to read {}", source_path.display()))?;
+
+    let mut written = Vec::new();
+    let mut counts = [0; 2];
+    for example in extract(&source) {
+        let count = &mut counts[example.part.number() as usize - 1];
+        *count += 1;
+        let path = fixture_path(day_dir, example.part, *count);
+        if path.exists() {
+            continue;
+        }
+        fs::create_dir_all(fixtures_dir(day_dir))
+            .with_context(|| format!("failed to create {}", fixtures_dir(day_dir).display()))?;
+        fs::write(&path, example.input + "\n")
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod examples_tests {
+    use super::*;
+
+    const SOURCE: &str = "
+fn parse() {}
+
+    /*
+    --- Part One ---
+    For example, if you see the following box IDs:
+
+    abcdef contains no letters that appear exactly two or three times.
+    bababc contains two a and three b, so it counts for both.
+
+    For example, consider the following claims:
+
+    #1 @ 1,3: 4x4
+      #2 @ 3,1: 4x4
+    Visually, these claim the following areas:
+    */
+    fn part1() {}
+
+    /*
+    --- Part Two ---
+    For example, given the following box IDs:
+
+    abcde
+    fghij
+    */
+    fn part2() {}
+";
+
+    #[test]
+    fn extracts_input_blocks() {
+        let examples = extract(SOURCE);
+        assert_eq!(examples, vec![
+            Example { part: Part::One, input: "#1 @ 1,3: 4x4\n  #2 @ 3,1: 4x4".to_string() },
+            Example { part: Part::Two, input: "abcde\nfghij".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn ignores_examples_outside_comments() {
+        assert_eq!(extract("// For example:\nlet x = 1;\n"), vec![]);
+    }
+
+    #[test]
+    fn closes_single_line_comments() {
+        let source = format!("/* A note */\n// For example:\nlet x = 1;\n{}", SOURCE);
+        assert_eq!(extract(&source), extract(SOURCE));
+    }
+}
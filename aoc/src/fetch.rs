@@ -0,0 +1,70 @@
+// Downloads puzzle inputs, keeping a cached copy of each.
+
+use std::fs;
+use std::path::Path;
+
+use util::error::{ AppErr, Context };
+
+use crate::client::Client;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    /// The input had already been downloaded, and was not fetched again.
+    Cached,
+}
+
+/// Downloads the input for `year` and `day` to `path`, unless it is already there.
+/// An empty file (e.g. the placeholder written by `aoc new`) does not count as cached.
+pub fn fetch_input(client: &Client, year: u32, day: u32, path: &Path) -> Result<Fetched, AppErr> {
+    if fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+    let input = client.get(&format!("/{}/day/{}/input", year, day))
+        .with_context(|| format!("failed to download the input for {} day {}", year, day))?;
+
+    // Write to a temporary file first, so a partial download is never mistaken for a cached input
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+    let partial = path.with_extension("partial");
+    fs::write(&partial, input)
+        .with_context(|| format!("failed to write {}", partial.display()))?;
+    fs::rename(&partial, path)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod fetch_tests {
+    use super::*;
+    use crate::client::stand_in;
+
+    #[test]
+    fn downloads_once() {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-test-{}", std::process::id()));
+        let path = dir.join("2023").join("day8").join("input.txt");
+
+        let (base_url, requests) = stand_in::serve(vec![(200, "LR\n".to_string())]);
+        let client = Client::new(&base_url, Some("abc".to_string()));
+        assert_eq!(fetch_input(&client, 2023, 8, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(requests.recv().unwrap().line, "GET /2023/day/8/input HTTP/1.1");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "LR\n");
+
+        // The stand-in only answers once, so a second download would fail
+        assert_eq!(fetch_input(&client, 2023, 8, &path).unwrap(), Fetched::Cached);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn failed_download_is_not_cached() {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-fail-test-{}", std::process::id()));
+        let path = dir.join("input.txt");
+
+        let (base_url, _requests) = stand_in::serve(vec![(400, "Please log in".to_string())]);
+        let client = Client::new(&base_url, Some("expired".to_string()));
+        assert!(fetch_input(&client, 2023, 8, &path).is_err());
+        assert!(!path.exists());
+    }
+}
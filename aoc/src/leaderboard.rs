@@ -0,0 +1,344 @@
+// Reports on a private leaderboard: rankings, star timelines and the time taken to solve part 2.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use util::error::{ AppErr, Context, ErrorKind };
+use util::json::{ self, Value };
+use util::solution::Part;
+
+use crate::client::Client;
+
+/// Puzzles unlock at midnight EST, which is 05:00 UTC.
+const UNLOCK_HOUR: u64 = 5;
+
+/// The number of puzzles in each event.
+const DAYS: u32 = 25;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u64,
+    /// When each part of each day was completed, as a Unix timestamp.
+    pub days: BTreeMap<u32, [Option<u64>; 2]>,
+}
+
+impl Member {
+    pub fn completed(&self, day: u32, part: Part) -> Option<u64> {
+        self.days.get(&day).and_then(|parts| parts[part.number() as usize - 1])
+    }
+
+    /// The time of the member's most recent star.
+    fn last_star(&self) -> u64 {
+        self.days.values().flatten().flatten().max().cloned().unwrap_or(0)
+    }
+}
+
+/// Reads a leaderboard saved from the website.
+pub fn read(path: &Path) -> Result<Leaderboard, AppErr> {
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    parse(&text)
+        .with_context(|| format!("failed to parse {}", path.display()))
+}
+
+/// Fetches the private leaderboard `id` for `year`.
+pub fn fetch(client: &Client, year: u32, id: &str) -> Result<Leaderboard, AppErr> {
+    let text = client.get(&format!("/{}/leaderboard/private/view/{}.json", year, id))
+        .with_context(|| format!("failed to fetch leaderboard {} for {}", id, year))?;
+    parse(&text)
+        .with_context(|| format!("failed to parse leaderboard {} for {}", id, year))
+}
+
+pub fn parse(text: &str) -> Result<Leaderboard, AppErr> {
+    let document = json::parse(text)?;
+    let event = field(&document, "event")?.as_str()
+        .ok_or_else(|| invalid("`event` is not a string"))?
+        .to_string();
+    let members = field(&document, "members")?.as_object()
+        .ok_or_else(|| invalid("`members` is not an object"))?
+        .iter()
+        .map(|(_, member)| parse_member(member))
+        .collect::<Result<_, _>>()?;
+    Ok(Leaderboard { event, members })
+}
+
+fn parse_member(member: &Value) -> Result<Member, AppErr> {
+    let id = number(member, "id")?;
+    // Anonymous members have no name
+    let name = match field(member, "name")? {
+        Value::String(name) => name.clone(),
+        _ => format!("(anonymous user #{})", id),
+    };
+    let mut days = BTreeMap::new();
+    let completion = field(member, "completion_day_level")?.as_object()
+        .ok_or_else(|| invalid("`completion_day_level` is not an object"))?;
+    for (day, parts) in completion {
+        let day = day.parse().ok()
+            .filter(|day| (1..=DAYS).contains(day))
+            .ok_or_else(|| invalid(&format!("invalid day `{}`", day)))?;
+        let mut completed = [None; 2];
+        for part in Part::ALL {
+            if let Some(star) = parts.get(&part.number().to_string()) {
+                completed[part.number() as usize - 1] = Some(number(star, "get_star_ts")?);
+            }
+        }
+        days.insert(day, completed);
+    }
+    Ok(Member {
+        id,
+        name,
+        local_score: number(member, "local_score")?,
+        stars: number(member, "stars")?,
+        days,
+    })
+}
+
+fn field<'a>(value: &'a Value, key: &str) -> Result<&'a Value, AppErr> {
+    value.get(key).ok_or_else(|| invalid(&format!("missing `{}`", key)))
+}
+
+fn number(value: &Value, key: &str) -> Result<u64, AppErr> {
+    field(value, key)?.as_u64().ok_or_else(|| invalid(&format!("`{}` is not a number", key)))
+}
+
+fn invalid(message: &str) -> AppErr {
+    AppErr::new("Leaderboard", message).with_kind(ErrorKind::Parse)
+}
+
+/// The members ordered by local score, then by who reached it first.
+pub fn ranking(leaderboard: &Leaderboard) -> Vec<&Member> {
+    let mut members = leaderboard.members.iter().collect::<Vec<_>>();
+    members.sort_by(|a, b| b.local_score.cmp(&a.local_score)
+        .then(a.last_star().cmp(&b.last_star()))
+        .then(a.name.cmp(&b.name)));
+    members
+}
+
+/// The ranking, with each member's stars for each day:
+/// `*` for both parts, `+` for only part 1 and `.` for neither.
+pub fn format_ranking(leaderboard: &Leaderboard) -> String {
+    let ranking = ranking(leaderboard);
+    let last_day = ranking.iter()
+        .filter_map(|m| m.days.keys().max().cloned())
+        .max()
+        .unwrap_or(0);
+
+    let mut text = String::new();
+    let tens = (1..=last_day).map(|day| if day >= 10 { char::from(b'0' + (day / 10) as u8) } else { ' ' });
+    let units = (1..=last_day).map(|day| char::from(b'0' + (day % 10) as u8));
+    if last_day >= 10 {
+        writeln!(text, "{:17}{}", "", tens.collect::<String>()).unwrap();
+    }
+    writeln!(text, "{:17}{}", "", units.collect::<String>()).unwrap();
+
+    let mut rank = 0;
+    for (i, member) in ranking.iter().enumerate() {
+        // Members with the same score share a rank
+        if i == 0 || ranking[i - 1].local_score != member.local_score {
+            rank = i + 1;
+        }
+        let stars = (1..=last_day)
+            .map(|day| match (member.completed(day, Part::One), member.completed(day, Part::Two)) {
+                (Some(_), Some(_)) => '*',
+                (Some(_), None)    => '+',
+                _                  => '.',
+            })
+            .collect::<String>();
+        writeln!(text, "{:>3}) {:>5} {:>3}* {} {}", rank, member.local_score, member.stars, stars, member.name).unwrap();
+    }
+    text
+}
+
+/// Every star in the order it was earned, with the time since the puzzle unlocked.
+pub fn format_timeline(leaderboard: &Leaderboard, year: u32) -> String {
+    let mut stars = leaderboard.members.iter()
+        .flat_map(|member| member.days.iter().flat_map(move |(&day, parts)| {
+            Part::ALL.iter()
+                .zip(parts)
+                .filter_map(move |(&part, &time)| Some((time?, day, part, member)))
+        }))
+        .collect::<Vec<_>>();
+    stars.sort_by(|a, b| (a.0, a.1, a.2.number(), &a.3.name).cmp(&(b.0, b.1, b.2.number(), &b.3.name)));
+
+    let mut text = String::new();
+    for (time, day, part, member) in stars {
+        let elapsed = time.saturating_sub(unlock_time(year, day));
+        writeln!(text, "{}  day {:>2} part {}  {:>11}  {}", format_timestamp(time), day, part.number(), format_elapsed(elapsed), member.name).unwrap();
+    }
+    text
+}
+
+/// How long each member took to solve part 2 after solving part 1.
+pub fn format_gaps(leaderboard: &Leaderboard) -> String {
+    let mut text = String::new();
+    for member in ranking(leaderboard) {
+        let mut gaps = member.days.iter()
+            .filter_map(|(&day, parts)| match *parts {
+                [Some(one), Some(two)] => Some((two.saturating_sub(one), day)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if gaps.is_empty() {
+            continue;
+        }
+        gaps.sort();
+        let (fastest, fastest_day) = gaps[0];
+        let (slowest, slowest_day) = gaps[gaps.len() - 1];
+        let median = gaps[gaps.len() / 2].0;
+        writeln!(text, "{}: {} days, median {}, fastest {} (day {}), slowest {} (day {})",
+            member.name, gaps.len(), format_elapsed(median), format_elapsed(fastest), fastest_day, format_elapsed(slowest), slowest_day).unwrap();
+    }
+    text
+}
+
+/// The Unix timestamp at which the puzzle for `day` unlocked.
+fn unlock_time(year: u32, day: u32) -> u64 {
+    days_from_civil(year, 12, day) * 86400 + UNLOCK_HOUR * 3600
+}
+
+/// Formats a Unix timestamp as a UTC date and time.
+fn format_timestamp(time: u64) -> String {
+    let (year, month, day) = civil_from_days(time / 86400);
+    let seconds = time % 86400;
+    format!("{}-{:02}-{:02} {:02}:{:02}:{:02}", year, month, day, seconds / 3600, seconds / 60 % 60, seconds % 60)
+}
+
+/// Formats a number of seconds as `h:mm:ss`, or `Nd hh:mm:ss` if it is more than a day.
+fn format_elapsed(seconds: u64) -> String {
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    match days {
+        0    => format!("{}:{:02}:{:02}", hours, minutes, seconds),
+        days => format!("{}d {:02}:{:02}:{:02}", days, hours, minutes, seconds),
+    }
+}
+
+/// The number of days from 1970-01-01 to a date, which must not be before it.
+fn days_from_civil(year: u32, month: u32, day: u32) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year } as u64;
+    let (era, year_of_era) = (year / 400, year % 400);
+    let day_of_year = (153 * ((month as u64 + 9) % 12) + 2) / 5 + day as u64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The date `days` after 1970-01-01.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719468;
+    let (era, day_of_era) = (days / 146097, days % 146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = era * 400 + year_of_era + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod leaderboard_tests {
+    use super::*;
+
+    // 2023-12-01 05:00:00 UTC
+    const DAY_1: u64 = 1701406800;
+
+    fn sample() -> String {
+        format!(r#"{{"owner_id":1,"event":"2023","members":{{
+            "1":{{"id":1,"name":"Ada","stars":4,"local_score":10,"global_score":0,"last_star_ts":{d2p2},
+                "completion_day_level":{{"1":{{"1":{{"get_star_ts":{d1p1},"star_index":1}},"2":{{"get_star_ts":{d1p2},"star_index":5}}}},
+                                        "2":{{"1":{{"get_star_ts":{d2p1},"star_index":9}},"2":{{"get_star_ts":{d2p2},"star_index":12}}}}}}}},
+            "2":{{"id":2,"name":null,"stars":1,"local_score":3,"global_score":0,"last_star_ts":{b},
+                "completion_day_level":{{"2":{{"1":{{"get_star_ts":{b},"star_index":7}}}}}}}},
+            "3":{{"id":3,"name":"Bea","stars":3,"local_score":10,"global_score":0,"last_star_ts":{c},
+                "completion_day_level":{{"1":{{"1":{{"get_star_ts":{c0},"star_index":2}},"2":{{"get_star_ts":{c1},"star_index":3}}}},
+                                        "3":{{"1":{{"get_star_ts":{c},"star_index":20}}}}}}}}}}}}"#,
+            d1p1 = DAY_1 + 754, d1p2 = DAY_1 + 1000, d2p1 = DAY_1 + 86400 + 60, d2p2 = DAY_1 + 86400 + 3660,
+            b = DAY_1 + 86400 + 30, c0 = DAY_1 + 900, c1 = DAY_1 + 960, c = DAY_1 + 2 * 86400 + 5)
+    }
+
+    #[test]
+    fn parses_members() {
+        let leaderboard = parse(&sample()).unwrap();
+        assert_eq!(leaderboard.event, "2023");
+        assert_eq!(leaderboard.members.len(), 3);
+        let ada = &leaderboard.members[0];
+        assert_eq!((ada.id, ada.name.as_str(), ada.local_score, ada.stars), (1, "Ada", 10, 4));
+        assert_eq!(ada.completed(1, Part::One), Some(DAY_1 + 754));
+        assert_eq!(ada.completed(3, Part::One), None);
+        assert_eq!(leaderboard.members[1].name, "(anonymous user #2)");
+        assert_eq!(leaderboard.members[1].completed(2, Part::Two), None);
+    }
+
+    #[test]
+    fn rejects_invalid_leaderboards() {
+        let with_day = |day: &str| format!(r#"{{"event":"2023","members":{{"1":{{"id":1,"name":"A","local_score":0,"stars":1,
+            "completion_day_level":{{"{}":{{"1":{{"get_star_ts":1701406900}}}}}}}}}}}}"#, day);
+        assert!(parse(&with_day("25")).is_ok());
+        for text in ["[]", r#"{"event":"2023"}"#, r#"{"event":"2023","members":{"1":{"id":1,"name":"A"}}}"#, &with_day("0"), &with_day("26"), &with_day("4000000000")] {
+            assert_eq!(parse(text).unwrap_err().kind(), ErrorKind::Parse, "{}", text);
+        }
+    }
+
+    #[test]
+    fn ranks_by_local_score() {
+        let leaderboard = parse(&sample()).unwrap();
+        let names = ranking(&leaderboard).iter().map(|m| m.name.as_str()).collect::<Vec<_>>();
+        // Ada and Bea have the same score, but Ada finished first
+        assert_eq!(names, ["Ada", "Bea", "(anonymous user #2)"]);
+        assert_eq!(format_ranking(&leaderboard), concat!(
+            "                 123\n",
+            "  1)    10   4* **. Ada\n",
+            "  1)    10   3* *.+ Bea\n",
+            "  3)     3   1* .+. (anonymous user #2)\n"));
+    }
+
+    #[test]
+    fn lists_stars_in_order() {
+        let timeline = format_timeline(&parse(&sample()).unwrap(), 2023);
+        let lines = timeline.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 8);
+        assert_eq!(lines[0], "2023-12-01 05:12:34  day  1 part 1      0:12:34  Ada");
+        assert_eq!(lines[1], "2023-12-01 05:15:00  day  1 part 1      0:15:00  Bea");
+        assert_eq!(lines[7], "2023-12-03 05:00:05  day  3 part 1      0:00:05  Bea");
+    }
+
+    #[test]
+    fn measures_part_two_gaps() {
+        assert_eq!(format_gaps(&parse(&sample()).unwrap()), "\
+Ada: 2 days, median 1:00:00, fastest 0:04:06 (day 1), slowest 1:00:00 (day 2)
+Bea: 1 days, median 0:01:00, fastest 0:01:00 (day 1), slowest 0:01:00 (day 1)
+");
+    }
+
+    #[test]
+    fn converts_dates() {
+        assert_eq!(unlock_time(2023, 1), DAY_1);
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00");
+        assert_eq!(format_timestamp(951782400), "2000-02-29 00:00:00");
+        assert_eq!(format_elapsed(2 * 86400 + 3723), "2d 01:02:03");
+    }
+
+    #[test]
+    fn fetches_private_leaderboard() {
+        use crate::client::stand_in;
+
+        let (base_url, requests) = stand_in::serve(vec![(200, sample())]);
+        let client = Client::new(&base_url, Some("abc".to_string()));
+        let leaderboard = fetch(&client, 2023, "12345").unwrap();
+        assert_eq!(leaderboard.members.len(), 3);
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "GET /2023/leaderboard/private/view/12345.json HTTP/1.1");
+        assert_eq!(request.header("Cookie"), Some("session=abc"));
+    }
+}
@@ -0,0 +1,525 @@
+// Runs, verifies and benchmarks the registered solutions: `aoc run <year> <day>`.
+
+mod args;
+mod bench;
+mod cache;
+mod calendar;
+mod client;
+mod config;
+mod diff;
+mod examples;
+mod fetch;
+mod leaderboard;
+mod new_day;
+mod puzzle;
+mod registry;
+mod report;
+mod server;
+mod submit;
+mod verify;
+mod watch;
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::{ SystemTime, UNIX_EPOCH };
+
+use util::answers::Answers;
+use util::error::{ AppErr, Context, ErrorKind, Exit };
+use util::params::{ self, Overrides };
+use util::solution::{ Answer, Part, Registration, Registry, MAIN_IMPLEMENTATION };
+use util::vault;
+
+use args::{ BenchArgs, CalendarArgs, Command, DayArgs, DiffArgs, LeaderboardArgs, ReportArgs, RunArgs, ServeArgs, SubmitArgs, VaultArgs, VerifyArgs };
+
+/// Overrides the directory holding each year's puzzle inputs.
+/// Defaults to the root of the repository.
+const ROOT_VAR: &str = "AOC_ROOT";
+
+/// Counts allocations, so that the memory used by each phase can be reported.
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: util::memory::CountingAllocator = util::memory::CountingAllocator;
+
+fn main() -> Exit {
+    run(std::env::args().skip(1)).into()
+}
+
+fn run(args: impl Iterator<Item=String>) -> Result<(), AppErr> {
+    match args::parse(args)? {
+        Command::Run(args) => run_day(&args),
+        Command::Watch(args) => watch(&args),
+        Command::Diff(args) => diff(&args),
+        Command::Verify(args) => verify(&args),
+        Command::Bench(args) => bench(&args),
+        Command::Examples(args) => write_examples(&args),
+        Command::New(args) => new_day(&args),
+        Command::Fetch(args) => fetch(&args),
+        Command::Submit(args) => submit(&args),
+        Command::Puzzle(args) => update_puzzle(&args),
+        Command::Leaderboard(args) => show_leaderboard(&args),
+        Command::Calendar(args) => show_calendar(&args),
+        Command::Vault(VaultArgs::Add { year, day, input }) => add_to_vault(year, day, input),
+        Command::Vault(VaultArgs::Rotate) => rotate_vault_key(),
+        Command::Serve(args) => serve(&args),
+        Command::Help => {
+            print!("{}", args::USAGE);
+            Ok(())
+        }
+    }
+}
+
+fn run_day(args: &RunArgs) -> Result<(), AppErr> {
+    let registry = registry::registry();
+    let registration = find_implementation(&registry, args)?;
+
+    let input_path = match args.input {
+        Some(ref path) => path.clone(),
+        None           => default_input_path(args.year, args.day),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None       => Part::ALL.to_vec(),
+    };
+
+    let overrides = param_overrides(registration, args)?;
+    for (name, value) in &overrides {
+        println!("Using {} = {}", name, value);
+    }
+
+    let implementation = args.implementation.as_deref().unwrap_or(MAIN_IMPLEMENTATION);
+    let mut store = cache::Store::open(args.force);
+    let run = params::with_overrides(&overrides, || store.run_day(implementation, registration, &input_path, &parts, args.timeout));
+    store.save();
+    write_reports(&args.report, &report::Entry::from_run(&run, &parts))?;
+
+    let parts = run.parts?;
+    if let Some(usage) = run.parse_memory {
+        println!("Parse memory: {}", usage);
+    }
+    for report::PartRun { part, answer, memory, cached, .. } in parts {
+        let answer = answer.with_context(|| format!("failed to solve {}", part))?;
+        let cached = if cached { " (cached)" } else { "" };
+        match answer {
+            Answer::Grid(_) => println!("{}{}:\n{}", part, cached, answer),
+            _               => println!("{}: {}{}", part, answer, cached),
+        }
+        if let Some(usage) = memory {
+            println!("{} memory: {}", part, usage);
+        }
+    }
+    Ok(())
+}
+
+fn watch(args: &RunArgs) -> Result<(), AppErr> {
+    let registry = registry::registry();
+    param_overrides(find_implementation(&registry, args)?, args)?;
+    let day_dir = day_dir(args.year, args.day);
+    let input_path = match args.input {
+        Some(ref path) => std::path::absolute(path)?,
+        None           => default_input_path(args.year, args.day),
+    };
+    let report_path = match args.report.json {
+        Some(ref path) => std::path::absolute(path)?,
+        None           => std::env::temp_dir().join(format!("aoc-watch-{}.json", std::process::id())),
+    };
+    let mut watcher = watch::Watcher::new(&day_dir, &input_path)?;
+
+    let mut previous = Vec::new();
+    loop {
+        match watch::run(&repo_dir(), args, &input_path, &report_path) {
+            Ok(entries) => {
+                print!("{}", watch::format_changes(&previous, &entries));
+                previous = entries;
+            }
+            // Keep watching for a fix
+            Err(err) => eprintln!("{}", err),
+        }
+        println!();
+        println!("Watching {} for changes", day_dir.display());
+
+        let changed = watcher.wait()?;
+        for path in changed {
+            println!("Changed {}", path.display());
+        }
+    }
+}
+
+/// Values for the day's parameters: those set in the config file as `param.<year>.<day>.<name> = <value>`,
+/// then those given with `--param`.
+fn param_overrides(registration: &Registration, args: &RunArgs) -> Result<Overrides, AppErr> {
+    let config = config::Config::load()?;
+    let mut overrides = config.with_prefix(&format!("param.{}.{}.", args.year, args.day));
+    overrides.extend(args.params.iter().cloned());
+    params::check_overrides(registration.solver.params(), &overrides)?;
+    Ok(overrides)
+}
+
+/// The implementation of the day chosen by `args`, which is the main implementation unless `--impl` is given.
+fn find_implementation<'a>(registry: &'a Registry, args: &RunArgs) -> Result<&'a Registration, AppErr> {
+    let name = args.implementation.as_deref().unwrap_or(MAIN_IMPLEMENTATION);
+    registry.find_implementation(args.year, args.day, name).ok_or_else(|| match name {
+        MAIN_IMPLEMENTATION => AppErr::usage(&format!("no solution for {} day {}", args.year, args.day)),
+        _ => AppErr::usage(&format!("no implementation `{}` of {} day {}", name, args.year, args.day)),
+    })
+}
+
+fn diff(args: &DiffArgs) -> Result<(), AppErr> {
+    let registry = registry::registry();
+    let selected = select(&registry, args.year, args.day).into_iter()
+        .map(|r| registry.implementations(r.year, r.day))
+        .filter(|implementations| implementations.len() > 1)
+        .collect::<Vec<_>>();
+    if selected.is_empty() {
+        return Err(AppErr::usage("no days with more than one implementation"));
+    }
+
+    let mut compared = 0;
+    let mut disagreed = 0;
+    for implementations in selected {
+        let (_, registration) = implementations[0];
+        let names = implementations.iter().map(|&(name, _)| name).collect::<Vec<_>>();
+        println!("{} day {}: {}", registration.year, registration.day, names.join(", "));
+
+        let day_dir = day_dir(registration.year, registration.day);
+        for path in diff::inputs(&day_dir).iter().chain(&args.inputs) {
+            let input = report::read_input(path)?;
+            let found = diff::disagreements(&diff::run(&implementations, input.as_str()));
+            let name = path.strip_prefix(&day_dir).unwrap_or(path).display();
+            compared += 1;
+            if found.is_empty() {
+                println!("    {}: agree", name);
+                continue;
+            }
+            disagreed += 1;
+            println!("    {}: DISAGREE", name);
+            for disagreement in found {
+                println!("        {}", disagreement);
+            }
+        }
+    }
+
+    println!();
+    println!("{} inputs compared, {} with disagreements", compared, disagreed);
+    if disagreed == 0 {
+        Ok(())
+    }
+    else {
+        Err(AppErr::new("Diff", &format!("implementations disagreed on {} of {} inputs", disagreed, compared)))
+    }
+}
+
+fn verify(args: &VerifyArgs) -> Result<(), AppErr> {
+    let answers_path = match args.answers {
+        Some(ref path) => path.clone(),
+        None           => root_dir().join("answers.txt"),
+    };
+    let answers = Answers::read(&answers_path)?;
+
+    let registry = registry::registry();
+    let selected = select(&registry, args.year, args.day);
+    if selected.is_empty() {
+        return Err(AppErr::usage("no solutions to verify"));
+    }
+
+    let mut store = cache::Store::open(args.force);
+    let mut tally = verify::Tally::default();
+    let mut entries = Vec::new();
+    for registration in selected {
+        let input_path = default_input_path(registration.year, registration.day);
+        let run = store.run_day(MAIN_IMPLEMENTATION, registration, &input_path, &Part::ALL, args.timeout);
+        entries.extend(verify::verify_day(&run, registration.solver.slow_parts(), &answers, &mut tally));
+    }
+    store.save();
+    write_reports(&args.report, &entries)?;
+
+    println!();
+    println!("{} passed, {} failed, {} errors, {} timed out, {} known slow, {} missing", tally.passed, tally.failed, tally.errors, tally.timeouts, tally.slow, tally.missing);
+
+    if tally.is_success() {
+        Ok(())
+    }
+    else {
+        // Exit as the most serious failure would have, e.g. as a timeout if every other part passed
+        let failed = tally.failed + tally.errors + tally.timeouts;
+        Err(AppErr::new("Verify", &format!("{} of {} answers did not match", failed, tally.passed + failed))
+            .with_kind(tally.worst.unwrap_or(ErrorKind::Other)))
+    }
+}
+
+fn write_reports(args: &ReportArgs, entries: &[report::Entry]) -> Result<(), AppErr> {
+    if let Some(ref path) = args.json {
+        report::write(path, &report::to_json(entries))?;
+    }
+    if let Some(ref path) = args.junit {
+        report::write(path, &report::to_junit(entries))?;
+    }
+    Ok(())
+}
+
+fn bench(args: &BenchArgs) -> Result<(), AppErr> {
+    let registry = registry::registry();
+    let selected = select(&registry, args.year, args.day);
+    if selected.is_empty() {
+        return Err(AppErr::usage("no solutions to bench"));
+    }
+
+    println!("Running each day {} times", args.iterations);
+    let mut years = Vec::<(u32, Vec<bench::DayBench>)>::new();
+    let mut errors = 0;
+    for registration in selected {
+        let input_path = default_input_path(registration.year, registration.day);
+        match bench::bench_day(registration, &input_path, args.iterations, args.timeout) {
+            Ok(day) => {
+                print!("{}", bench::format_day(&day));
+                match years.last_mut() {
+                    Some((year, days)) if *year == day.year => days.push(day),
+                    _ => years.push((day.year, vec![day])),
+                }
+            }
+            Err(err) => {
+                errors += 1;
+                println!("{} day {}: ERROR {}", registration.year, registration.day, err);
+            }
+        }
+    }
+
+    for (year, days) in &years {
+        println!();
+        print!("{}", bench::format_year(*year, days));
+    }
+
+    if errors == 0 {
+        Ok(())
+    }
+    else {
+        Err(AppErr::new("Bench", &format!("{} days could not be run", errors)))
+    }
+}
+
+fn write_examples(args: &DayArgs) -> Result<(), AppErr> {
+    let written = examples::write_fixtures(&day_dir(args.year, args.day))?;
+    if written.is_empty() {
+        println!("No new examples found");
+    }
+    for path in written {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn new_day(args: &DayArgs) -> Result<(), AppErr> {
+    for path in new_day::create_day(&repo_dir(), args.year, args.day)? {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn fetch(args: &DayArgs) -> Result<(), AppErr> {
+    let client = client::Client::from_config(&config::Config::load()?);
+    let path = default_input_path(args.year, args.day);
+    match fetch::fetch_input(&client, args.year, args.day, &path)? {
+        fetch::Fetched::Downloaded => println!("Downloaded {}", path.display()),
+        fetch::Fetched::Cached     => println!("Already downloaded {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(args: &SubmitArgs) -> Result<(), AppErr> {
+    let answer = match args.answer {
+        Some(ref answer) => answer.clone(),
+        None             => solve(args.year, args.day, args.part)?,
+    };
+    let client = client::Client::from_config(&config::Config::load()?);
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let log_path = root_dir().join("submissions.txt");
+
+    let attempt = submit::submit(&client, &log_path, args.year, args.day, args.part, &answer, now)?;
+    match attempt.wait {
+        0    => println!("{} day {} {}: {:?} is {}", args.year, args.day, args.part, answer, attempt.outcome),
+        wait => println!("{} day {} {}: {:?} is {} (wait {}s before trying again)", args.year, args.day, args.part, answer, attempt.outcome, wait),
+    }
+
+    // Solving part 1 unlocks the description of part 2
+    let puzzle_path = day_dir(args.year, args.day).join("PUZZLE.md");
+    if attempt.outcome == submit::Outcome::Correct && args.part == Part::One && puzzle_path.exists() {
+        update_puzzle(&DayArgs { year: args.year, day: args.day })?;
+    }
+    Ok(())
+}
+
+fn update_puzzle(args: &DayArgs) -> Result<(), AppErr> {
+    let client = client::Client::from_config(&config::Config::load()?);
+    let path = day_dir(args.year, args.day).join("PUZZLE.md");
+    match puzzle::update(&client, args.year, args.day, &path)? {
+        puzzle::Updated::Written { part_two: true }  => println!("Wrote {}", path.display()),
+        puzzle::Updated::Written { part_two: false } => println!("Wrote {} (part 2 is not yet unlocked)", path.display()),
+        puzzle::Updated::Unchanged => println!("{} is up to date (part 2 is not yet unlocked)", path.display()),
+        puzzle::Updated::Complete  => println!("{} already includes part 2", path.display()),
+    }
+    Ok(())
+}
+
+fn show_leaderboard(args: &LeaderboardArgs) -> Result<(), AppErr> {
+    let leaderboard = match args.file {
+        Some(ref path) => leaderboard::read(path)?,
+        None => {
+            let config = config::Config::load()?;
+            let id = args.id.as_deref()
+                .or_else(|| config.get("leaderboard"))
+                .ok_or_else(|| AppErr::usage("no leaderboard: use --id, --file or set `leaderboard` in the config file"))?;
+            leaderboard::fetch(&client::Client::from_config(&config), args.year, id)?
+        }
+    };
+
+    println!("Ranking:");
+    print!("{}", leaderboard::format_ranking(&leaderboard));
+    println!();
+    println!("Part 2 after part 1:");
+    print!("{}", leaderboard::format_gaps(&leaderboard));
+    println!();
+    println!("Stars:");
+    print!("{}", leaderboard::format_timeline(&leaderboard, args.year));
+    Ok(())
+}
+
+fn show_calendar(args: &CalendarArgs) -> Result<(), AppErr> {
+    let answers_path = match args.answers {
+        Some(ref path) => path.clone(),
+        None           => root_dir().join("answers.txt"),
+    };
+    let answers = Answers::read(&answers_path)?;
+    let entries = match args.report {
+        Some(ref path) => report::read(path)?,
+        None           => Vec::new(),
+    };
+    let calendar = calendar::Calendar::new(&answers, &entries);
+
+    println!("* solved, ? implemented but unverified, . missing");
+    if let Some(year) = args.year {
+        println!();
+        print!("{}", calendar::format_year(&calendar, year));
+        return Ok(());
+    }
+    for year in calendar::FIRST_YEAR..=calendar::LAST_YEAR {
+        println!();
+        print!("{}", calendar::format_year(&calendar, year));
+    }
+    println!();
+    print!("{}", calendar::format_summary(&calendar));
+    Ok(())
+}
+
+/// Encrypts an input into the day's `input.txt.enc`, creating a key file if there is no key yet.
+/// If the input is the day's plain text `input.txt`, it is removed once it is encrypted, so that it is not committed.
+/// Another input is refused while the day has an `input.txt`, which would be read in place of the encrypted input.
+fn add_to_vault(year: u32, day: u32, input: Option<PathBuf>) -> Result<(), AppErr> {
+    let plain_path = day_dir(year, day).join("input.txt");
+    let source = input.unwrap_or_else(|| plain_path.clone());
+    let same_file = |a: &PathBuf, b: &PathBuf| a == b || fs::canonicalize(a).ok().is_some_and(|a| fs::canonicalize(b).ok() == Some(a));
+    let source_is_plain = same_file(&source, &plain_path);
+    if !source_is_plain && plain_path.exists() {
+        return Err(AppErr::usage(&format!("{} would be read in place of the encrypted input: encrypt it with `aoc vault add {} {}`, or remove it first", plain_path.display(), year, day)));
+    }
+    let key = match vault::find_key()? {
+        Some((key, _)) => key,
+        None => {
+            let key_path = vault::key_path()
+                .ok_or_else(|| AppErr::usage(&format!("no config directory for the key file: set {}", vault::KEY_FILE_VAR)))?;
+            let key = vault::Key::generate()?;
+            vault::save_key(&key_path, &key)?;
+            println!("Wrote a new key to {}", key_path.display());
+            key
+        }
+    };
+
+    let path = vault::encrypted_path(&plain_path);
+    vault::encrypt_file(&key, &source, &path)?;
+    println!("Encrypted {} to {}", source.display(), path.display());
+    if source_is_plain {
+        fs::remove_file(&plain_path)
+            .with_context(|| format!("failed to remove {}", plain_path.display()))?;
+        println!("Removed {}", plain_path.display());
+    }
+    Ok(())
+}
+
+/// Re-encrypts every input with a new key.
+/// The new key replaces the key file, or is printed to be set in `AOC_VAULT_KEY` if that is where the old key came from.
+fn rotate_vault_key() -> Result<(), AppErr> {
+    let (key, source) = vault::load_key()?;
+    let key_path = match source {
+        vault::KeySource::File(path) => path,
+        vault::KeySource::Env => return Err(AppErr::usage(&format!("only a key file can be rotated: unset {}", vault::KEY_VAR))),
+    };
+
+    // A key kept by a rotation which failed part way through is still the key of some inputs
+    let old_path = vault::old_key_path(&key_path);
+    let (old, new) = if old_path.exists() {
+        let text = fs::read_to_string(&old_path)
+            .with_context(|| format!("failed to read {}", old_path.display()))?;
+        let old = vault::Key::parse(&text).with_context(|| format!("failed to read {}", old_path.display()))?;
+        println!("Finishing the rotation from the key in {}", old_path.display());
+        (old, key)
+    }
+    else {
+        (key, vault::Key::generate()?)
+    };
+    let paths = vault::encrypted_inputs(&root_dir());
+    vault::rotate(&paths, &key_path, &old, &new)?;
+    println!("Wrote the new key to {}", key_path.display());
+    println!("Re-encrypted {} inputs", paths.len());
+    Ok(())
+}
+
+fn serve(args: &ServeArgs) -> Result<(), AppErr> {
+    let listener = std::net::TcpListener::bind(("127.0.0.1", args.port))
+        .with_context(|| format!("failed to listen on port {}", args.port))?;
+    println!("Listening on http://{}", listener.local_addr()?);
+    server::serve(listener, registry::registry(), args.timeout, args.jobs)
+}
+
+/// Solves one part of a day against its `input.txt`.
+fn solve(year: u32, day: u32, part: Part) -> Result<String, AppErr> {
+    let registry = registry::registry();
+    let registration = registry.find(year, day)
+        .ok_or_else(|| AppErr::usage(&format!("no solution for {} day {}", year, day)))?;
+    let mut store = cache::Store::open(false);
+    let run = store.run_day(MAIN_IMPLEMENTATION, registration, &default_input_path(year, day), &[part], Some(args::DEFAULT_TIMEOUT));
+    store.save();
+    let result = run.parts?.pop().ok_or("no answer")?;
+    match result.answer.with_context(|| format!("failed to solve {}", part))? {
+        Answer::Grid(_) => Err(AppErr::usage("the answer is a picture: read it and submit it as text")),
+        answer          => Ok(answer.to_string()),
+    }
+}
+
+/// The registered solutions, optionally only those of one year or one day.
+fn select(registry: &Registry, year: Option<u32>, day: Option<u32>) -> Vec<&Registration> {
+    registry.iter()
+        .filter(|r| year.is_none_or(|year| r.year == year))
+        .filter(|r| day.is_none_or(|day| r.day == day))
+        .collect()
+}
+
+/// The directory holding each year's crates, and the recorded answers.
+fn root_dir() -> PathBuf {
+    std::env::var_os(ROOT_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(repo_dir)
+}
+
+/// The workspace this runner was built from, which new days are added to.
+fn repo_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// The day's crate, e.g. `2020/day8`.
+fn day_dir(year: u32, day: u32) -> PathBuf {
+    root_dir().join(year.to_string()).join(format!("day{}", day))
+}
+
+/// The `input.txt` in the day's crate, e.g. `2020/day8/input.txt`,
+/// or the encrypted `input.txt.enc` if there is no plain text input.
+fn default_input_path(year: u32, day: u32) -> PathBuf {
+    vault::resolve_input(&day_dir(year, day).join("input.txt"))
+}
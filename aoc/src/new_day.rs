@@ -0,0 +1,164 @@
+// Creates the crate for a new day from the templates, and registers it with the runner.
+
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use util::error::{ AppErr, Context };
+use util::solution::Part;
+
+use crate::examples;
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+
+/// Creates `<year>/day<day>` under `repo_dir`, adds it to the runner's dependencies and
+/// registry, and to the workspace if it is the first day of the year.
+/// Returns the paths of each file which was created or changed.
+pub fn create_day(repo_dir: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, AppErr> {
+    let day_dir = repo_dir.join(year.to_string()).join(format!("day{}", day));
+    if day_dir.exists() {
+        return Err(AppErr::usage(&format!("{} already exists", day_dir.display())));
+    }
+
+    // Check every file can be updated before creating anything
+    let workspace_path = repo_dir.join("Cargo.toml");
+    let manifest_path = repo_dir.join("aoc").join("Cargo.toml");
+    let registry_path = repo_dir.join("aoc").join("src").join("registry.rs");
+    let workspace = edit(&workspace_path, |text| add_workspace_member(text, year))?;
+    let manifest = edit(&manifest_path, |text| add_dependency(text, year, day))?;
+    let registry = edit(&registry_path, |text| add_registration(text, year, day))?;
+
+    let files = [
+        (day_dir.join("Cargo.toml"), render(CARGO_TEMPLATE, year, day)),
+        (day_dir.join("src").join("main.rs"), render(MAIN_TEMPLATE, year, day)),
+        (day_dir.join("src").join("lib.rs"), render(LIB_TEMPLATE, year, day)),
+        (examples::fixture_path(&day_dir, Part::One, 1), String::new()),
+        (day_dir.join("input.txt"), String::new()),
+    ];
+    let mut written = Vec::new();
+    for (path, text) in files.into_iter().chain([(workspace_path, workspace), (manifest_path, manifest), (registry_path, registry)]) {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        fs::write(&path, text)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn edit<F>(path: &Path, f: F) -> Result<String, AppErr>
+    where F: FnOnce(&str) -> Result<String, AppErr>
+{
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    f(&text).with_context(|| format!("failed to update {}", path.display()))
+}
+
+pub fn render(template: &str, year: u32, day: u32) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Adds `"<year>/day*"` to the workspace members, unless already present.
+pub fn add_workspace_member(text: &str, year: u32) -> Result<String, AppErr> {
+    let line = format!("    \"{}/day*\",", year);
+    if text.lines().any(|l| l == line) {
+        return Ok(text.to_string());
+    }
+    insert_ordered(text, &line, |l| {
+        let year = l.trim().strip_prefix('"')?.strip_suffix("/day*\",")?;
+        Some((year.parse().ok()?, 0))
+    })
+}
+
+/// Adds `aoc-<year>-day<day>` to the runner's dependencies.
+pub fn add_dependency(text: &str, year: u32, day: u32) -> Result<String, AppErr> {
+    let line = format!("aoc-{0}-day{1} = {{ path = \"../{0}/day{1}\" }}", year, day);
+    insert_ordered(text, &line, |l| {
+        let name = l.split(' ').next()?.strip_prefix("aoc-")?;
+        parse_key(name.split_once("-day")?)
+    })
+}
+
+/// Adds the day's `SOLUTION` to the runner's registry.
+pub fn add_registration(text: &str, year: u32, day: u32) -> Result<String, AppErr> {
+    let line = format!("    aoc_{}_day{}::SOLUTION,", year, day);
+    insert_ordered(text, &line, |l| {
+        let name = l.trim().strip_prefix("aoc_")?.strip_suffix("::SOLUTION,")?;
+        parse_key(name.split_once("_day")?)
+    })
+}
+
+fn parse_key((year, day): (&str, &str)) -> Option<(u32, u32)> {
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Inserts `line` among the lines of `text` which have a `key`, keeping them in order.
+fn insert_ordered<F>(text: &str, line: &str, key: F) -> Result<String, AppErr>
+    where F: Fn(&str) -> Option<(u32, u32)>
+{
+    let new_key = key(line).ok_or_else(|| format!("invalid line {:?}", line))?;
+    let mut lines = text.lines().collect::<Vec<_>>();
+
+    let keyed = lines.iter()
+        .enumerate()
+        .filter_map(|(i, l)| key(l).map(|k| (i, k)))
+        .collect::<Vec<_>>();
+    if keyed.iter().any(|&(_, k)| k == new_key) {
+        return Err(format!("{:?} is already present", line.trim()).into());
+    }
+    let index = match keyed.iter().rev().find(|&&(_, k)| k < new_key) {
+        Some(&(i, _)) => i + 1,
+        None => keyed.first().map(|&(i, _)| i).ok_or("found nowhere to add the new day")?,
+    };
+    lines.insert(index, line);
+
+    let mut result = lines.join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod new_day_tests {
+    use super::*;
+
+    #[test]
+    fn adds_workspace_member_in_order() {
+        let text = "members = [\n    \"aoc\",\n    \"2015/day*\",\n    \"2023/day*\",\n]\n";
+        assert_eq!(add_workspace_member(text, 2023).unwrap(), text);
+        assert_eq!(add_workspace_member(text, 2022).unwrap(),
+            "members = [\n    \"aoc\",\n    \"2015/day*\",\n    \"2022/day*\",\n    \"2023/day*\",\n]\n");
+    }
+
+    #[test]
+    fn adds_dependency_in_order() {
+        let text = "[dependencies]\nutil = { path = \"../util\" }\naoc-2023-day2 = { path = \"../2023/day2\" }\naoc-2023-day10 = { path = \"../2023/day10\" }\n";
+        let result = add_dependency(text, 2023, 8).unwrap();
+        assert_eq!(result.lines().nth(3), Some("aoc-2023-day8 = { path = \"../2023/day8\" }"));
+        let result = add_dependency(text, 2015, 1).unwrap();
+        assert_eq!(result.lines().nth(2), Some("aoc-2015-day1 = { path = \"../2015/day1\" }"));
+    }
+
+    #[test]
+    fn adds_registration_in_order() {
+        let text = "const SOLUTIONS: &[Registration] = &[\n    aoc_2023_day7::SOLUTION,\n];\n";
+        assert_eq!(add_registration(text, 2023, 8).unwrap(),
+            "const SOLUTIONS: &[Registration] = &[\n    aoc_2023_day7::SOLUTION,\n    aoc_2023_day8::SOLUTION,\n];\n");
+        assert!(add_registration(text, 2023, 7).is_err());
+        assert!(add_registration("", 2023, 8).is_err());
+    }
+
+    #[test]
+    fn renders_templates() {
+        let lib = render(LIB_TEMPLATE, 2023, 8);
+        assert!(lib.contains("pub struct Day8;"));
+        assert!(lib.contains("Registration { year: 2023, day: 8, solver: &Day8 }"));
+        assert!(!render(MAIN_TEMPLATE, 2023, 8).contains("{{"));
+    }
+}
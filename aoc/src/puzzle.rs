@@ -0,0 +1,229 @@
+// Fetches puzzle descriptions and converts them to markdown, e.g. `2018/day1/PUZZLE.md`.
+
+use std::fs;
+use std::path::Path;
+
+use util::error::{ AppErr, Context };
+
+use crate::client::Client;
+
+/// The heading which starts the description of part 2.
+const PART_TWO: &str = "--- Part Two ---";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Updated {
+    /// Written, with the description of part 2 if `true`.
+    Written { part_two: bool },
+    /// The page has not changed since it was last written, e.g. part 2 is still locked.
+    Unchanged,
+    /// Both parts had already been written, so the page was not fetched.
+    Complete,
+}
+
+/// Writes the puzzle description for `year` and `day` to `path`, unless it already includes part 2.
+pub fn update(client: &Client, year: u32, day: u32, path: &Path) -> Result<Updated, AppErr> {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+    if existing.contains(PART_TWO) {
+        return Ok(Updated::Complete);
+    }
+    let page = client.get_public(&format!("/{}/day/{}", year, day))
+        .with_context(|| format!("failed to fetch the puzzle for {} day {}", year, day))?;
+    let markdown = to_markdown(&page);
+    if markdown.trim().is_empty() {
+        return Err(AppErr::new("Puzzle", &format!("no puzzle description found for {} day {}", year, day)));
+    }
+    if markdown == existing {
+        return Ok(Updated::Unchanged);
+    }
+    fs::write(path, &markdown)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(Updated::Written { part_two: markdown.contains(PART_TWO) })
+}
+
+/// Converts each `<article>` in a puzzle page to markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    for article in articles(html) {
+        convert(article, &mut markdown);
+    }
+    tidy(&markdown)
+}
+
+/// The contents of each `<article>` element.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else { break };
+        let content_start = start + open_end + 1;
+        let Some(length) = rest[content_start..].find("</article>") else { break };
+        articles.push(&rest[content_start..content_start + length]);
+        rest = &rest[content_start + length..];
+    }
+    articles
+}
+
+fn convert(html: &str, out: &mut String) {
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut links = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        push_text(&rest[..start], in_pre, out);
+        let Some(length) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag = &rest[start + 1..start + length];
+        rest = &rest[start + length + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag.trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("")
+            .to_lowercase();
+        match (name.as_str(), closing) {
+            ("h2", false)   => out.push_str("\n\n## "),
+            ("p", _) | ("h2", true) | ("ul", _) => out.push_str("\n\n"),
+            ("li", false)   => out.push_str("\n- "),
+            ("br", _)       => out.push('\n'),
+            ("pre", false)  => {
+                in_pre = true;
+                out.push_str("\n\n```\n");
+            }
+            ("pre", true)   => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => {
+                in_code = !closing;
+                out.push('`');
+            }
+            ("em", _) if !in_pre && !in_code => out.push('*'),
+            ("a", false)    => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            ("a", true)     => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({})", decode(&href)));
+            }
+            _ => {}
+        }
+    }
+    push_text(rest, in_pre, out);
+}
+
+fn push_text(text: &str, in_pre: bool, out: &mut String) {
+    let text = decode(text);
+    if in_pre {
+        out.push_str(&text);
+    }
+    else {
+        out.push_str(&text.replace('\n', " "));
+    }
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+    let length = tag[start..].find('"')?;
+    Some(tag[start..start + length].to_string())
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Collapses runs of blank lines and trailing spaces, ending with a single newline.
+fn tidy(markdown: &str) -> String {
+    let mut result = String::new();
+    let mut blank = true;
+    for line in markdown.lines().map(str::trim_end) {
+        if line.is_empty() {
+            if !blank {
+                result.push('\n');
+            }
+            blank = true;
+            continue;
+        }
+        result.push_str(line);
+        result.push('\n');
+        blank = false;
+    }
+    result.trim_end().to_string() + "\n"
+}
+
+#[cfg(test)]
+mod puzzle_tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Chronal Calibration ---</h2><p>A value like <code>+6</code> means the <em>current</em> frequency increases by <code><em>6</em></code>; see <a href="https://example.com/?a=1&amp;b=2" target="_blank">this</a>.</p>
+<p>For example:</p>
+<pre><code>+1, -2
+-3 &lt; 4
+</code></pre>
+<ul><li>One</li><li>Two</li></ul>
+</article>
+<p>Your puzzle answer was <code>416</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the first frequency<br/>reached twice.</p></article>
+</main></body></html>"#;
+
+    #[test]
+    fn converts_articles() {
+        assert_eq!(to_markdown(PAGE), "\
+## --- Day 1: Chronal Calibration ---
+
+A value like `+6` means the *current* frequency increases by `6`; see [this](https://example.com/?a=1&b=2).
+
+For example:
+
+```
++1, -2
+-3 < 4
+```
+
+- One
+- Two
+
+## --- Part Two ---
+
+Find the first frequency
+reached twice.
+");
+    }
+
+    #[test]
+    fn finds_each_article() {
+        assert_eq!(articles(PAGE).len(), 2);
+        assert_eq!(articles("<html>Please log in</html>").len(), 0);
+    }
+
+    #[test]
+    fn updates_until_part_two() {
+        use crate::client::stand_in;
+
+        let path = std::env::temp_dir().join(format!("aoc-puzzle-test-{}.md", std::process::id()));
+        let part_one = PAGE[..PAGE.find("<p>Your puzzle").unwrap()].to_string();
+        let (base_url, requests) = stand_in::serve(vec![(200, part_one.clone()), (200, part_one), (200, PAGE.to_string())]);
+        let client = Client::new(&base_url, None);
+
+        assert_eq!(update(&client, 2018, 1, &path).unwrap(), Updated::Written { part_two: false });
+        assert_eq!(requests.recv().unwrap().line, "GET /2018/day/1 HTTP/1.1");
+        assert_eq!(update(&client, 2018, 1, &path).unwrap(), Updated::Unchanged);
+        assert_eq!(update(&client, 2018, 1, &path).unwrap(), Updated::Written { part_two: true });
+        assert_eq!(update(&client, 2018, 1, &path).unwrap(), Updated::Complete);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,80 @@
+// Every solution in the repository, ordered by year and day.
+
+use util::solution::{ Registration, Registry };
+
+const SOLUTIONS: &[Registration] = &[
+    aoc_2015_day1::SOLUTION,
+    aoc_2015_day2::SOLUTION,
+    aoc_2015_day3::SOLUTION,
+    aoc_2015_day4::SOLUTION,
+    aoc_2015_day5::SOLUTION,
+    aoc_2015_day6::SOLUTION,
+    aoc_2015_day7::SOLUTION,
+    aoc_2015_day8::SOLUTION,
+    aoc_2015_day9::SOLUTION,
+    aoc_2015_day10::SOLUTION,
+    aoc_2015_day11::SOLUTION,
+    aoc_2015_day12::SOLUTION,
+    aoc_2015_day13::SOLUTION,
+    aoc_2015_day14::SOLUTION,
+    aoc_2015_day15::SOLUTION,
+    aoc_2015_day16::SOLUTION,
+    aoc_2015_day17::SOLUTION,
+    aoc_2015_day18::SOLUTION,
+    aoc_2017_day1::SOLUTION,
+    aoc_2017_day2::SOLUTION,
+    aoc_2017_day3::SOLUTION,
+    aoc_2018_day1::SOLUTION,
+    aoc_2018_day2::SOLUTION,
+    aoc_2018_day3::SOLUTION,
+    aoc_2018_day4::SOLUTION,
+    aoc_2018_day5::SOLUTION,
+    aoc_2018_day6::SOLUTION,
+    aoc_2018_day7::SOLUTION,
+    aoc_2018_day8::SOLUTION,
+    aoc_2018_day9::SOLUTION,
+    aoc_2018_day10::SOLUTION,
+    aoc_2018_day11::SOLUTION,
+    aoc_2018_day12::SOLUTION,
+    aoc_2018_day13::SOLUTION,
+    aoc_2018_day14::SOLUTION,
+    aoc_2020_day1::SOLUTION,
+    aoc_2020_day2::SOLUTION,
+    aoc_2020_day3::SOLUTION,
+    aoc_2020_day4::SOLUTION,
+    aoc_2020_day5::SOLUTION,
+    aoc_2020_day6::SOLUTION,
+    aoc_2020_day7::SOLUTION,
+    aoc_2020_day8::SOLUTION,
+    aoc_2020_day9::SOLUTION,
+    aoc_2020_day10::SOLUTION,
+    aoc_2020_day11::SOLUTION,
+    aoc_2020_day12::SOLUTION,
+    aoc_2020_day13::SOLUTION,
+    aoc_2021_day1::SOLUTION,
+    aoc_2021_day2::SOLUTION,
+    aoc_2021_day3::SOLUTION,
+    aoc_2023_day1::SOLUTION,
+    aoc_2023_day2::SOLUTION,
+    aoc_2023_day3::SOLUTION,
+    aoc_2023_day4::SOLUTION,
+    aoc_2023_day5::SOLUTION,
+    aoc_2023_day6::SOLUTION,
+    aoc_2023_day7::SOLUTION,
+];
+
+/// Other implementations of a day, by name. These are compared with the main implementation by `aoc diff`.
+const ALTERNATIVES: &[(&str, Registration)] = &[
+    ("alt", aoc_2023_day2_alt::SOLUTION),
+];
+
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    for &solution in SOLUTIONS {
+        registry.register(solution);
+    }
+    for &(name, solution) in ALTERNATIVES {
+        registry.register_alternative(name, solution);
+    }
+    registry
+}
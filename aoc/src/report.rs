@@ -0,0 +1,481 @@
+// Machine-readable reports of each day and part which was run, as JSON or JUnit XML.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use sha2::{ Digest, Sha256 };
+use util::answers::{ canonical, Answers, Verdict };
+use util::error::{ AppErr, Context, ErrorKind };
+use util::input::InputFile;
+use util::json::{ self, quote, Value };
+use util::memory::Usage;
+use util::solution::{ solve_with_budget, Answer, Part, Registration };
+
+use util::vault;
+
+/// The outcome of running one day: the answer and time taken for each part.
+pub struct DayRun {
+    pub year: u32,
+    pub day: u32,
+    /// The SHA-256 of the input, if it could be read.
+    pub input_hash: Option<String>,
+    pub parse_time: Duration,
+    /// The memory used by parsing, if allocations are counted.
+    pub parse_memory: Option<Usage>,
+    /// Fails if the input could not be read or parsed.
+    pub parts: Result<Vec<PartRun>, AppErr>,
+}
+
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Result<Answer, AppErr>,
+    pub time: Duration,
+    pub memory: Option<Usage>,
+    /// Whether the answer was found in the answer cache, rather than solved. `time` is then the time it took originally.
+    pub cached: bool,
+}
+
+/// Reads the input at `input_path` and solves each of `parts`, allowing each at most `budget` if given.
+pub fn run_day(registration: &Registration, input_path: &Path, parts: &[Part], budget: Option<Duration>) -> DayRun {
+    match read_input(input_path) {
+        Ok(input) => solve_input(registration, &input, parts, budget)
+            .with_context(|| format!("failed to parse {}", input_path.display())),
+        Err(err) => DayRun::failed(registration, err),
+    }
+}
+
+/// Reads the input at `input_path`, decrypting it if it is encrypted, with the path in any error.
+pub fn read_input(input_path: &Path) -> Result<InputFile, AppErr> {
+    vault::read_input(input_path)
+        .with_context(|| format!("failed to read {}", input_path.display()))
+}
+
+/// Solves each of `parts` of `input`, as for `run_day`.
+pub fn solve_input(registration: &Registration, input: &InputFile, parts: &[Part], budget: Option<Duration>) -> DayRun {
+    let mut run = DayRun::new(registration);
+    run.input_hash = Some(hash(input.as_str()));
+    let solved = match budget {
+        Some(budget) => solve_with_budget(registration, input.as_str().to_string(), parts, budget),
+        None         => registration.solver.solve_timed(input.as_str(), parts),
+    };
+    run.parts = solved.map(|(results, timings)| {
+        run.parse_time = timings.parse;
+        run.parse_memory = timings.parse_memory;
+        results.into_iter()
+            .zip(timings.parts)
+            .zip(timings.part_memory)
+            .map(|(((part, answer), time), memory)| PartRun { part, answer, time, memory, cached: false })
+            .collect()
+    });
+    run
+}
+
+impl DayRun {
+    /// A run of `registration` which has not solved any parts yet.
+    pub fn new(registration: &Registration) -> DayRun {
+        DayRun {
+            year: registration.year,
+            day: registration.day,
+            input_hash: None,
+            parse_time: Duration::ZERO,
+            parse_memory: None,
+            parts: Ok(Vec::new()),
+        }
+    }
+
+    /// A run of `registration` which failed before any part could be solved.
+    pub fn failed(registration: &Registration, err: AppErr) -> DayRun {
+        DayRun { parts: Err(err), ..DayRun::new(registration) }
+    }
+
+    /// Adds `context` to the error, if the run failed.
+    pub fn with_context<F>(mut self, context: F) -> DayRun
+        where F: FnOnce() -> String
+    {
+        self.parts = self.parts.with_context(context);
+        self
+    }
+}
+
+/// The SHA-256 of `input`, in hex.
+pub fn hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// Solved, without checking the answer.
+    Solved,
+    Pass,
+    Fail,
+    /// Solved, but no answer has been recorded to check against.
+    Missing,
+    Error,
+    /// Ran out of time before giving an answer.
+    Timeout,
+}
+
+impl Status {
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Solved  => "solved",
+            Status::Pass    => "pass",
+            Status::Fail    => "fail",
+            Status::Missing => "missing",
+            Status::Error   => "error",
+            Status::Timeout => "timeout",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Status> {
+        [Status::Solved, Status::Pass, Status::Fail, Status::Missing, Status::Error, Status::Timeout]
+            .into_iter()
+            .find(|status| status.name() == name)
+    }
+}
+
+/// One part of one day in a report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub error: Option<String>,
+    pub parse_time: Duration,
+    /// The time taken to solve the part, not including parsing.
+    pub time: Duration,
+    /// The memory used by parsing and by the part, if allocations are counted.
+    pub parse_memory: Option<Usage>,
+    pub memory: Option<Usage>,
+    pub input_hash: Option<String>,
+    /// Whether the answer came from the answer cache.
+    pub cached: bool,
+}
+
+impl Entry {
+    /// An entry for each part of `run`. If the input could not be read or parsed, each of `parts` is an error.
+    pub fn from_run(run: &DayRun, parts: &[Part]) -> Vec<Entry> {
+        let entry = |part, status| Entry {
+            year: run.year,
+            day: run.day,
+            part,
+            status,
+            answer: None,
+            expected: None,
+            error: None,
+            parse_time: run.parse_time,
+            time: Duration::ZERO,
+            parse_memory: run.parse_memory,
+            memory: None,
+            input_hash: run.input_hash.clone(),
+            cached: false,
+        };
+        match run.parts {
+            Ok(ref results) => results.iter()
+                .map(|result| match result.answer {
+                    Ok(ref answer) => Entry { answer: Some(canonical(answer)), time: result.time, memory: result.memory, cached: result.cached, ..entry(result.part, Status::Solved) },
+                    Err(ref err)   => Entry { error: Some(error_text(err)), time: result.time, memory: result.memory, ..entry(result.part, error_status(err)) },
+                })
+                .collect(),
+            Err(ref err) => parts.iter()
+                .map(|&part| Entry { error: Some(error_text(err)), ..entry(part, error_status(err)) })
+                .collect(),
+        }
+    }
+
+    /// Checks a solved entry against the recorded answers.
+    pub fn check(&mut self, answers: &Answers) {
+        let answer = match (self.status, &self.answer) {
+            (Status::Solved, Some(answer)) => Answer::Str(answer.clone()),
+            _ => return,
+        };
+        self.expected = answers.get(self.year, self.day, self.part).map(String::from);
+        self.status = match answers.check(self.year, self.day, self.part, &answer) {
+            Verdict::Pass        => Status::Pass,
+            Verdict::Fail { .. } => Status::Fail,
+            Verdict::Missing     => Status::Missing,
+        };
+    }
+}
+
+/// Parts which ran out of time are reported apart from those which failed.
+fn error_status(err: &AppErr) -> Status {
+    match err.kind() {
+        ErrorKind::Timeout => Status::Timeout,
+        _                  => Status::Error,
+    }
+}
+
+/// The error and each of its causes on one line, without the backtrace.
+fn error_text(err: &AppErr) -> String {
+    err.chain()
+        .map(|cause| cause.to_string())
+        .collect::<Vec<_>>()
+        .join(": ")
+}
+
+/// Formats `entries` as a JSON object, with one entry per line.
+pub fn to_json(entries: &[Entry]) -> String {
+    fn optional(value: &Option<String>) -> String {
+        value.as_deref().map_or("null".to_string(), quote)
+    }
+    fn memory(usage: &Option<Usage>) -> String {
+        usage.map_or("null".to_string(), |usage| {
+            format!("{{\"allocations\":{},\"bytes\":{},\"peak\":{}}}", usage.allocations, usage.bytes, usage.peak)
+        })
+    }
+
+    let mut text = String::from("{\"entries\":[");
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 {
+            text.push(',');
+        }
+        write!(text,
+            "\n{{\"year\":{},\"day\":{},\"part\":{},\"status\":{},\"answer\":{},\"expected\":{},\"error\":{},\"parse_ns\":{},\"time_ns\":{},\"parse_memory\":{},\"memory\":{},\"input_hash\":{},\"cached\":{}}}",
+            entry.year,
+            entry.day,
+            entry.part.number(),
+            quote(entry.status.name()),
+            optional(&entry.answer),
+            optional(&entry.expected),
+            optional(&entry.error),
+            entry.parse_time.as_nanos(),
+            entry.time.as_nanos(),
+            memory(&entry.parse_memory),
+            memory(&entry.memory),
+            optional(&entry.input_hash),
+            entry.cached).unwrap();
+    }
+    text.push_str("\n]}\n");
+    text
+}
+
+/// Reads the entries of a report written by `to_json`.
+pub fn from_json(text: &str) -> Result<Vec<Entry>, AppErr> {
+    fn invalid(message: &str) -> AppErr {
+        AppErr::new("Report", message).with_kind(ErrorKind::Parse)
+    }
+    fn field<'a>(entry: &'a Value, key: &str) -> Result<&'a Value, AppErr> {
+        entry.get(key).ok_or_else(|| invalid(&format!("missing `{}`", key)))
+    }
+    fn number(entry: &Value, key: &str) -> Result<u64, AppErr> {
+        field(entry, key)?.as_u64().ok_or_else(|| invalid(&format!("`{}` is not a number", key)))
+    }
+    fn optional(entry: &Value, key: &str) -> Result<Option<String>, AppErr> {
+        match field(entry, key)? {
+            Value::Null => Ok(None),
+            value => value.as_str().map(|s| Some(s.to_string())).ok_or_else(|| invalid(&format!("`{}` is not a string", key))),
+        }
+    }
+    fn memory(entry: &Value, key: &str) -> Result<Option<Usage>, AppErr> {
+        match field(entry, key)? {
+            Value::Null => Ok(None),
+            usage => Ok(Some(Usage {
+                allocations: number(usage, "allocations")? as usize,
+                bytes: number(usage, "bytes")? as usize,
+                peak: number(usage, "peak")? as usize,
+            })),
+        }
+    }
+
+    let document = json::parse(text)?;
+    let entries = document.get("entries").and_then(Value::as_array)
+        .ok_or_else(|| invalid("missing `entries`"))?;
+    entries.iter()
+        .map(|entry| {
+            let part = number(entry, "part")?;
+            let status = field(entry, "status")?.as_str().unwrap_or("");
+            Ok(Entry {
+                year: number(entry, "year")? as u32,
+                day: number(entry, "day")? as u32,
+                part: Part::from_number(part as u32).ok_or_else(|| invalid(&format!("invalid part {}", part)))?,
+                status: Status::from_name(status).ok_or_else(|| invalid(&format!("invalid status `{}`", status)))?,
+                answer: optional(entry, "answer")?,
+                expected: optional(entry, "expected")?,
+                error: optional(entry, "error")?,
+                parse_time: Duration::from_nanos(number(entry, "parse_ns")?),
+                time: Duration::from_nanos(number(entry, "time_ns")?),
+                parse_memory: memory(entry, "parse_memory")?,
+                memory: memory(entry, "memory")?,
+                input_hash: optional(entry, "input_hash")?,
+                cached: match field(entry, "cached")? {
+                    &Value::Bool(cached) => cached,
+                    _ => return Err(invalid("`cached` is not a boolean")),
+                },
+            })
+        })
+        .collect()
+}
+
+/// Reads a JSON report written by `aoc run` or `aoc verify`.
+pub fn read(path: &Path) -> Result<Vec<Entry>, AppErr> {
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("failed to read report {}", path.display()))?;
+    from_json(&text)
+        .with_context(|| format!("failed to parse report {}", path.display()))
+}
+
+/// Formats `entries` as JUnit XML, with a test suite for each year and a test case for each part.
+/// Missing answers are reported as skipped.
+pub fn to_junit(entries: &[Entry]) -> String {
+    let mut text = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"aoc\">\n");
+    for year_entries in entries.chunk_by(|a, b| a.year == b.year) {
+        let count = |status| year_entries.iter().filter(|e| e.status == status).count();
+        let time = year_entries.iter().map(|e| e.time).sum::<Duration>();
+        writeln!(text, "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+            year_entries[0].year, year_entries.len(), count(Status::Fail), count(Status::Error) + count(Status::Timeout), count(Status::Missing), time.as_secs_f64()).unwrap();
+
+        for entry in year_entries {
+            write!(text, "    <testcase classname=\"{}.day{}\" name=\"{}\" time=\"{:.6}\"",
+                entry.year, entry.day, entry.part, entry.time.as_secs_f64()).unwrap();
+            let answer = entry.answer.as_deref().unwrap_or("");
+            let expected = entry.expected.as_deref().unwrap_or("");
+            match entry.status {
+                Status::Solved | Status::Pass => text.push_str("/>\n"),
+                Status::Fail => writeln!(text, ">\n      <failure message=\"{}\"/>\n    </testcase>",
+                    escape_xml(&format!("expected {:?}, got {:?}", expected, answer))).unwrap(),
+                Status::Missing => writeln!(text, ">\n      <skipped message=\"{}\"/>\n    </testcase>",
+                    escape_xml(&format!("no recorded answer (got {:?})", answer))).unwrap(),
+                Status::Error | Status::Timeout => writeln!(text, ">\n      <error message=\"{}\"/>\n    </testcase>",
+                    escape_xml(entry.error.as_deref().unwrap_or(""))).unwrap(),
+            }
+        }
+        text.push_str("  </testsuite>\n");
+    }
+    text.push_str("</testsuites>\n");
+    text
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&'  => out.push_str("&amp;"),
+            '<'  => out.push_str("&lt;"),
+            '>'  => out.push_str("&gt;"),
+            '"'  => out.push_str("&quot;"),
+            '\n' => out.push_str("&#10;"),
+            c    => out.push(c),
+        }
+    }
+    out
+}
+
+pub fn write(path: &Path, text: &str) -> Result<(), AppErr> {
+    fs::write(path, text)
+        .with_context(|| format!("failed to write report {}", path.display()))
+}
+
+#[cfg(test)]
+mod report_tests {
+    use super::*;
+
+    fn entry(day: u32, status: Status) -> Entry {
+        Entry {
+            year: 2020,
+            day,
+            part: Part::One,
+            status,
+            answer: Some("<42>".to_string()),
+            expected: Some("41".to_string()),
+            error: None,
+            parse_time: Duration::from_micros(5),
+            time: Duration::from_micros(10),
+            parse_memory: None,
+            memory: None,
+            input_hash: None,
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn hashes_input() {
+        assert_eq!(hash(""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    }
+
+    #[test]
+    fn entries_from_run() {
+        let run = DayRun {
+            year: 2020,
+            day: 8,
+            input_hash: Some("abc".to_string()),
+            parse_time: Duration::from_micros(1),
+            parse_memory: Some(Usage { allocations: 1, bytes: 2, peak: 2 }),
+            parts: Ok(vec![
+                PartRun { part: Part::One, answer: Ok(Answer::from(5)), time: Duration::from_micros(2), memory: None, cached: false },
+                PartRun { part: Part::Two, answer: Err(AppErr::no_solution("none")), time: Duration::from_micros(3), memory: None, cached: false },
+            ]),
+        };
+        let entries = Entry::from_run(&run, &Part::ALL);
+        assert_eq!(entries[0].status, Status::Solved);
+        assert_eq!(entries[0].answer.as_deref(), Some("5"));
+        assert_eq!(entries[0].parse_memory.map(|m| m.allocations), Some(1));
+        assert_eq!(entries[1].status, Status::Error);
+        assert_eq!(entries[1].error.as_deref(), Some("[NoSolution] none"));
+
+        let timed_out = DayRun { parts: Err(AppErr::timeout("too slow").context("failed to parse")), ..run };
+        let entries = Entry::from_run(&timed_out, &[Part::One]);
+        assert_eq!(entries[0].status, Status::Timeout);
+        assert_eq!(Status::from_name("timeout"), Some(Status::Timeout));
+
+        let failed = DayRun { parts: Err(AppErr::new("Io", "missing").context("failed to read")), ..timed_out };
+        let entries = Entry::from_run(&failed, &Part::ALL);
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|e| e.status == Status::Error));
+        assert_eq!(entries[1].error.as_deref(), Some("failed to read: [Io] missing"));
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers::parse("2020 1 1 <42>\n2020 2 1 7\n").unwrap();
+        let mut entries = [entry(1, Status::Solved), entry(2, Status::Solved), entry(3, Status::Solved), entry(4, Status::Error)];
+        for entry in &mut entries {
+            entry.check(&answers);
+        }
+        let statuses = entries.iter().map(|e| e.status).collect::<Vec<_>>();
+        assert_eq!(statuses, [Status::Pass, Status::Fail, Status::Missing, Status::Error]);
+        assert_eq!(entries[1].expected.as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn json_report() {
+        let json = to_json(&[entry(1, Status::Pass)]);
+        assert_eq!(json, concat!(
+            "{\"entries\":[\n",
+            "{\"year\":2020,\"day\":1,\"part\":1,\"status\":\"pass\",\"answer\":\"<42>\",\"expected\":\"41\",",
+            "\"error\":null,\"parse_ns\":5000,\"time_ns\":10000,\"parse_memory\":null,\"memory\":null,\"input_hash\":null,\"cached\":false}\n",
+            "]}\n"));
+    }
+
+    #[test]
+    fn json_report_round_trips() {
+        let entries = [
+            entry(1, Status::Pass),
+            Entry { error: Some("a \"b\"".to_string()), input_hash: Some("abc".to_string()), ..entry(2, Status::Error) },
+            Entry { memory: Some(Usage { allocations: 3, bytes: 100, peak: 60 }), cached: true, ..entry(3, Status::Solved) },
+        ];
+        assert_eq!(from_json(&to_json(&entries)).unwrap(), entries);
+        assert_eq!(from_json("{\"entries\":[{\"year\":2020}]}").unwrap_err().kind(), ErrorKind::Parse);
+        // Every field is required
+        let json = to_json(&entries[..1]);
+        assert!(from_json(&json.replace(",\"cached\":false", "")).unwrap_err().to_string().contains("missing `cached`"));
+        assert!(from_json(&json.replace(",\"memory\":null", "")).unwrap_err().to_string().contains("missing `memory`"));
+    }
+
+    #[test]
+    fn junit_report() {
+        let xml = to_junit(&[entry(1, Status::Pass), entry(2, Status::Fail), entry(3, Status::Missing)]);
+        assert!(xml.contains("<testsuite name=\"2020\" tests=\"3\" failures=\"1\" errors=\"0\" skipped=\"1\""));
+        assert!(xml.contains("<testcase classname=\"2020.day1\" name=\"Part 1\" time=\"0.000010\"/>"));
+        assert!(xml.contains("<failure message=\"expected &quot;41&quot;, got &quot;&lt;42&gt;&quot;\"/>"));
+        assert!(xml.contains("<skipped"));
+    }
+}
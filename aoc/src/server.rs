@@ -0,0 +1,426 @@
+// Serves the solutions over HTTP, so that other tools can solve inputs without running the runner.
+
+use std::io::{ BufRead, BufReader, Read, Write };
+use std::net::{ TcpListener, TcpStream };
+use std::path::Path;
+use std::sync::{ mpsc, Arc, Mutex };
+use std::thread;
+use std::time::Duration;
+
+use util::error::{ AppErr, Context };
+use util::input::InputFile;
+use util::json::quote;
+use util::params::{ self, check_overrides, parse_override };
+use util::solution::{ self, Part, Registry, MAIN_IMPLEMENTATION };
+
+use crate::report::{ self, Entry };
+
+/// The largest input accepted, well above any puzzle input.
+const MAX_BODY: usize = 16 * 1024 * 1024;
+
+/// The longest request line or header accepted.
+const MAX_LINE: usize = 8 * 1024;
+
+/// The most headers accepted in a request.
+const MAX_HEADERS: usize = 100;
+
+/// How long to wait for a client to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    /// The decoded query parameters, in order.
+    pub query: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    /// Always JSON.
+    pub body: String,
+}
+
+impl Response {
+    fn ok(body: String) -> Response {
+        Response { status: 200, body }
+    }
+
+    /// An error as JSON, as formatted by `AppErr::to_json`.
+    fn error(status: u16, err: &AppErr) -> Response {
+        Response { status, body: format!("{}\n", err.to_json()) }
+    }
+}
+
+/// Answers a request:
+///
+/// - `POST /<year>/<day>` solves the input in the body, returning a report of each part as written by `aoc run --report`.
+///   The query may select one part with `part=<1|2>`, an implementation with `impl=<name>`,
+///   and parameter values with `param=<name>=<value>`.
+/// - `GET /days` lists each registered day with its implementations and parameters.
+///
+/// A solve is refused with 503 while `jobs` solver threads are still running,
+/// as a solver which ran out of time but does not check its cancellation token keeps running after it is abandoned.
+pub fn handle(registry: &Registry, request: &Request, budget: Duration, jobs: usize) -> Response {
+    let segments = request.path.trim_matches('/').split('/').collect::<Vec<_>>();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["days"]) => Response::ok(days_json(registry)),
+        ("POST", [_, _]) if solution::running_solvers() >= jobs => {
+            Response::error(503, &AppErr::new("Busy", &format!("{} solvers are still running, some of which may have run out of time: try again later", jobs)))
+        }
+        ("POST", [year, day]) => match (year.parse(), day.parse()) {
+            (Ok(year), Ok(day)) => solve(registry, year, day, request, budget),
+            _ => not_found(request),
+        },
+        (_, ["days"]) | (_, [_, _]) => Response::error(405, &AppErr::usage(&format!("{} is not allowed on {}", request.method, request.path))),
+        _ => not_found(request),
+    }
+}
+
+fn not_found(request: &Request) -> Response {
+    Response::error(404, &AppErr::usage(&format!("no such path {}: expected POST /<year>/<day> or GET /days", request.path)))
+}
+
+fn solve(registry: &Registry, year: u32, day: u32, request: &Request, budget: Duration) -> Response {
+    let mut parts = Part::ALL.to_vec();
+    let mut implementation = MAIN_IMPLEMENTATION.to_string();
+    let mut overrides = Vec::new();
+    for (name, value) in &request.query {
+        let parsed = match name.as_str() {
+            "part" => value.parse().ok()
+                .and_then(Part::from_number)
+                .map(|part| parts = vec![part])
+                .ok_or_else(|| AppErr::usage(&format!("invalid part `{}`", value))),
+            "impl" => {
+                implementation = value.clone();
+                Ok(())
+            }
+            "param" => parse_override(value).map(|param| overrides.push(param)),
+            _ => Err(AppErr::usage(&format!("unknown query parameter `{}`", name))),
+        };
+        if let Err(err) = parsed {
+            return Response::error(400, &err);
+        }
+    }
+
+    let registration = match registry.find_implementation(year, day, &implementation) {
+        Some(registration) => registration,
+        None => return Response::error(404, &AppErr::usage(&format!("no implementation `{}` of {} day {}", implementation, year, day))),
+    };
+    if let Err(err) = check_overrides(registration.solver.params(), &overrides) {
+        return Response::error(400, &err);
+    }
+    let input = match String::from_utf8(request.body.clone()) {
+        Ok(input) => input,
+        Err(_) => return Response::error(400, &AppErr::usage("the input is not UTF-8 text")),
+    };
+
+    let input = InputFile::from_string(Path::new(&request.path), input);
+    let run = params::with_overrides(&overrides, || report::solve_input(registration, &input, &parts, Some(budget)));
+    Response::ok(report::to_json(&Entry::from_run(&run, &parts)))
+}
+
+/// Each registered day, e.g. `{"days":[{"year":2018,"day":7,"implementations":["main"],"params":[{"name":"workers","default":"5","description":"..."}]}]}`.
+fn days_json(registry: &Registry) -> String {
+    let days = registry.iter()
+        .map(|registration| {
+            let implementations = registry.implementations(registration.year, registration.day).iter()
+                .map(|&(name, _)| quote(name))
+                .collect::<Vec<_>>();
+            let params = registration.solver.params().iter()
+                .map(|p| format!("{{\"name\":{},\"default\":{},\"description\":{}}}", quote(p.name), quote(p.default), quote(p.description)))
+                .collect::<Vec<_>>();
+            format!("\n{{\"year\":{},\"day\":{},\"implementations\":[{}],\"params\":[{}]}}",
+                registration.year,
+                registration.day,
+                implementations.join(","),
+                params.join(","))
+        })
+        .collect::<Vec<_>>();
+    format!("{{\"days\":[{}\n]}}\n", days.join(","))
+}
+
+/// Reads one request from `reader`. The body must have a `Content-Length`.
+/// Fails with the status to respond with.
+pub fn read_request(reader: &mut impl BufRead) -> Result<Request, (u16, AppErr)> {
+    let bad_request = |message: &str| (400, AppErr::usage(message));
+
+    let line = read_line(reader, 414)?;
+    let (method, target) = match line.split_whitespace().collect::<Vec<_>>().as_slice() {
+        [method, target, version] if version.starts_with("HTTP/1.") => (method.to_string(), target.to_string()),
+        _ => return Err(bad_request(&format!("invalid request line {:?}", line.trim_end()))),
+    };
+
+    let mut length = 0;
+    for count in 0.. {
+        let header = read_line(reader, 431)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if count == MAX_HEADERS {
+            return Err((431, AppErr::usage(&format!("the request has more than {} headers", MAX_HEADERS))));
+        }
+        let (name, value) = header.split_once(':')
+            .ok_or_else(|| bad_request(&format!("invalid header {:?}", header)))?;
+        if name.eq_ignore_ascii_case("Content-Length") {
+            length = value.trim().parse().map_err(|_| bad_request(&format!("invalid Content-Length {:?}", value.trim())))?;
+        }
+        else if name.eq_ignore_ascii_case("Transfer-Encoding") {
+            return Err((411, AppErr::usage("send the input with a Content-Length, rather than chunked")));
+        }
+    }
+    if length > MAX_BODY {
+        return Err((413, AppErr::usage(&format!("the input is larger than {} bytes", MAX_BODY))));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).map_err(|err| (400, AppErr::from(err).context("failed to read the body")))?;
+
+    let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+    let query = query.split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode(name), decode(value))
+        })
+        .collect();
+    Ok(Request { method, path: decode(path), query, body })
+}
+
+/// Reads one line of the request line or headers, failing with `status` if it is longer than `MAX_LINE`.
+fn read_line(reader: &mut impl BufRead, status: u16) -> Result<String, (u16, AppErr)> {
+    let mut line = String::new();
+    reader.by_ref().take(MAX_LINE as u64).read_line(&mut line).map_err(|err| (400, AppErr::from(err)))?;
+    if line.len() == MAX_LINE && !line.ends_with('\n') {
+        return Err((status, AppErr::usage(&format!("a line of the request is longer than {} bytes", MAX_LINE))));
+    }
+    Ok(line)
+}
+
+/// Decodes `%xx` escapes, and `+` as a space.
+fn decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes.get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match (bytes[i], escaped) {
+            (_, Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (b'+', _) => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        414 => "URI Too Long",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        _   => "Error",
+    }
+}
+
+fn respond(stream: &mut TcpStream, response: &Response) -> std::io::Result<()> {
+    write!(stream, "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        response.body.len(),
+        response.body)?;
+    stream.flush()
+}
+
+/// Reads one request from `stream` and answers it.
+fn serve_connection(registry: &Registry, stream: TcpStream, budget: Duration, jobs: usize) -> Result<(), AppErr> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream);
+    let response = match read_request(&mut reader) {
+        Ok(request) => {
+            let response = handle(registry, &request, budget, jobs);
+            println!("{} {} {}", request.method, request.path, response.status);
+            response
+        }
+        Err((status, err)) => Response::error(status, &err),
+    };
+    respond(reader.get_mut(), &response)?;
+    Ok(())
+}
+
+/// Answers requests on `listener` until it fails, `jobs` at a time, each on one of `jobs` worker threads.
+/// Further connections wait to be accepted until a worker is free.
+/// Each part is allowed at most `budget`, and solves are refused while `jobs` solver threads are running, as for `handle`.
+pub fn serve(listener: TcpListener, registry: Registry, budget: Duration, jobs: usize) -> Result<(), AppErr> {
+    let registry = Arc::new(registry);
+    let (sender, receiver) = mpsc::sync_channel::<TcpStream>(0);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for worker in 0..jobs {
+        let registry = Arc::clone(&registry);
+        let receiver = Arc::clone(&receiver);
+        thread::Builder::new()
+            .name(format!("server worker {}", worker))
+            .spawn(move || loop {
+                // The lock is released before the connection is served, so other workers can take the next
+                let stream = receiver.lock().unwrap_or_else(|err| err.into_inner()).recv();
+                let Ok(stream) = stream else { return };
+                if let Err(err) = serve_connection(&registry, stream, budget, jobs) {
+                    eprintln!("{}", err);
+                }
+            })
+            .context("failed to start a server worker")?;
+    }
+    for stream in listener.incoming() {
+        let stream = stream.context("failed to accept a connection")?;
+        sender.send(stream).map_err(|_| AppErr::new("Server", "every server worker has stopped"))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod server_tests {
+    use util::json::{ self, Value };
+
+    use super::*;
+    use crate::registry;
+
+    const BUDGET: Duration = Duration::from_secs(60);
+    const JOBS: usize = 1000;
+
+    fn request(method: &str, target: &str, body: &str) -> Request {
+        let text = format!("{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}", method, target, body.len(), body);
+        read_request(&mut text.as_bytes()).unwrap()
+    }
+
+    fn entries(response: &Response) -> Vec<Entry> {
+        assert_eq!(response.status, 200, "{}", response.body);
+        report::from_json(&response.body).unwrap()
+    }
+
+    #[test]
+    fn reads_requests() {
+        let request = request("POST", "/2018/7?part=2&param=workers%3D2&param=base_duration=0", "Step C must be finished");
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/2018/7");
+        assert_eq!(request.query, [
+            ("part".to_string(), "2".to_string()),
+            ("param".to_string(), "workers=2".to_string()),
+            ("param".to_string(), "base_duration=0".to_string()),
+        ]);
+        assert_eq!(request.body, b"Step C must be finished");
+
+        let invalid = |text: &str| read_request(&mut text.as_bytes()).unwrap_err().0;
+        assert_eq!(invalid("POST /2020/8\r\n\r\n"), 400);
+        assert_eq!(invalid("POST /2020/8 HTTP/1.1\r\nContent-Length: x\r\n\r\n"), 400);
+        assert_eq!(invalid("POST /2020/8 HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n"), 411);
+        assert_eq!(invalid("POST /2020/8 HTTP/1.1\r\nContent-Length: 999999999\r\n\r\n"), 413);
+        assert_eq!(invalid("POST /2020/8 HTTP/1.1\r\nContent-Length: 10\r\n\r\nshort"), 400);
+        assert_eq!(invalid(&format!("POST /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE))), 414);
+        assert_eq!(invalid(&format!("POST /2020/8 HTTP/1.1\r\nX: {}\r\n\r\n", "a".repeat(MAX_LINE))), 431);
+        assert_eq!(invalid(&format!("POST /2020/8 HTTP/1.1\r\n{}\r\n", "X: a\r\n".repeat(MAX_HEADERS + 1))), 431);
+        assert!(read_request(&mut format!("POST /2020/8 HTTP/1.1\r\n{}\r\n", "X: a\r\n".repeat(MAX_HEADERS)).as_bytes()).is_ok());
+        assert_eq!(decode("a+b%20c%2"), "a b c%2");
+    }
+
+    #[test]
+    fn solves_inputs() {
+        let registry = registry::registry();
+        let input = "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n";
+        let response = handle(&registry, &request("POST", "/2020/8", input), BUDGET, JOBS);
+        let entries = entries(&response);
+        assert_eq!(entries.iter().map(|e| (e.part, e.answer.as_deref())).collect::<Vec<_>>(), [(Part::One, Some("5")), (Part::Two, Some("8"))]);
+        assert_eq!(entries[0].input_hash.as_deref(), Some(report::hash(input).as_str()));
+
+        let entries = self::entries(&handle(&registry, &request("POST", "/2020/8?part=2", input), BUDGET, JOBS));
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].part, Part::Two);
+    }
+
+    #[test]
+    fn solves_with_params() {
+        let registry = registry::registry();
+        let input = "Step C must be finished before step A can begin.\nStep C must be finished before step F can begin.\n";
+        let target = "/2018/7?param=workers=2&param=base_duration=0";
+        let entries = entries(&handle(&registry, &request("POST", target, input), BUDGET, JOBS));
+        assert_eq!(entries[0].answer.as_deref(), Some("CAF"));
+        assert_eq!(entries[1].answer.as_deref(), Some("9"));
+    }
+
+    #[test]
+    fn reports_errors() {
+        let registry = registry::registry();
+        let entries = entries(&handle(&registry, &request("POST", "/2020/8", "bogus\n"), BUDGET, JOBS));
+        assert!(entries.iter().all(|e| e.status == report::Status::Error && e.error.is_some()));
+
+        let status = |method: &str, target: &str| handle(&registry, &request(method, target, "1\n"), BUDGET, JOBS).status;
+        assert_eq!(status("POST", "/2020/30"), 404);
+        assert_eq!(status("POST", "/2020/8?impl=missing"), 404);
+        assert_eq!(status("POST", "/2020/8?part=3"), 400);
+        assert_eq!(status("POST", "/2020/8?param=x=1"), 400);
+        assert_eq!(status("POST", "/2020/8?colour=red"), 400);
+        assert_eq!(status("GET", "/2020/8"), 405);
+        assert_eq!(status("GET", "/"), 404);
+
+        let response = handle(&registry, &request("POST", "/2020/30", ""), BUDGET, JOBS);
+        let error = json::parse(&response.body).unwrap();
+        assert_eq!(error.get("kind").and_then(Value::as_str), Some("usage"));
+    }
+
+    #[test]
+    fn refuses_solves_while_busy() {
+        let registry = registry::registry();
+        let response = handle(&registry, &request("POST", "/2020/8", "nop +0\n"), BUDGET, 0);
+        assert_eq!(response.status, 503);
+        assert!(response.body.contains("solvers are still running"));
+        assert_eq!(handle(&registry, &request("GET", "/days", ""), BUDGET, 0).status, 200);
+    }
+
+    #[test]
+    fn lists_days() {
+        let response = handle(&registry::registry(), &request("GET", "/days", ""), BUDGET, JOBS);
+        assert_eq!(response.status, 200);
+        let days = json::parse(&response.body).unwrap();
+        let days = days.get("days").and_then(Value::as_array).unwrap();
+        let day7 = days.iter()
+            .find(|d| d.get("year").and_then(Value::as_u64) == Some(2018) && d.get("day").and_then(Value::as_u64) == Some(7))
+            .unwrap();
+        let params = day7.get("params").and_then(Value::as_array).unwrap();
+        assert_eq!(params[0].get("name").and_then(Value::as_str), Some("workers"));
+        assert!(days.iter().any(|d| d.get("implementations").and_then(Value::as_array).is_some_and(|i| i.len() == 2)));
+    }
+
+    #[test]
+    fn serves_over_http() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        thread::spawn(move || serve(listener, registry::registry(), BUDGET, 2));
+
+        let body = ureq::post(&format!("{}/2015/1", base_url)).send_string("()())").unwrap().into_string().unwrap();
+        let entries = report::from_json(&body).unwrap();
+        assert_eq!(entries[0].answer.as_deref(), Some("-1"));
+        assert_eq!(entries[1].answer.as_deref(), Some("5"));
+
+        match ureq::get(&format!("{}/2015/99", base_url)).call() {
+            Err(ureq::Error::Status(status, _)) => assert_eq!(status, 405),
+            other => panic!("expected an error status, got {:?}", other.map(|r| r.status())),
+        }
+    }
+}
@@ -0,0 +1,323 @@
+// Submits answers, keeping a log of every attempt so that known-wrong answers and cooldowns are respected.
+
+use std::fmt;
+use std::fs::{ self, OpenOptions };
+use std::io::Write;
+use std::path::Path;
+
+use util::error::{ AppErr, Context, ErrorKind };
+use util::input::lines;
+use util::solution::Part;
+
+use crate::client::Client;
+
+/// The website's verdict on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint.
+    Wrong,
+    /// Submitted too soon after the previous attempt. The answer was not checked.
+    Wait,
+    /// This part has already been solved.
+    AlreadySolved,
+    /// The response could not be understood.
+    Unknown,
+}
+
+impl Outcome {
+    const ALL: [Outcome; 7] = [
+        Outcome::Correct, Outcome::TooHigh, Outcome::TooLow, Outcome::Wrong,
+        Outcome::Wait, Outcome::AlreadySolved, Outcome::Unknown,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Outcome::Correct       => "correct",
+            Outcome::TooHigh       => "too-high",
+            Outcome::TooLow        => "too-low",
+            Outcome::Wrong         => "wrong",
+            Outcome::Wait          => "wait",
+            Outcome::AlreadySolved => "already-solved",
+            Outcome::Unknown       => "unknown",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Outcome> {
+        Outcome::ALL.iter().copied().find(|o| o.name() == name)
+    }
+
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Reads the outcome, and how many seconds to wait before the next attempt, from the response page.
+pub fn parse_response(html: &str) -> (Outcome, u64) {
+    let text = html.to_lowercase();
+    if text.contains("that's the right answer") {
+        (Outcome::Correct, 0)
+    }
+    else if text.contains("you gave an answer too recently") {
+        (Outcome::Wait, wait_seconds(&text).unwrap_or(60))
+    }
+    else if text.contains("that's not the right answer") {
+        let outcome = if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        }
+        else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        }
+        else {
+            Outcome::Wrong
+        };
+        (outcome, wait_seconds(&text).unwrap_or(60))
+    }
+    else if text.contains("you don't seem to be solving the right level") {
+        (Outcome::AlreadySolved, 0)
+    }
+    else {
+        (Outcome::Unknown, 0)
+    }
+}
+
+/// Finds e.g. "you have 1m 5s left to wait" or "please wait one minute before trying again".
+fn wait_seconds(text: &str) -> Option<u64> {
+    if let Some(start) = text.find("you have ") {
+        let rest = &text[start + "you have ".len()..];
+        let end = rest.find(" left to wait")?;
+        let mut seconds = 0;
+        for token in rest[..end].split_whitespace() {
+            let (number, unit_seconds) = [("h", 3600), ("m", 60), ("s", 1)].iter()
+                .find_map(|&(unit, unit_seconds)| Some((token.strip_suffix(unit)?, unit_seconds)))?;
+            seconds += number.parse::<u64>().ok()? * unit_seconds;
+        }
+        return Some(seconds);
+    }
+
+    const NUMBERS: [&str; 10] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten"];
+    let start = text.find("please wait ")?;
+    let number = text[start + "please wait ".len()..].split_whitespace().next()?;
+    let minutes = match NUMBERS.iter().position(|&n| n == number) {
+        Some(i) => i as u64 + 1,
+        None    => number.parse().ok()?,
+    };
+    Some(minutes * 60)
+}
+
+/// A submitted answer, and the response to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub outcome: Outcome,
+    /// The number of seconds to wait before the next attempt.
+    pub wait: u64,
+    pub answer: String,
+}
+
+/// Every attempt to submit an answer, oldest first.
+///
+/// The file format has one attempt per line: `<time> <year> <day> <part> <outcome> <wait> <answer>`.
+#[derive(Debug, Default)]
+pub struct AttemptLog {
+    attempts: Vec<Attempt>,
+}
+
+impl AttemptLog {
+    /// Reads the log at `path`. A missing file is an empty log.
+    pub fn read(path: &Path) -> Result<AttemptLog, AppErr> {
+        if !path.exists() {
+            return Ok(AttemptLog::default());
+        }
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        AttemptLog::parse(&text)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<AttemptLog, AppErr> {
+        let attempts = lines(text)
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(number, line)| {
+                parse_attempt(line)
+                    .map_err(|err| err.with_kind(ErrorKind::Parse))
+                    .with_context(|| format!("failed to parse line {}: {:?}", number, line))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(AttemptLog { attempts })
+    }
+
+    /// Fails if `answer` should not be submitted at `now`: the part is already solved,
+    /// the answer is known to be wrong, or the last attempt's cooldown has not passed.
+    pub fn check(&self, year: u32, day: u32, part: Part, answer: &str, now: u64) -> Result<(), AppErr> {
+        let refuse = |message: String| Err(AppErr::validation(&message));
+        let attempts = self.attempts.iter()
+            .filter(|a| a.year == year && a.day == day && a.part == part)
+            .collect::<Vec<_>>();
+
+        if let Some(correct) = attempts.iter().find(|a| a.outcome == Outcome::Correct) {
+            return refuse(format!("{} day {} {} was already solved with {:?}", year, day, part, correct.answer));
+        }
+        for attempt in &attempts {
+            let known_wrong = match (attempt.outcome, number(answer), number(&attempt.answer)) {
+                _ if attempt.outcome.is_wrong() && attempt.answer == answer => true,
+                (Outcome::TooHigh, Some(answer), Some(high)) => answer >= high,
+                (Outcome::TooLow, Some(answer), Some(low))   => answer <= low,
+                _ => false,
+            };
+            if known_wrong {
+                return refuse(format!("{:?} is known to be wrong: {:?} was {}", answer, attempt.answer, attempt.outcome));
+            }
+        }
+        if let Some(last) = attempts.last() {
+            let ready = last.time + last.wait;
+            if ready > now {
+                return refuse(format!("wait {}s before submitting {} day {} {} again", ready - now, year, day, part));
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends `attempt` to the log at `path`.
+    pub fn append(&mut self, path: &Path, attempt: Attempt) -> Result<(), AppErr> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)
+            .with_context(|| format!("failed to open {}", path.display()))?;
+        writeln!(file, "{} {} {} {} {} {} {}",
+            attempt.time, attempt.year, attempt.day, attempt.part.number(), attempt.outcome, attempt.wait, attempt.answer)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+fn number(answer: &str) -> Option<i128> {
+    answer.parse().ok()
+}
+
+fn parse_attempt(line: &str) -> Result<Attempt, AppErr> {
+    let mut fields = line.splitn(7, ' ');
+    let mut next = |name: &str| fields.next().ok_or_else(|| AppErr::from(format!("expected {}", name)));
+    let time = next("a time")?.parse()?;
+    let year = next("a year")?.parse()?;
+    let day = next("a day")?.parse()?;
+    let part = next("a part")?.parse()?;
+    let part = Part::from_number(part).ok_or_else(|| format!("invalid part {}", part))?;
+    let outcome = next("an outcome")?;
+    let outcome = Outcome::from_name(outcome).ok_or_else(|| format!("invalid outcome {}", outcome))?;
+    let wait = next("a wait")?.parse()?;
+    let answer = next("an answer")?.to_string();
+    Ok(Attempt { time, year, day, part, outcome, wait, answer })
+}
+
+/// Submits `answer` unless the log shows it should not be, and records the attempt.
+pub fn submit(client: &Client, log_path: &Path, year: u32, day: u32, part: Part, answer: &str, now: u64) -> Result<Attempt, AppErr> {
+    if answer.is_empty() || answer.contains('\n') {
+        return Err(AppErr::usage(&format!("cannot submit {:?}: answers must be a single line", answer)));
+    }
+    let mut log = AttemptLog::read(log_path)?;
+    log.check(year, day, part, answer, now)?;
+
+    let level = part.number().to_string();
+    let response = client.post_form(&format!("/{}/day/{}/answer", year, day), &[("level", &level), ("answer", answer)])
+        .with_context(|| format!("failed to submit {} day {} {}", year, day, part))?;
+    let (outcome, wait) = parse_response(&response);
+
+    let attempt = Attempt { time: now, year, day, part, outcome, wait, answer: answer.to_string() };
+    log.append(log_path, attempt.clone())?;
+    Ok(attempt)
+}
+
+#[cfg(test)]
+mod submit_tests {
+    use super::*;
+    use crate::client::stand_in;
+
+    const WRONG: &str = "<article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, ... Please wait one minute before trying again.</p></article>";
+    const RIGHT: &str = "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(parse_response(RIGHT), (Outcome::Correct, 0));
+        assert_eq!(parse_response(WRONG), (Outcome::TooHigh, 60));
+        assert_eq!(parse_response("That's not the right answer; your answer is too low. please wait 5 minutes"), (Outcome::TooLow, 300));
+        assert_eq!(parse_response("That's not the right answer. please wait one minute"), (Outcome::Wrong, 60));
+        assert_eq!(parse_response("You gave an answer too recently. You have 1m 5s left to wait."), (Outcome::Wait, 65));
+        assert_eq!(parse_response("You gave an answer too recently. You have 1h 2€ left to wait."), (Outcome::Wait, 60));
+        assert_eq!(parse_response("You don't seem to be solving the right level."), (Outcome::AlreadySolved, 0));
+        assert_eq!(parse_response("<html>Something else</html>"), (Outcome::Unknown, 0));
+    }
+
+    #[test]
+    fn log_round_trips() {
+        let text = "100 2023 8 1 too-high 60 5000\n200 2023 8 1 correct 0 4000\n";
+        let log = AttemptLog::parse(text).unwrap();
+        assert_eq!(log.attempts.len(), 2);
+        assert_eq!(log.attempts[0].outcome, Outcome::TooHigh);
+        assert_eq!(log.attempts[1].answer, "4000");
+        assert!(AttemptLog::parse("100 2023 8 3 correct 0 1").is_err());
+        assert!(AttemptLog::parse("100 2023 8 1 nope 0 1").is_err());
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let log = AttemptLog::parse("100 2023 8 1 too-high 60 5000\n200 2023 8 1 too-low 60 10\n300 2023 8 2 wrong 60 abc\n").unwrap();
+        let now = 1000;
+        assert!(log.check(2023, 8, Part::One, "5000", now).is_err());
+        assert!(log.check(2023, 8, Part::One, "6000", now).is_err());
+        assert!(log.check(2023, 8, Part::One, "9", now).is_err());
+        assert!(log.check(2023, 8, Part::One, "4000", now).is_ok());
+        assert!(log.check(2023, 8, Part::Two, "abc", now).is_err());
+        assert!(log.check(2023, 8, Part::Two, "abd", now).is_ok());
+        assert!(log.check(2023, 9, Part::One, "5000", now).is_ok());
+    }
+
+    #[test]
+    fn respects_cooldown() {
+        let log = AttemptLog::parse("100 2023 8 1 wait 65 5000\n").unwrap();
+        let err = log.check(2023, 8, Part::One, "5000", 150).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+        assert_eq!(err.to_string(), "[Validation] wait 15s before submitting 2023 day 8 Part 1 again");
+        assert!(log.check(2023, 8, Part::One, "5000", 165).is_ok());
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let log = AttemptLog::parse("100 2023 8 1 correct 0 4000\n").unwrap();
+        assert!(log.check(2023, 8, Part::One, "4001", 1000).is_err());
+    }
+
+    #[test]
+    fn submits_and_records_attempts() {
+        let log_path = std::env::temp_dir().join(format!("aoc-submit-test-{}.txt", std::process::id()));
+        let (base_url, requests) = stand_in::serve(vec![(200, WRONG.to_string()), (200, RIGHT.to_string())]);
+        let client = Client::new(&base_url, Some("abc".to_string()));
+
+        let attempt = submit(&client, &log_path, 2023, 8, Part::Two, "5000", 100).unwrap();
+        assert_eq!((attempt.outcome, attempt.wait), (Outcome::TooHigh, 60));
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "POST /2023/day/8/answer HTTP/1.1");
+        assert_eq!(request.body, "level=2&answer=5000");
+
+        // Too soon, and then known to be wrong, without contacting the stand-in
+        assert!(submit(&client, &log_path, 2023, 8, Part::Two, "4000", 120).is_err());
+        assert!(submit(&client, &log_path, 2023, 8, Part::Two, "5001", 200).is_err());
+
+        let attempt = submit(&client, &log_path, 2023, 8, Part::Two, "4000", 200).unwrap();
+        assert_eq!(attempt.outcome, Outcome::Correct);
+        assert_eq!(fs::read_to_string(&log_path).unwrap(), "100 2023 8 2 too-high 60 5000\n200 2023 8 2 correct 0 4000\n");
+        fs::remove_file(&log_path).unwrap();
+    }
+}
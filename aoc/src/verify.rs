@@ -0,0 +1,146 @@
+// Checks each solution's answers against the recorded answers.
+
+use util::answers::Answers;
+use util::error::{ AppErr, ErrorKind };
+use util::solution::Part;
+
+use crate::report::{ DayRun, Entry, Status };
+
+#[derive(Debug, Default)]
+pub struct Tally {
+    pub passed: usize,
+    pub failed: usize,
+    pub errors: usize,
+    pub missing: usize,
+    pub timeouts: usize,
+    /// Timeouts of parts known to be too slow, which do not fail verification.
+    pub slow: usize,
+    /// The most serious kind of failure, if any: a wrong answer, then an error solving a part, then a timeout.
+    pub worst: Option<ErrorKind>,
+}
+
+impl Tally {
+    /// Whether every recorded answer matched, and every part could be solved.
+    pub fn is_success(&self) -> bool {
+        self.failed == 0 && self.errors == 0 && self.timeouts == 0
+    }
+
+    fn record(&mut self, kind: ErrorKind) {
+        // A wrong answer is `Other`, as is any error without a more specific kind
+        let severity = |kind| match kind {
+            ErrorKind::Other   => 2,
+            ErrorKind::Timeout => 0,
+            _                  => 1,
+        };
+        if self.worst.is_none_or(|worst| severity(kind) > severity(worst)) {
+            self.worst = Some(kind);
+        }
+    }
+}
+
+/// Checks both parts of a run against `answers`, printing a line for each part.
+/// A timeout in one of `slow_parts` is expected, and is tallied apart from other timeouts.
+/// Returns the report entry for each part.
+pub fn verify_day(run: &DayRun, slow_parts: &[Part], answers: &Answers, tally: &mut Tally) -> Vec<Entry> {
+    let mut entries = Entry::from_run(run, &Part::ALL);
+
+    for entry in &mut entries {
+        entry.check(answers);
+        let Entry { year, day, part, .. } = *entry;
+        let answer = entry.answer.as_deref().unwrap_or("");
+        match entry.status {
+            Status::Pass => {
+                tally.passed += 1;
+                let cached = if entry.cached { " (cached)" } else { "" };
+                println!("{} day {} {}: pass{}", year, day, part, cached);
+            }
+            Status::Fail => {
+                tally.failed += 1;
+                tally.record(ErrorKind::Other);
+                println!("{} day {} {}: FAIL expected {:?}, got {:?}", year, day, part, entry.expected.as_deref().unwrap_or(""), answer);
+            }
+            Status::Missing => {
+                tally.missing += 1;
+                println!("{} day {} {}: missing (got {:?})", year, day, part, answer);
+            }
+            Status::Timeout if slow_parts.contains(&part) => {
+                tally.slow += 1;
+                println!("{} day {} {}: slow, timed out as expected", year, day, part);
+            }
+            Status::Timeout => {
+                tally.timeouts += 1;
+                tally.record(ErrorKind::Timeout);
+                println!("{} day {} {}: TIMEOUT {}", year, day, part, entry.error.as_deref().unwrap_or(""));
+            }
+            Status::Error | Status::Solved => {
+                tally.errors += 1;
+                tally.record(error_kind(run, part).unwrap_or(ErrorKind::Other));
+                println!("{} day {} {}: ERROR {}", year, day, part, entry.error.as_deref().unwrap_or(""));
+            }
+        }
+    }
+    entries
+}
+
+/// The kind of error which stopped `part` of `run` from being solved, if any.
+fn error_kind(run: &DayRun, part: Part) -> Option<ErrorKind> {
+    match run.parts {
+        Err(ref err) => Some(err.kind()),
+        Ok(ref parts) => parts.iter()
+            .find(|result| result.part == part)?
+            .answer.as_ref()
+            .err()
+            .map(AppErr::kind),
+    }
+}
+
+#[cfg(test)]
+mod verify_tests {
+    use std::time::Duration;
+
+    use util::solution::Answer;
+
+    use super::*;
+    use crate::report::PartRun;
+
+    fn run(answers: [Result<Answer, AppErr>; 2]) -> DayRun {
+        let parts = Part::ALL.iter()
+            .zip(answers)
+            .map(|(&part, answer)| PartRun { part, answer, time: Duration::ZERO, memory: None, cached: false })
+            .collect();
+        DayRun { parts: Ok(parts), ..DayRun::new(&aoc_2015_day1::SOLUTION) }
+    }
+
+    #[test]
+    fn tallies_the_worst_failure() {
+        let answers = Answers::parse("2015 1 1 280\n2015 1 2 1797\n").unwrap();
+        let worst = |run: DayRun| {
+            let mut tally = Tally::default();
+            verify_day(&run, &[], &answers, &mut tally);
+            tally.worst
+        };
+        let timeout = || Err(AppErr::timeout("abandoned"));
+        let no_solution = || Err(AppErr::no_solution("none"));
+
+        assert_eq!(worst(run([Ok(Answer::Int(280)), Ok(Answer::Int(1797))])), None);
+        assert_eq!(worst(run([Ok(Answer::Int(280)), timeout()])), Some(ErrorKind::Timeout));
+        assert_eq!(worst(run([no_solution(), timeout()])), Some(ErrorKind::NoSolution));
+        assert_eq!(worst(run([Ok(Answer::Int(1)), no_solution()])), Some(ErrorKind::Other));
+        let failed = DayRun::failed(&aoc_2015_day1::SOLUTION, AppErr::new("Input", "bad").with_kind(ErrorKind::Parse));
+        assert_eq!(worst(failed), Some(ErrorKind::Parse));
+    }
+
+    #[test]
+    fn expects_slow_parts_to_time_out() {
+        let answers = Answers::parse("2015 1 1 280\n2015 1 2 1797\n").unwrap();
+        let mut tally = Tally::default();
+        verify_day(&run([Ok(Answer::Int(280)), Err(AppErr::timeout("abandoned"))]), &[Part::Two], &answers, &mut tally);
+        assert_eq!((tally.passed, tally.timeouts, tally.slow), (1, 0, 1));
+        assert!(tally.is_success());
+
+        let mut tally = Tally::default();
+        verify_day(&run([Err(AppErr::timeout("abandoned")), Ok(Answer::Int(1797))]), &[Part::Two], &answers, &mut tally);
+        assert_eq!((tally.passed, tally.timeouts, tally.slow), (1, 1, 0));
+        assert!(!tally.is_success());
+    }
+}
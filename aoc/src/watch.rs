@@ -0,0 +1,288 @@
+// Re-runs a day whenever its source or input changes, showing how the answers and timings changed.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{ Path, PathBuf };
+use std::process::{ Command, Stdio };
+use std::time::Duration;
+
+use util::error::{ AppErr, Context };
+
+use crate::args::RunArgs;
+use crate::bench::format_duration;
+use crate::report::{ self, Entry, Status };
+
+/// How long to wait after a change for any further changes, e.g. while an editor saves several files.
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+/// Rebuilds the runner and runs the day in a new process, so that changes to its source are picked up.
+/// The report of the run is written to `report_path`, and read back.
+pub fn run(repo_dir: &Path, args: &RunArgs, input_path: &Path, report_path: &Path) -> Result<Vec<Entry>, AppErr> {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command.current_dir(repo_dir)
+        .args(["run", "--quiet", "--release", "-p", "aoc", "--", "run"])
+        .arg(args.year.to_string())
+        .arg(args.day.to_string())
+        .arg("--input").arg(input_path)
+        .arg("--report").arg(report_path)
+        // The answers are shown with their changes instead
+        .stdout(Stdio::null());
+    if let Some(part) = args.part {
+        command.arg("--part").arg(part.number().to_string());
+    }
+    if let Some(ref name) = args.implementation {
+        command.arg("--impl").arg(name);
+    }
+    for (name, value) in &args.params {
+        command.arg("--param").arg(format!("{}={}", name, value));
+    }
+    // No timeout is passed on as 0
+    let timeout = args.timeout.map_or(0, |t| t.as_secs());
+    command.arg("--timeout").arg(timeout.to_string());
+    if args.force {
+        command.arg("--force");
+    }
+    if let Some(ref path) = args.report.junit {
+        command.arg("--junit").arg(path);
+    }
+
+    let _ = fs::remove_file(report_path);
+    let status = command.status()
+        .context("failed to run cargo")?;
+    // A report is written even if a part fails
+    if !report_path.exists() {
+        return Err(AppErr::new("Watch", &format!("failed to build or run {} day {} ({})", args.year, args.day, status)));
+    }
+    report::read(report_path)
+}
+
+/// The answer and time of each part of `current`, with how they changed from `previous`.
+pub fn format_changes(previous: &[Entry], current: &[Entry]) -> String {
+    let mut text = String::new();
+    let last = |entry: &Entry| previous.iter().find(|e| (e.year, e.day, e.part) == (entry.year, entry.day, entry.part));
+
+    if let Some(entry) = current.first() {
+        let parse_change = previous.first().map_or(String::new(), |p| format_delta(p.parse_time, entry.parse_time));
+        writeln!(text, "Parse: {}{}", format_duration(entry.parse_time), parse_change).unwrap();
+    }
+    for entry in current {
+        let previous = last(entry);
+        let previous_answer = previous.and_then(|p| p.answer.as_deref());
+        let time_change = previous.map_or(String::new(), |p| format_delta(p.time, entry.time));
+        let answer = match (entry.status, entry.answer.as_deref()) {
+            (Status::Error, _) => format!("ERROR {}", entry.error.as_deref().unwrap_or("")),
+            (Status::Timeout, _) => format!("TIMEOUT {}", entry.error.as_deref().unwrap_or("")),
+            (_, answer) => answer.unwrap_or("").to_string(),
+        };
+        let time = format!("{}{}", format_duration(entry.time), time_change);
+        // Pictures are shown below the line
+        if answer.contains('\n') {
+            let change = match previous_answer {
+                None                      => "",
+                Some(p) if p == answer    => "picture unchanged, ",
+                Some(_)                   => "picture changed, ",
+            };
+            writeln!(text, "{}: {}{}\n{}", entry.part, change, time, answer).unwrap();
+        }
+        else {
+            let change = match previous_answer {
+                None                      => String::new(),
+                Some(p) if p == answer    => " (unchanged)".to_string(),
+                Some(p) if p.contains('\n') => " (was a picture)".to_string(),
+                Some(p)                   => format!(" (was {})", p),
+            };
+            writeln!(text, "{}: {}{}, {}", entry.part, answer, change, time).unwrap();
+        }
+    }
+    text
+}
+
+/// The change from `previous` to `current`, e.g. ` (+1.20ms)`.
+fn format_delta(previous: Duration, current: Duration) -> String {
+    match current.checked_sub(previous) {
+        Some(increase) => format!(" (+{})", format_duration(increase)),
+        None           => format!(" (-{})", format_duration(previous - current)),
+    }
+}
+
+/// Whether a change to `path` should trigger a new run.
+fn is_watched(path: &Path, input_path: &Path) -> bool {
+    path == input_path || matches!(path.extension().and_then(|e| e.to_str()), Some("rs" | "toml"))
+}
+
+/// `dir` and each directory below it, except build output and hidden directories.
+fn directories(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = vec![dir.to_path_buf()];
+    let mut i = 0;
+    while i < dirs.len() {
+        if let Ok(entries) = fs::read_dir(&dirs[i]) {
+            let mut children = entries
+                .filter_map(Result::ok)
+                .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+                .filter(|e| e.file_name() != "target" && !e.file_name().to_string_lossy().starts_with('.'))
+                .map(|e| e.path())
+                .collect::<Vec<_>>();
+            children.sort();
+            dirs.extend(children);
+        }
+        i += 1;
+    }
+    dirs
+}
+
+/// Watches a day's crate and its input file for changes, using inotify.
+#[cfg(target_os = "linux")]
+pub struct Watcher {
+    inotify: inotify::Inotify,
+    dirs: Vec<(inotify::WatchDescriptor, PathBuf)>,
+    input_path: PathBuf,
+}
+
+#[cfg(target_os = "linux")]
+impl Watcher {
+    pub fn new(day_dir: &Path, input_path: &Path) -> Result<Watcher, AppErr> {
+        use inotify::{ Inotify, WatchMask };
+
+        let inotify = Inotify::init()
+            .context("failed to initialise inotify")?;
+        let mut dirs = directories(day_dir);
+        if let Some(input_dir) = input_path.parent().filter(|dir| !dirs.iter().any(|d| d == dir)) {
+            dirs.push(input_dir.to_path_buf());
+        }
+        let mask = WatchMask::CLOSE_WRITE | WatchMask::CREATE | WatchMask::DELETE | WatchMask::MOVED_TO;
+        let dirs = dirs.into_iter()
+            .map(|dir| {
+                let wd = inotify.watches().add(&dir, mask)
+                    .with_context(|| format!("failed to watch {}", dir.display()))?;
+                Ok((wd, dir))
+            })
+            .collect::<Result<_, AppErr>>()?;
+        Ok(Watcher { inotify, dirs, input_path: input_path.to_path_buf() })
+    }
+
+    /// Blocks until a watched file changes, then waits for the changes to settle.
+    /// Returns each file which changed.
+    pub fn wait(&mut self) -> Result<Vec<PathBuf>, AppErr> {
+        let mut buffer = [0; 4096];
+        let mut changed = Vec::new();
+        while changed.is_empty() {
+            let events = self.inotify.read_events_blocking(&mut buffer)
+                .context("failed to read inotify events")?;
+            changed.extend(self.changed_paths(events));
+        }
+        loop {
+            std::thread::sleep(SETTLE_TIME);
+            match self.inotify.read_events(&mut buffer) {
+                Ok(events) => {
+                    let more = self.changed_paths(events);
+                    if more.is_empty() {
+                        break;
+                    }
+                    changed.extend(more);
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => break,
+                Err(err) => return Err(AppErr::from(err).context("failed to read inotify events")),
+            }
+        }
+        changed.sort();
+        changed.dedup();
+        Ok(changed)
+    }
+
+    fn changed_paths<'a>(&self, events: impl Iterator<Item=inotify::Event<&'a std::ffi::OsStr>>) -> Vec<PathBuf> {
+        events
+            .filter_map(|event| {
+                let (_, dir) = self.dirs.iter().find(|(wd, _)| *wd == event.wd)?;
+                Some(dir.join(event.name?))
+            })
+            .filter(|path| is_watched(path, &self.input_path))
+            .collect()
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub struct Watcher;
+
+#[cfg(not(target_os = "linux"))]
+impl Watcher {
+    pub fn new(_day_dir: &Path, _input_path: &Path) -> Result<Watcher, AppErr> {
+        Err(AppErr::usage("watch mode uses inotify, which is only available on Linux"))
+    }
+
+    pub fn wait(&mut self) -> Result<Vec<PathBuf>, AppErr> {
+        unreachable!()
+    }
+}
+
+#[cfg(test)]
+mod watch_tests {
+    use util::solution::Part;
+
+    use super::*;
+
+    fn entry(part: Part, answer: &str, micros: u64) -> Entry {
+        Entry {
+            year: 2015,
+            day: 1,
+            part,
+            status: Status::Solved,
+            answer: Some(answer.to_string()),
+            expected: None,
+            error: None,
+            parse_time: Duration::from_micros(micros),
+            time: Duration::from_micros(micros),
+            parse_memory: None,
+            memory: None,
+            input_hash: None,
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn shows_changes() {
+        let first = [entry(Part::One, "280", 10), entry(Part::Two, "1797", 20)];
+        assert_eq!(format_changes(&[], &first), "Parse: 10.0µs\nPart 1: 280, 10.0µs\nPart 2: 1797, 20.0µs\n");
+
+        let failed = Entry { status: Status::Error, answer: None, error: Some("[NoSolution] none".to_string()), ..entry(Part::Two, "", 5) };
+        let second = [entry(Part::One, "280", 12), failed];
+        assert_eq!(format_changes(&first, &second), concat!(
+            "Parse: 12.0µs (+2.0µs)\n",
+            "Part 1: 280 (unchanged), 12.0µs (+2.0µs)\n",
+            "Part 2: ERROR [NoSolution] none (was 1797), 5.0µs (-15.0µs)\n"));
+
+        let third = [entry(Part::One, "281", 12), entry(Part::Two, "# #\n # ", 12)];
+        assert_eq!(format_changes(&second, &third), concat!(
+            "Parse: 12.0µs (+0.0µs)\n",
+            "Part 1: 281 (was 280), 12.0µs (+0.0µs)\n",
+            "Part 2: 12.0µs (+7.0µs)\n# #\n # \n"));
+
+        let fourth = [entry(Part::One, "281", 12), entry(Part::Two, "# #\n##", 12)];
+        assert!(format_changes(&third, &fourth).contains("Part 2: picture changed, 12.0µs (+0.0µs)\n# #\n##\n"));
+    }
+
+    #[test]
+    fn watches_source_and_input() {
+        let input = Path::new("/aoc/2015/day1/input.txt");
+        assert!(is_watched(input, input));
+        assert!(is_watched(Path::new("/aoc/2015/day1/src/lib.rs"), input));
+        assert!(is_watched(Path::new("/aoc/2015/day1/Cargo.toml"), input));
+        assert!(!is_watched(Path::new("/aoc/2015/day1/example.txt"), input));
+        assert!(!is_watched(Path::new("/aoc/2015/day1/src/.lib.rs.swp"), input));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn waits_for_changes() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-test-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        let input_path = dir.join("input.txt");
+        let mut watcher = Watcher::new(&dir, &input_path).unwrap();
+
+        fs::write(dir.join("notes.txt"), "ignored").unwrap();
+        fs::write(dir.join("src").join("lib.rs"), "// changed").unwrap();
+        fs::write(&input_path, "1").unwrap();
+        assert_eq!(watcher.wait().unwrap(), [input_path.clone(), dir.join("src").join("lib.rs")]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
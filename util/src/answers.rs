@@ -0,0 +1,206 @@
+// Utility for recording the expected answer to each puzzle
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use crate::error::{ AppErr, Context, ErrorKind };
+use crate::input::lines;
+use crate::solution::{ Answer, Part };
+
+/// Expected answers, keyed by year, day and part.
+///
+/// The file format has one answer per line: `<year> <day> <part> <answer>`.
+/// Blank lines and lines starting with `#` are ignored.
+/// Answers which span several lines (e.g. a grid) are written on one line,
+/// with each newline escaped as `\n` and each backslash as `\\`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u32, u32, Part), String>,
+}
+
+/// The result of checking an answer against the recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    /// No answer has been recorded for this part.
+    Missing,
+}
+
+impl Answers {
+    pub fn new() -> Answers {
+        Answers { entries: BTreeMap::new() }
+    }
+
+    /// Reads an answers file. A missing file is an error.
+    pub fn read<P>(path: P) -> Result<Answers, AppErr>
+        where P: AsRef<Path>
+    {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        Answers::parse(&text)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, AppErr> {
+        let mut answers = Answers::new();
+        for (number, line) in lines(text) {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, answer) = parse_entry(line)
+                .map_err(|err| err.with_kind(ErrorKind::Parse))
+                .with_context(|| format!("failed to parse line {}: {:?}", number, line))?;
+            if answers.entries.insert(key, answer).is_some() {
+                let (year, day, part) = key;
+                return Err(AppErr::new("Answers", &format!("line {}: {} day {} {} is recorded twice", number, year, day, part))
+                    .with_kind(ErrorKind::Parse));
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: Part) -> Option<&str> {
+        self.entries.get(&(year, day, part)).map(String::as_str)
+    }
+
+    /// Records `answer`, replacing any answer already recorded for this part.
+    pub fn insert(&mut self, year: u32, day: u32, part: Part, answer: &Answer) {
+        self.entries.insert((year, day, part), canonical(answer));
+    }
+
+    pub fn check(&self, year: u32, day: u32, part: Part, answer: &Answer) -> Verdict {
+        match self.get(year, day, part) {
+            None => Verdict::Missing,
+            Some(expected) if expected == canonical(answer) => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_string() },
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Writes the answers in the file format read by `Answers::parse`.
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (&(year, day, part), answer) in &self.entries {
+            writeln!(f, "{} {} {} {}", year, day, part.number(), escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+/// The form in which an answer is recorded and compared.
+/// Trailing whitespace on each line is not significant.
+pub fn canonical(answer: &Answer) -> String {
+    trim_lines(&answer.to_string())
+}
+
+fn trim_lines(text: &str) -> String {
+    text.lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parse_entry(line: &str) -> Result<((u32, u32, Part), String), AppErr> {
+    let mut fields = line.splitn(4, ' ');
+    let year = fields.next().ok_or("expected a year")?.parse()?;
+    let day = fields.next().ok_or("expected a day")?.parse()?;
+    let part = fields.next().ok_or("expected a part")?.parse()?;
+    let part = Part::from_number(part).ok_or_else(|| format!("invalid part {}", part))?;
+    let answer = trim_lines(&unescape(fields.next().unwrap_or(""))?);
+    Ok(((year, day, part), answer))
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(text: &str) -> Result<String, AppErr> {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n')  => result.push('\n'),
+            Some('\\') => result.push('\\'),
+            Some(other) => return Err(format!("invalid escape sequence \\{}", other).into()),
+            None        => return Err("incomplete escape sequence".into()),
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod answers_tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+# year day part answer
+2015 1 1 280
+2015 1 2 1797
+
+2018 10 1 # #\\n ## \\n#  #
+2018 11 1 34,13
+";
+
+    #[test]
+    fn parse_and_get() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.len(), 4);
+        assert_eq!(answers.get(2015, 1, Part::One), Some("280"));
+        assert_eq!(answers.get(2015, 1, Part::Two), Some("1797"));
+        assert_eq!(answers.get(2018, 10, Part::One), Some("# #\n ##\n#  #"));
+        assert_eq!(answers.get(2018, 11, Part::Two), None);
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+    }
+
+    #[test]
+    fn check_verdicts() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(2015, 1, Part::One, &280.into()), Verdict::Pass);
+        assert_eq!(answers.check(2015, 1, Part::Two, &1.into()), Verdict::Fail { expected: "1797".into() });
+        assert_eq!(answers.check(2017, 1, Part::One, &1.into()), Verdict::Missing);
+        assert_eq!(answers.check(2018, 11, Part::One, &"34,13".into()), Verdict::Pass);
+    }
+
+    #[test]
+    fn grid_trailing_whitespace_is_not_significant() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let grid = Answer::Grid(vec!["# #  ".into(), " ##".into(), "#  #   ".into()]);
+        assert_eq!(answers.check(2018, 10, Part::One, &grid), Verdict::Pass);
+    }
+
+    #[test]
+    fn insert_escapes_answer() {
+        let mut answers = Answers::new();
+        answers.insert(2018, 10, Part::One, &Answer::Grid(vec!["a\\b".into(), "c".into()]));
+        assert_eq!(answers.to_string(), "2018 10 1 a\\\\b\\nc\n");
+    }
+
+    #[test]
+    fn invalid_entries() {
+        for text in ["2015 1 3 280", "2015 x 1 280", "2015", "2015 1 1 \\t", "2015 1 1 1\n2015 1 1 2"] {
+            let err = Answers::parse(text).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Parse, "{}", text);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// Utility for stopping solutions which run for too long
+
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicBool, AtomicU64, Ordering };
+use std::time::{ Duration, Instant };
+
+use crate::error::AppErr;
+
+/// Shared between a runner and the solution it runs, which checks it in any loop that may run for a long time.
+/// The token is cancelled when `cancel` is called, or once its deadline has passed.
+#[derive(Debug, Clone)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    created: Instant,
+    cancelled: AtomicBool,
+    /// Nanoseconds after `created`, or `u64::MAX` for no deadline.
+    deadline: AtomicU64,
+}
+
+impl CancellationToken {
+    /// A token without a deadline, which is only cancelled by `cancel`.
+    pub fn new() -> CancellationToken {
+        CancellationToken {
+            inner: Arc::new(Inner {
+                created: Instant::now(),
+                cancelled: AtomicBool::new(false),
+                deadline: AtomicU64::new(u64::MAX),
+            }),
+        }
+    }
+
+    /// A token which is cancelled once `budget` has passed.
+    pub fn with_budget(budget: Duration) -> CancellationToken {
+        let token = CancellationToken::new();
+        token.restart(budget);
+        token
+    }
+
+    /// Moves the deadline to `budget` from now, e.g. as each part starts.
+    pub fn restart(&self, budget: Duration) {
+        let deadline = self.inner.created.elapsed().saturating_add(budget);
+        let nanos = deadline.as_nanos().min(u64::MAX as u128) as u64;
+        self.inner.deadline.store(nanos, Ordering::Relaxed);
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        if self.inner.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+        let deadline = self.inner.deadline.load(Ordering::Relaxed);
+        deadline != u64::MAX && self.inner.created.elapsed().as_nanos() >= deadline as u128
+    }
+
+    /// Fails with a timeout error once the token is cancelled.
+    pub fn check(&self) -> Result<(), AppErr> {
+        if self.is_cancelled() {
+            return Err(AppErr::timeout("cancelled after running out of time"));
+        }
+        Ok(())
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> CancellationToken {
+        CancellationToken::new()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `token` as the current thread's token, which is checked by `check`.
+pub fn with_token<T, F>(token: &CancellationToken, f: F) -> T
+    where F: FnOnce() -> T
+{
+    let previous = CURRENT.with(|current| current.replace(Some(token.clone())));
+    let result = f();
+    CURRENT.with(|current| current.replace(previous));
+    result
+}
+
+/// The current thread's token, if any.
+/// A solution which starts its own threads should pass it to them with `with_token`.
+pub fn current() -> Option<CancellationToken> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Fails with a timeout error if the current thread's token has been cancelled.
+/// Always succeeds when there is no token, e.g. when a day's binary runs itself.
+///
+/// ```ignore
+/// loop {
+///     util::cancel::check()?;
+///     ...
+/// }
+/// ```
+pub fn check() -> Result<(), AppErr> {
+    CURRENT.with(|current| match *current.borrow() {
+        Some(ref token) => token.check(),
+        None            => Ok(()),
+    })
+}
+
+#[cfg(test)]
+mod cancel_tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn cancels_token() {
+        let token = CancellationToken::new();
+        assert!(token.check().is_ok());
+        token.clone().cancel();
+        assert_eq!(token.check().unwrap_err().kind(), ErrorKind::Timeout);
+    }
+
+    #[test]
+    fn cancels_after_deadline() {
+        let token = CancellationToken::with_budget(Duration::ZERO);
+        assert!(token.is_cancelled());
+        token.restart(Duration::from_secs(60));
+        assert!(!token.is_cancelled());
+    }
+
+    #[test]
+    fn checks_current_token() {
+        assert!(check().is_ok());
+        let token = CancellationToken::new();
+        token.cancel();
+        let result = with_token(&token, || {
+            assert!(current().is_some());
+            check()
+        });
+        assert!(result.is_err());
+        assert!(current().is_none());
+    }
+}
@@ -0,0 +1,484 @@
+use std::fmt::{Debug, Display, Formatter, Error as FmtError};
+use std::error::Error;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::process::{ExitCode, Termination};
+
+use crate::json::quote;
+
+/// Broad classification of an error, used to pick the process exit code.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// Any error which has not been given a more specific kind.
+    Other,
+    /// Reading or writing a file failed.
+    Io,
+    /// The puzzle input could not be parsed.
+    Parse,
+    /// The input parsed, but a solution does not exist for it.
+    NoSolution,
+    /// The input parsed, but broke one of the puzzle's rules.
+    Validation,
+    /// The solution took longer than it was allowed.
+    Timeout,
+    /// The program was invoked with invalid arguments.
+    Usage,
+}
+
+impl ErrorKind {
+    /// The process exit code for this kind of error.
+    /// Where one fits, codes follow the BSD sysexits convention.
+    pub fn exit_code(self) -> u8 {
+        match self {
+            ErrorKind::Other      => 1,
+            ErrorKind::NoSolution => 3,
+            ErrorKind::Validation => 4,
+            ErrorKind::Usage      => 64,  // EX_USAGE
+            ErrorKind::Parse      => 65,  // EX_DATAERR
+            ErrorKind::Io         => 74,  // EX_IOERR
+            ErrorKind::Timeout    => 124, // As timeout(1)
+        }
+    }
+
+    /// A stable, machine-readable name for this kind of error.
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorKind::Other      => "other",
+            ErrorKind::Io         => "io",
+            ErrorKind::Parse      => "parse",
+            ErrorKind::NoSolution => "no_solution",
+            ErrorKind::Validation => "validation",
+            ErrorKind::Timeout    => "timeout",
+            ErrorKind::Usage      => "usage",
+        }
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        write!(f, "{}", self.name())
+    }
+}
+
+pub struct AppErr {
+    kind: ErrorKind,
+    message: String,
+    cause: Cause,
+    backtrace: Option<Backtrace>,
+}
+
+enum Cause {
+    None,
+    /// An underlying error which this AppErr describes directly.
+    /// Its own source (if any) continues the chain.
+    Wrapped(Box<dyn Error + Send + Sync>),
+    /// An AppErr which has been wrapped with additional context.
+    Context(Box<AppErr>),
+}
+
+impl AppErr {
+    fn with_cause(message: String, cause: Cause) -> AppErr {
+        // Context wrappers share the kind and backtrace of the error they wrap
+        let (kind, backtrace) = match cause {
+            Cause::Context(ref inner) => (inner.kind, None),
+            _ => (ErrorKind::Other, Some(Backtrace::capture())),
+        };
+        AppErr { kind, message, cause, backtrace }
+    }
+
+    fn from_err<E> (kind: &str, error: E) -> AppErr
+        where E: Error + Send + Sync + 'static
+    {
+        AppErr::with_cause(format!("[{}] {}", kind, error), Cause::Wrapped(Box::new(error)))
+    }
+
+    pub fn from_debug (kind: &str, error: &dyn Debug) -> AppErr {
+        AppErr::with_cause(format!("[{}] {:?}", kind, error), Cause::None)
+    }
+
+    pub fn from_display (kind: &str, error: &dyn Display) -> AppErr {
+        AppErr::with_cause(format!("[{}] {}", kind, error), Cause::None)
+    }
+
+    pub fn new (kind: &str, message: &str) -> AppErr {
+        AppErr::with_cause(format!("[{}] {}", kind, message), Cause::None)
+    }
+
+    pub fn no_solution (message: &str) -> AppErr {
+        AppErr::new("NoSolution", message).with_kind(ErrorKind::NoSolution)
+    }
+
+    pub fn validation (message: &str) -> AppErr {
+        AppErr::new("Validation", message).with_kind(ErrorKind::Validation)
+    }
+
+    pub fn timeout (message: &str) -> AppErr {
+        AppErr::new("Timeout", message).with_kind(ErrorKind::Timeout)
+    }
+
+    pub fn usage (message: &str) -> AppErr {
+        AppErr::new("Usage", message).with_kind(ErrorKind::Usage)
+    }
+
+    /// Reclassifies this error.
+    pub fn with_kind (mut self, kind: ErrorKind) -> AppErr {
+        self.kind = kind;
+        self
+    }
+
+    pub fn kind (&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Wraps this error with a message describing what was being attempted when it occurred.
+    pub fn context<C> (self, context: C) -> AppErr
+        where C: Display
+    {
+        AppErr::with_cause(context.to_string(), Cause::Context(Box::new(self)))
+    }
+
+    /// Iterates over this error and each of its causes, outermost first.
+    pub fn chain (&self) -> Chain<'_> {
+        Chain { next: Some(self) }
+    }
+
+    /// The innermost error in the chain.
+    pub fn root_cause (&self) -> &(dyn Error + 'static) {
+        self.chain().last().unwrap_or(self)
+    }
+
+    /// The backtrace captured when the original error was created.
+    /// Capture is controlled by the `RUST_BACKTRACE` and `RUST_LIB_BACKTRACE` environment variables.
+    pub fn backtrace (&self) -> Option<&Backtrace> {
+        match self.cause {
+            Cause::Context(ref inner) => inner.backtrace(),
+            _ => self.backtrace.as_ref().filter(|bt| bt.status() == BacktraceStatus::Captured),
+        }
+    }
+
+    /// Formats this error along with every cause and the captured backtrace, if any.
+    pub fn report (&self) -> Report<'_> {
+        Report(self)
+    }
+
+    /// Formats this error as a single-line JSON object for consumption by other tools.
+    /// E.g. `{"kind":"parse","exit_code":65,"message":"...","causes":["..."]}`
+    pub fn to_json (&self) -> String {
+        let causes = self.chain()
+            .skip(1)
+            .map(|cause| quote(&cause.to_string()))
+            .collect::<Vec<_>>();
+        format!(
+            "{{\"kind\":{},\"exit_code\":{},\"message\":{},\"causes\":[{}]}}",
+            quote(self.kind.name()),
+            self.kind.exit_code(),
+            quote(&self.message),
+            causes.join(",")
+        )
+    }
+}
+
+pub fn fail (message: &str) -> AppErr {
+    AppErr::new("Fail", message)
+}
+
+impl Display for AppErr {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Debug for AppErr {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        // Used when an error is returned from main
+        Display::fmt(&self.report(), f)
+    }
+}
+
+impl Error for AppErr {
+    fn source (&self) -> Option<&(dyn Error + 'static)> {
+        match self.cause {
+            Cause::None               => None,
+            Cause::Wrapped(ref err)   => err.source(),
+            Cause::Context(ref inner) => Some(inner.as_ref()),
+        }
+    }
+}
+
+pub struct Chain<'a> {
+    next: Option<&'a (dyn Error + 'static)>,
+}
+
+impl<'a> Iterator for Chain<'a> {
+    type Item = &'a (dyn Error + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.next.take()?;
+        self.next = next.source();
+        Some(next)
+    }
+}
+
+pub struct Report<'a>(&'a AppErr);
+
+impl<'a> Display for Report<'a> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        write!(f, "{}", self.0)?;
+        let causes = self.0.chain().skip(1).collect::<Vec<_>>();
+        if !causes.is_empty() {
+            write!(f, "\n\nCaused by:")?;
+            for (i, cause) in causes.iter().enumerate() {
+                write!(f, "\n    {}: {}", i, cause)?;
+            }
+        }
+        if let Some(backtrace) = self.0.backtrace() {
+            write!(f, "\n\nStack backtrace:\n{}", backtrace)?;
+        }
+        Ok(())
+    }
+}
+
+/// Set to `json` to have `Exit` write errors to stderr as JSON rather than as a report.
+pub const ERROR_FORMAT_VAR: &str = "AOC_ERROR_FORMAT";
+
+/// The result of `main`, which maps the error kind (if any) to the process exit code.
+///
+/// ```no_run
+/// # use util::error::{AppErr, Exit};
+/// fn main() -> Exit {
+///     run().into()
+/// }
+///
+/// fn run() -> Result<(), AppErr> {
+///     Ok(())
+/// }
+/// ```
+pub struct Exit(pub Result<(), AppErr>);
+
+impl From<Result<(), AppErr>> for Exit {
+    fn from(result: Result<(), AppErr>) -> Self {
+        Exit(result)
+    }
+}
+
+impl Termination for Exit {
+    fn report(self) -> ExitCode {
+        match self.0 {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                match std::env::var(ERROR_FORMAT_VAR) {
+                    Ok(ref format) if format == "json" => eprintln!("{}", err.to_json()),
+                    _ => eprintln!("Error: {}", err.report()),
+                }
+                ExitCode::from(err.kind().exit_code())
+            }
+        }
+    }
+}
+
+/// Extension trait for attaching context to fallible values.
+pub trait Context<T> {
+    /// Converts the error into an AppErr, wrapped with `context`.
+    fn context<C>(self, context: C) -> Result<T, AppErr>
+        where C: Display;
+
+    /// As `context`, but only builds the message if an error occurred.
+    fn with_context<C, F>(self, context: F) -> Result<T, AppErr>
+        where C: Display,
+              F: FnOnce() -> C;
+}
+
+impl<T, E> Context<T> for Result<T, E>
+    where E: Into<AppErr>
+{
+    fn context<C>(self, context: C) -> Result<T, AppErr>
+        where C: Display
+    {
+        self.map_err(|err| err.into().context(context))
+    }
+
+    fn with_context<C, F>(self, context: F) -> Result<T, AppErr>
+        where C: Display,
+              F: FnOnce() -> C
+    {
+        self.map_err(|err| err.into().context(context()))
+    }
+}
+
+impl<T> Context<T> for Option<T> {
+    fn context<C>(self, context: C) -> Result<T, AppErr>
+        where C: Display
+    {
+        self.ok_or_else(|| AppErr::with_cause(context.to_string(), Cause::None))
+    }
+
+    fn with_context<C, F>(self, context: F) -> Result<T, AppErr>
+        where C: Display,
+              F: FnOnce() -> C
+    {
+        self.ok_or_else(|| AppErr::with_cause(context().to_string(), Cause::None))
+    }
+}
+
+macro_rules! impl_from_error {
+    ($type:ty, $kind:expr) => {
+        impl From<$type> for AppErr {
+            fn from(err: $type) -> Self {
+                AppErr::from_err(stringify!($type), err).with_kind($kind)
+            }
+        }
+    }
+}
+
+macro_rules! impl_from_debug {
+    ($type:ty, $kind:expr) => {
+        impl From<$type> for AppErr {
+            fn from(err: $type) -> Self {
+                AppErr::from_debug(stringify!($type), &err).with_kind($kind)
+            }
+        }
+    }
+}
+
+macro_rules! impl_from_display {
+    ($type:ty, $kind:expr) => {
+        impl From<$type> for AppErr {
+            fn from(err: $type) -> Self {
+                AppErr::from_display(stringify!($type), &err).with_kind($kind)
+            }
+        }
+    }
+}
+
+// Error conversions, each labelled with the type as written here
+use std;
+use crate::ConsumeIteratorError;
+impl_from_error!(std::io::Error, ErrorKind::Io);
+impl_from_error!(std::num::ParseIntError, ErrorKind::Parse);
+impl_from_display!(ConsumeIteratorError, ErrorKind::Parse);
+// impl_from_debug!(crate::parse::ParseErr);
+impl_from_debug!(&str, ErrorKind::Other);
+impl_from_debug!(String, ErrorKind::Other);
+
+impl<'a> From<crate::parse::ParseErr<'a>> for AppErr {
+    fn from(err: crate::parse::ParseErr<'a>) -> Self {
+       AppErr::from_display("ParseErr", &err).with_kind(ErrorKind::Parse)
+    }
+}
+
+#[cfg(test)]
+mod error_tests {
+    use super::*;
+
+    fn parse_number(s: &str) -> Result<i32, AppErr> {
+        Ok(s.parse::<i32>()?)
+    }
+
+    #[test]
+    fn display_is_outermost_message() {
+        let err = parse_number("x").context("parsing line 12 of input.txt").unwrap_err();
+        assert_eq!(err.to_string(), "parsing line 12 of input.txt");
+    }
+
+    #[test]
+    fn source_chain() {
+        let err = parse_number("x")
+            .context("parsing line 12 of input.txt")
+            .context("reading input")
+            .unwrap_err();
+        let chain = err.chain().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(chain, vec![
+            "reading input",
+            "parsing line 12 of input.txt",
+            "[std::num::ParseIntError] invalid digit found in string",
+        ]);
+        assert_eq!(err.root_cause().to_string(), "[std::num::ParseIntError] invalid digit found in string");
+    }
+
+    #[test]
+    fn with_context_is_lazy() {
+        let mut called = false;
+        let result = parse_number("1").with_context(|| { called = true; "unused" });
+        assert_eq!(result.unwrap(), 1);
+        assert!(!called);
+    }
+
+    #[test]
+    fn option_context() {
+        let err = None::<i32>.context("expected a value").unwrap_err();
+        assert_eq!(err.to_string(), "expected a value");
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn report_lists_every_cause() {
+        let err = parse_number("x")
+            .context("parsing line 12 of input.txt")
+            .context("reading input")
+            .unwrap_err();
+        let report = err.report().to_string();
+        assert!(report.starts_with(concat!(
+            "reading input\n",
+            "\n",
+            "Caused by:\n",
+            "    0: parsing line 12 of input.txt\n",
+            "    1: [std::num::ParseIntError] invalid digit found in string",
+        )));
+    }
+
+    #[test]
+    fn kind_from_conversion() {
+        assert_eq!(parse_number("x").unwrap_err().kind(), ErrorKind::Parse);
+        let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "input.txt");
+        assert_eq!(AppErr::from(io_err).kind(), ErrorKind::Io);
+        assert_eq!(AppErr::from("oops").kind(), ErrorKind::Other);
+        assert_eq!(fail("oops").kind(), ErrorKind::Other);
+
+        let err = AppErr::from(ConsumeIteratorError::IteratorEmpty("password"));
+        assert_eq!(err.kind(), ErrorKind::Parse);
+        assert_eq!(err.to_string(), "[ConsumeIteratorError] expected password but no elements remained");
+    }
+
+    #[test]
+    fn context_keeps_kind() {
+        let err = parse_number("x").context("parsing line 12").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Parse);
+        let err = err.with_kind(ErrorKind::Validation).context("reading input");
+        assert_eq!(err.kind(), ErrorKind::Validation);
+    }
+
+    #[test]
+    fn exit_codes_are_distinct() {
+        let kinds = [
+            ErrorKind::Other,
+            ErrorKind::Io,
+            ErrorKind::Parse,
+            ErrorKind::NoSolution,
+            ErrorKind::Validation,
+            ErrorKind::Timeout,
+            ErrorKind::Usage,
+        ];
+        let mut codes = kinds.iter().map(|k| k.exit_code()).collect::<Vec<_>>();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), kinds.len());
+        assert!(!codes.contains(&0));
+    }
+
+    #[test]
+    fn json_format() {
+        let err = parse_number("x").context("parsing \"line\" 12").unwrap_err();
+        assert_eq!(err.to_json(), concat!(
+            r#"{"kind":"parse","exit_code":65,"message":"parsing \"line\" 12","#,
+            r#""causes":["[std::num::ParseIntError] invalid digit found in string"]}"#
+        ));
+        let err = AppErr::no_solution("nothing\nfound");
+        assert_eq!(err.to_json(), r#"{"kind":"no_solution","exit_code":3,"message":"[NoSolution] nothing\nfound","causes":[]}"#);
+    }
+
+    #[test]
+    fn report_without_causes() {
+        let err = fail("oops");
+        assert!(err.report().to_string().starts_with("[Fail] oops"));
+        assert!(!err.report().to_string().contains("Caused by"));
+    }
+}
@@ -0,0 +1,247 @@
+// Utility for reading input files into a single owned buffer
+
+use std::fs;
+use std::io::Error as IoError;
+use std::path::{ Path, PathBuf };
+use std::str;
+
+use crate::error::{ AppErr, Context, ErrorKind };
+
+#[cfg(feature = "mmap")]
+use memmap2::Mmap;
+
+enum Buffer {
+    Owned(String),
+    #[cfg(feature = "mmap")]
+    Mapped(Mmap),
+}
+
+/// An input file held in a single buffer.
+/// Lines and other views into the file are borrowed from the buffer
+/// rather than allocated individually.
+pub struct InputFile {
+    path: PathBuf,
+    buffer: Buffer,
+}
+
+impl InputFile {
+    /// Reads the entire file at `path` into memory.
+    pub fn open<P>(path: P) -> Result<InputFile, IoError>
+        where P: AsRef<Path>
+    {
+        let path = path.as_ref().to_path_buf();
+        let buffer = Buffer::Owned(fs::read_to_string(&path)?);
+        Ok(InputFile { path, buffer })
+    }
+
+    /// Memory-maps the file at `path`.
+    /// The contents are validated as UTF-8 once, up front, so that `as_str` need not validate them again.
+    #[cfg(feature = "mmap")]
+    pub fn map<P>(path: P) -> Result<InputFile, IoError>
+        where P: AsRef<Path>
+    {
+        let path = path.as_ref().to_path_buf();
+        let file = fs::File::open(&path)?;
+        // SAFETY: Input files are not expected to be modified while they are being solved.
+        let map = unsafe { Mmap::map(&file)? };
+        str::from_utf8(&map).map_err(|err| IoError::new(std::io::ErrorKind::InvalidData, err))?;
+        Ok(InputFile { path, buffer: Buffer::Mapped(map) })
+    }
+
+    /// Wraps an in-memory string as if it were read from `path`.
+    pub fn from_string<P>(path: P, contents: String) -> InputFile
+        where P: AsRef<Path>
+    {
+        InputFile { path: path.as_ref().to_path_buf(), buffer: Buffer::Owned(contents) }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self.buffer {
+            Buffer::Owned(ref s) => s,
+            #[cfg(feature = "mmap")]
+            // SAFETY: Validated as UTF-8 once in `InputFile::map`, and the map is never written to.
+            Buffer::Mapped(ref map) => unsafe { str::from_utf8_unchecked(map) },
+        }
+    }
+
+    /// Iterates over each line in the file, with trailing whitespace removed.
+    /// Lines are paired with their 1-based line number.
+    pub fn lines(&self) -> Lines<'_> {
+        lines(self.as_str())
+    }
+
+    /// Parses each line in the file with `parse`.
+    /// A failure is wrapped with the file path, the line number and the text of the line,
+    /// and is a `Parse` error unless `parse` gave it a more specific kind.
+    pub fn parse_lines<'a, T, E, F>(&'a self, mut parse: F) -> Result<Vec<T>, AppErr>
+        where F: FnMut(&'a str) -> Result<T, E>,
+              E: Into<AppErr>
+    {
+        self.lines()
+            .map(|(number, line)| {
+                parse(line).map_err(parse_failure).with_context(|| {
+                    format!("failed to parse line {} of {}: {:?}", number, self.path.display(), line)
+                })
+            })
+            .collect()
+    }
+}
+
+impl AsRef<str> for InputFile {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Iterates over each line in `input`, with trailing whitespace removed.
+/// Lines are paired with their 1-based line number.
+pub fn lines(input: &str) -> Lines<'_> {
+    Lines { inner: input.lines().enumerate() }
+}
+
+/// Parses each line in `input` with `parse`.
+/// A failure is wrapped with the line number and the text of the line,
+/// and is a `Parse` error unless `parse` gave it a more specific kind.
+pub fn parse_lines<'a, T, E, F>(input: &'a str, mut parse: F) -> Result<Vec<T>, AppErr>
+    where F: FnMut(&'a str) -> Result<T, E>,
+          E: Into<AppErr>
+{
+    lines(input)
+        .map(|(number, line)| {
+            parse(line).map_err(parse_failure).with_context(|| format!("failed to parse line {}: {:?}", number, line))
+        })
+        .collect()
+}
+
+/// A line which could not be parsed, e.g. with a `&str` error, was not understood.
+fn parse_failure<E: Into<AppErr>>(err: E) -> AppErr {
+    let err = err.into();
+    match err.kind() {
+        ErrorKind::Other => err.with_kind(ErrorKind::Parse),
+        _                => err,
+    }
+}
+
+pub struct Lines<'a> {
+    inner: std::iter::Enumerate<str::Lines<'a>>,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(i, line)| (i + 1, line.trim_end()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod input_tests {
+    use super::*;
+    use std::io::ErrorKind;
+
+    fn write_temp(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("util-input-{}-{}", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn lines_are_numbered_from_one() {
+        let input = InputFile::from_string("test.txt", "abc\ndef\n\nghi".to_string());
+        let lines = input.lines().collect::<Vec<_>>();
+        assert_eq!(lines, vec![(1, "abc"), (2, "def"), (3, ""), (4, "ghi")]);
+    }
+
+    #[test]
+    fn lines_are_trimmed() {
+        let input = InputFile::from_string("test.txt", "abc  \r\ndef\t\r\n".to_string());
+        let lines = input.lines().collect::<Vec<_>>();
+        assert_eq!(lines, vec![(1, "abc"), (2, "def")]);
+    }
+
+    #[test]
+    fn lines_borrow_from_buffer() {
+        let input = InputFile::from_string("test.txt", "abc\ndef".to_string());
+        let (_, line) = input.lines().nth(1).unwrap();
+        let buffer = input.as_str().as_ptr() as usize;
+        assert_eq!(line.as_ptr() as usize, buffer + 4);
+    }
+
+    #[test]
+    fn parse_lines_success() {
+        let input = InputFile::from_string("test.txt", "1\n2\n3".to_string());
+        let values = input.parse_lines(|line| line.parse::<i32>()).unwrap();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn parse_lines_borrowed() {
+        let input = InputFile::from_string("test.txt", "a:1\nb:2".to_string());
+        let values = input.parse_lines(|line| line.split_once(':').ok_or("missing `:`")).unwrap();
+        assert_eq!(values, vec![("a", "1"), ("b", "2")]);
+    }
+
+    #[test]
+    fn parse_lines_failure_has_location() {
+        use crate::error::ErrorKind;
+        let input = InputFile::from_string("test.txt", "1\n2\nthree\n4".to_string());
+        let err = input.parse_lines(|line| line.parse::<i32>()).unwrap_err();
+        assert_eq!(err.to_string(), r#"failed to parse line 3 of test.txt: "three""#);
+        assert_eq!(err.kind(), ErrorKind::Parse);
+        assert_eq!(err.root_cause().to_string(), "[std::num::ParseIntError] invalid digit found in string");
+    }
+
+    #[test]
+    fn parse_str_lines_failure_has_location() {
+        let err = parse_lines("1\nxx", |line| line.parse::<i32>()).unwrap_err();
+        assert_eq!(err.to_string(), r#"failed to parse line 2: "xx""#);
+
+        let err = parse_lines("1\nxx", |line| line.parse::<i32>().map_err(|_| "not a number")).unwrap_err();
+        assert_eq!(err.kind(), crate::error::ErrorKind::Parse);
+        let err = parse_lines("1\nxx", |_| Err::<i32, _>(AppErr::validation("too big"))).unwrap_err();
+        assert_eq!(err.kind(), crate::error::ErrorKind::Validation);
+    }
+
+    #[test]
+    fn open_reads_file() {
+        let path = write_temp("open", "1\n2\n3\n");
+        let input = InputFile::open(&path).unwrap();
+        assert_eq!(input.path(), path.as_path());
+        assert_eq!(input.lines().map(|(_, l)| l).collect::<Vec<_>>(), vec!["1", "2", "3"]);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn open_missing_file() {
+        let err = InputFile::open("does-not-exist.txt").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn map_reads_file() {
+        let path = write_temp("map", "a b\nc d\n");
+        let input = InputFile::map(&path).unwrap();
+        assert_eq!(input.as_str(), "a b\nc d\n");
+        assert_eq!(input.lines().count(), 2);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn map_rejects_invalid_utf8() {
+        let path = std::env::temp_dir().join(format!("util-input-{}-invalid", std::process::id()));
+        fs::write(&path, [0xff, 0xfe, 0xfd]).unwrap();
+        let err = InputFile::map(&path).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,374 @@
+// Utility for strictly consuming iterables
+
+use std::any::type_name;
+use std::collections::VecDeque;
+use std::convert::TryInto;
+use std::fmt;
+use std::fmt::Write;
+use std::iter::Fuse;
+
+// Each variant names the element being consumed: either the name passed to
+// `expect_next` / `expect_last`, or the item type.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ConsumeIteratorError {
+    // Asked empty iterator for a single element.
+    IteratorEmpty(&'static str),
+    // Asked for last element on iterator with more than one
+    // element left.
+    IteratorNotEmpty(&'static str),
+    // Asked for an exact number of elements (expected, actual).
+    WrongLength(usize, usize),
+}
+
+impl fmt::Display for ConsumeIteratorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConsumeIteratorError::IteratorEmpty(element) =>
+                write!(f, "expected {} but no elements remained", element),
+            ConsumeIteratorError::IteratorNotEmpty(element) =>
+                write!(f, "expected {} to be the last element but more remained", element),
+            ConsumeIteratorError::WrongLength(expected, actual) =>
+                write!(f, "expected {} elements but found {}", expected, actual),
+        }
+    }
+}
+
+/// The result of `ConsumeIterator::single`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Single<T> {
+    Empty,
+    One(T),
+    Many,
+}
+
+pub trait ConsumeIterator: Iterator {
+    /// Takes the next item in the iterator, returning an
+    /// error if no items remain.
+    fn take_next(&mut self) -> Result<Self::Item, ConsumeIteratorError>;
+
+    /// Takes the next item in the iterator, returning an
+    /// error if no items remain before the operation or if
+    /// further items remain after the operation.
+    fn take_last(&mut self) -> Result<Self::Item, ConsumeIteratorError>;
+
+    /// As `take_next`, naming the element in any error.
+    fn expect_next(&mut self, element: &'static str) -> Result<Self::Item, ConsumeIteratorError>;
+
+    /// As `take_last`, naming the element in any error.
+    fn expect_last(&mut self, element: &'static str) -> Result<Self::Item, ConsumeIteratorError>;
+
+    /// Checks whether the iterator holds exactly one item.
+    /// Stops after reading at most two items.
+    fn single(self) -> Single<Self::Item>;
+
+    /// Takes the only item in the iterator, returning an
+    /// error if there are none or more than one.
+    fn exactly_one(self) -> Result<Self::Item, ConsumeIteratorError>;
+
+    /// Collects exactly `N` items into an array, returning
+    /// an error if the iterator holds more or fewer.
+    fn collect_array<const N: usize>(self) -> Result<[Self::Item; N], ConsumeIteratorError>;
+
+    /// Yields each item paired with the item after it.
+    /// The last item is paired with the first.
+    fn circular_pairs(self) -> CircularPairs<Self>
+        where Self: Sized, Self::Item: Clone;
+
+    /// Groups runs of equal, consecutive items into (item, count) pairs.
+    fn run_lengths(self) -> RunLengths<Self>
+        where Self: Sized, Self::Item: PartialEq;
+
+    /// Finds the minimum and maximum items in a single pass.
+    /// Returns None if the iterator is empty.
+    fn minmax(self) -> Option<(Self::Item, Self::Item)>
+        where Self::Item: PartialOrd + Clone;
+
+    /// Yields each overlapping window of `N` consecutive items.
+    fn array_windows<const N: usize>(self) -> ArrayWindows<Self, N>
+        where Self: Sized, Self::Item: Clone;
+
+    /// Formats each item and joins them with `separator`.
+    fn join(self, separator: &str) -> String
+        where Self::Item: fmt::Display;
+}
+
+impl<I> ConsumeIterator for I where I: Iterator {
+
+    fn take_next(&mut self) -> Result<Self::Item, ConsumeIteratorError> {
+        self.expect_next(type_name::<Self::Item>())
+    }
+
+    fn take_last(&mut self) -> Result<Self::Item, ConsumeIteratorError> {
+        self.expect_last(type_name::<Self::Item>())
+    }
+
+    fn expect_next(&mut self, element: &'static str) -> Result<Self::Item, ConsumeIteratorError> {
+        match self.next() {
+            Some(v) => Ok(v),
+            None    => Err(ConsumeIteratorError::IteratorEmpty(element)),
+        }
+    }
+
+    fn expect_last(&mut self, element: &'static str) -> Result<Self::Item, ConsumeIteratorError> {
+        let v = self.expect_next(element)?;
+        match self.next() {
+            Some(_) => Err(ConsumeIteratorError::IteratorNotEmpty(element)),
+            None    => Ok(v)
+        }
+    }
+
+    fn single(mut self) -> Single<Self::Item> {
+        match (self.next(), self.next()) {
+            (Some(_), Some(_)) => Single::Many,
+            (Some(v), None)    => Single::One(v),
+            (None, _)          => Single::Empty,
+        }
+    }
+
+    fn exactly_one(mut self) -> Result<Self::Item, ConsumeIteratorError> {
+        self.take_last()
+    }
+
+    fn collect_array<const N: usize>(self) -> Result<[Self::Item; N], ConsumeIteratorError> {
+        let items = self.collect::<Vec<_>>();
+        let actual = items.len();
+        items.try_into().map_err(|_| ConsumeIteratorError::WrongLength(N, actual))
+    }
+
+    fn circular_pairs(self) -> CircularPairs<Self>
+        where Self::Item: Clone
+    {
+        CircularPairs { source: self.fuse(), first: None, prev: None }
+    }
+
+    fn run_lengths(self) -> RunLengths<Self>
+        where Self::Item: PartialEq
+    {
+        RunLengths { source: self, pending: None }
+    }
+
+    fn minmax(mut self) -> Option<(Self::Item, Self::Item)>
+        where Self::Item: PartialOrd + Clone
+    {
+        let first = self.next()?;
+        let mut min = first.clone();
+        let mut max = first;
+        for v in self {
+            if v < min {
+                min = v;
+            }
+            else if v > max {
+                max = v;
+            }
+        }
+        Some((min, max))
+    }
+
+    fn array_windows<const N: usize>(self) -> ArrayWindows<Self, N>
+        where Self::Item: Clone
+    {
+        ArrayWindows { source: self, window: VecDeque::with_capacity(N) }
+    }
+
+    fn join(self, separator: &str) -> String
+        where Self::Item: fmt::Display
+    {
+        let mut result = String::new();
+        for (i, v) in self.enumerate() {
+            if i > 0 {
+                result.push_str(separator);
+            }
+            write!(result, "{}", v).unwrap();
+        }
+        result
+    }
+}
+
+pub struct CircularPairs<I: Iterator> {
+    source: Fuse<I>,
+    first: Option<I::Item>,
+    prev: Option<I::Item>,
+}
+
+impl<I> Iterator for CircularPairs<I>
+    where I: Iterator, I::Item: Clone
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.prev.is_none() {
+            // Keep a copy of the first element to pair with the last
+            let first = self.source.next()?;
+            self.first = Some(first.clone());
+            self.prev = Some(first);
+        }
+        let a = self.prev.take()?;
+        match self.source.next() {
+            Some(b) => {
+                self.prev = Some(b.clone());
+                Some((a, b))
+            },
+            // End of the input! Yield the last pair...
+            None => self.first.take().map(|b| (a, b)),
+        }
+    }
+}
+
+pub struct RunLengths<I: Iterator> {
+    source: I,
+    pending: Option<I::Item>,
+}
+
+impl<I> Iterator for RunLengths<I>
+    where I: Iterator, I::Item: PartialEq
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.pending.take().or_else(|| self.source.next())?;
+        let mut count = 1;
+        for next in self.source.by_ref() {
+            if next != item {
+                self.pending = Some(next);
+                break;
+            }
+            count += 1;
+        }
+        Some((item, count))
+    }
+}
+
+pub struct ArrayWindows<I: Iterator, const N: usize> {
+    source: I,
+    window: VecDeque<I::Item>,
+}
+
+impl<I, const N: usize> Iterator for ArrayWindows<I, N>
+    where I: Iterator, I::Item: Clone
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if N == 0 {
+            return None;
+        }
+        if self.window.len() == N {
+            self.window.pop_front();
+        }
+        while self.window.len() < N {
+            self.window.push_back(self.source.next()?);
+        }
+        self.window.iter().cloned().collect_array().ok()
+    }
+}
+
+/// Each pair of distinct items in `items`, in order: `(items[i], items[j])` for every `i < j`.
+pub fn pairs<T>(items: &[T]) -> impl Iterator<Item=(&T, &T)> {
+    items.iter()
+        .enumerate()
+        .flat_map(move |(i, a)| items[i + 1..].iter().map(move |b| (a, b)))
+}
+
+#[cfg(test)]
+mod consume_iterator_tests {
+    use super::*;
+
+    #[test]
+    fn take_next_names_item_type() {
+        let err = "".split_terminator(':').take_next().unwrap_err();
+        assert_eq!(err, ConsumeIteratorError::IteratorEmpty("&str"));
+        assert_eq!(err.to_string(), "expected &str but no elements remained");
+    }
+
+    #[test]
+    fn expect_next_names_element() {
+        let mut pair = "1-3 a".split(':');
+        assert_eq!(pair.expect_next("policy"), Ok("1-3 a"));
+        let err = pair.expect_next("password").unwrap_err();
+        assert_eq!(err.to_string(), "expected password but no elements remained");
+    }
+
+    #[test]
+    fn expect_last_names_element() {
+        let mut pair = "a:b:c".split(':');
+        assert_eq!(pair.expect_next("key"), Ok("a"));
+        let err = pair.expect_last("value").unwrap_err();
+        assert_eq!(err, ConsumeIteratorError::IteratorNotEmpty("value"));
+        assert_eq!(err.to_string(), "expected value to be the last element but more remained");
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(Vec::<i32>::new().into_iter().single(), Single::Empty);
+        assert_eq!(vec![1].into_iter().single(), Single::One(1));
+        assert_eq!(vec![1, 2, 3].into_iter().single(), Single::Many);
+    }
+
+    #[test]
+    fn exactly_one() {
+        assert_eq!(vec![1].into_iter().exactly_one(), Ok(1));
+        assert!(Vec::<i32>::new().into_iter().exactly_one().is_err());
+        assert!(vec![1, 2].into_iter().exactly_one().is_err());
+    }
+
+    #[test]
+    fn exactly_one_into_app_err() {
+        use crate::error::{AppErr, ErrorKind};
+        fn only(v: Vec<i32>) -> Result<i32, AppErr> {
+            Ok(v.into_iter().exactly_one()?)
+        }
+        assert_eq!(only(vec![]).unwrap_err().kind(), ErrorKind::Parse);
+    }
+
+    #[test]
+    fn collect_array() {
+        assert_eq!("1 2 3".split(' ').collect_array(), Ok(["1", "2", "3"]));
+        assert_eq!("1 2".split(' ').collect_array::<3>(), Err(ConsumeIteratorError::WrongLength(3, 2)));
+        assert_eq!("1 2 3 4".split(' ').collect_array::<3>(), Err(ConsumeIteratorError::WrongLength(3, 4)));
+    }
+
+    #[test]
+    fn circular_pairs() {
+        let pairs = vec![1, 2, 3].into_iter().circular_pairs().collect::<Vec<_>>();
+        assert_eq!(pairs, vec![(1, 2), (2, 3), (3, 1)]);
+        let pairs = vec![1].into_iter().circular_pairs().collect::<Vec<_>>();
+        assert_eq!(pairs, vec![(1, 1)]);
+        assert_eq!(Vec::<i32>::new().into_iter().circular_pairs().count(), 0);
+    }
+
+    #[test]
+    fn run_lengths() {
+        let runs = "aaabccdd".chars().run_lengths().collect::<Vec<_>>();
+        assert_eq!(runs, vec![('a', 3), ('b', 1), ('c', 2), ('d', 2)]);
+        assert_eq!("".chars().run_lengths().count(), 0);
+    }
+
+    #[test]
+    fn minmax() {
+        assert_eq!(vec![3, 1, 4, 1, 5].into_iter().minmax(), Some((1, 5)));
+        assert_eq!(vec![7].into_iter().minmax(), Some((7, 7)));
+        assert_eq!(Vec::<i32>::new().into_iter().minmax(), None);
+    }
+
+    #[test]
+    fn array_windows() {
+        let windows = (1..=5).array_windows::<3>().collect::<Vec<_>>();
+        assert_eq!(windows, vec![[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+        assert_eq!((1..=2).array_windows::<3>().count(), 0);
+        assert_eq!((1..=2).array_windows::<0>().count(), 0);
+    }
+
+    #[test]
+    fn pairs() {
+        let pairs = super::pairs(&[1, 2, 3]).collect::<Vec<_>>();
+        assert_eq!(pairs, vec![(&1, &2), (&1, &3), (&2, &3)]);
+        assert_eq!(super::pairs(&[1]).count(), 0);
+    }
+
+    #[test]
+    fn join() {
+        assert_eq!(vec!['A', 'B', 'C'].into_iter().join(""), "ABC");
+        assert_eq!(vec![1, 2, 3].into_iter().join(" -> "), "1 -> 2 -> 3");
+        assert_eq!(Vec::<i32>::new().into_iter().join(","), "");
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(feature = "mmap")]
+extern crate memmap2;
+extern crate chacha20poly1305;
+extern crate getrandom;
+
+pub mod answers;
+pub mod cancel;
+pub mod error;
+pub mod input;
+pub mod iter;
+pub mod json;
+pub mod memory;
+pub mod params;
+pub mod parse;
+pub mod solution;
+pub mod vault;
+
+pub use iter::{ ConsumeIterator, ConsumeIteratorError };
+
+// Utility for reading input files
+
+use std::path::Path;
+use std::io::{ BufRead, BufReader, Error as IoError, Read };
+use std::fs::File;
+
+use crate::error::{ AppErr, Context };
+use crate::input::InputFile;
+
+pub fn read_input<P>(input_path: P) -> Result<Vec<String>, IoError>
+    where P: AsRef<Path>
+{
+    let mut result = Vec::new();
+    
+    let file = File::open(input_path.as_ref())?;
+    let mut input = BufReader::new(file);
+
+    let mut line = String::new();
+    loop {
+        if input.read_line(&mut line)? == 0 {
+            break;
+        }
+        result.push(line.trim_end().to_string());
+        line.clear();
+    }
+    Ok(result)
+}
+
+/// Reads the file at `input_path` and parses each line with `parse`.
+/// Failures are reported with the file path, line number and line text.
+pub fn parse_input_lines<P, T, E, F>(input_path: P, parse: F) -> Result<Vec<T>, AppErr>
+    where P: AsRef<Path>,
+          F: FnMut(&str) -> Result<T, E>,
+          E: Into<AppErr>
+{
+    let input_path = input_path.as_ref();
+    let input = InputFile::open(input_path)
+        .with_context(|| format!("failed to read {}", input_path.display()))?;
+    input.parse_lines(parse)
+}
+
+pub fn read_input_to_string<P>(input_path: P) -> Result<String, IoError>
+    where P: AsRef<Path>
+{
+    let mut result = String::new();
+    let mut file = File::open(input_path.as_ref())?;
+    file.read_to_string(&mut result)?;
+    Ok(result)
+}
@@ -0,0 +1,165 @@
+// Utility for measuring the memory used by each phase of a solution
+
+use std::alloc::{ GlobalAlloc, Layout, System };
+use std::fmt;
+use std::sync::atomic::{ AtomicBool, AtomicUsize, Ordering };
+
+/// A global allocator which counts each allocation made through it, then defers to the system allocator.
+/// Install it in a binary to have the memory used by each phase reported:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: util::memory::CountingAllocator = util::memory::CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+fn record_allocation(size: usize) {
+    INSTALLED.store(true, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+fn record_free(size: usize) {
+    LIVE.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_free(layout.size());
+    }
+
+    /// Counted as a new allocation of `new_size` bytes, and freeing the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_allocation(new_size);
+            record_free(layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Whether `CountingAllocator` is the global allocator, and has counted an allocation.
+pub fn is_counting() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// The memory used by one phase of a solution.
+/// Allocations from every thread are counted, including any made by other threads during the phase.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: usize,
+    /// The total size of every allocation, including those which were freed.
+    pub bytes: usize,
+    /// The most memory in use at once, above what was in use when the phase started.
+    pub peak: usize,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} allocations, {} allocated, {} peak", self.allocations, format_bytes(self.bytes), format_bytes(self.peak))
+    }
+}
+
+/// Formats `bytes` in the largest binary unit in which it is at least 1.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// The counters at the start of a phase.
+/// Only one phase should be measured at a time, as starting a phase resets the peak.
+pub struct Phase {
+    allocations: usize,
+    bytes: usize,
+    live: usize,
+}
+
+impl Phase {
+    pub fn start() -> Phase {
+        let live = LIVE.load(Ordering::Relaxed);
+        PEAK.store(live, Ordering::Relaxed);
+        Phase {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: ALLOCATED.load(Ordering::Relaxed),
+            live,
+        }
+    }
+
+    /// The memory used since the phase started, unless allocations are not being counted.
+    pub fn finish(self) -> Option<Usage> {
+        if !is_counting() {
+            return None;
+        }
+        Some(Usage {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            bytes: ALLOCATED.load(Ordering::Relaxed) - self.bytes,
+            peak: PEAK.load(Ordering::Relaxed).saturating_sub(self.live),
+        })
+    }
+}
+
+#[cfg(test)]
+mod memory_tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn counts_allocations() {
+        let phase = Phase::start();
+        let freed = "0123456789".to_string();
+        drop(freed);
+        let values = Vec::<u64>::with_capacity(1000);
+        let usage = phase.finish().unwrap();
+        // Other tests may allocate at the same time
+        assert!(usage.allocations >= 2);
+        assert!(usage.bytes >= 8010);
+        assert!(usage.peak >= 8000);
+        assert_eq!(values.capacity(), 1000);
+    }
+
+    #[test]
+    fn formats_usage() {
+        assert_eq!(format_bytes(1000), "1000 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+        let usage = Usage { allocations: 3, bytes: 2048, peak: 10 };
+        assert_eq!(usage.to_string(), "3 allocations, 2.0 KiB allocated, 10 B peak");
+    }
+}
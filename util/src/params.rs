@@ -0,0 +1,132 @@
+// Utility for puzzle parameters which are given in the puzzle text rather than the input
+
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::AppErr;
+
+/// A named parameter of a puzzle, such as the number of rounds to run.
+/// Its default is the value given in the puzzle text, and examples often use a different value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub description: &'static str,
+}
+
+/// Values given for parameters, by name.
+pub type Overrides = Vec<(String, String)>;
+
+impl Param {
+    pub const fn new(name: &'static str, default: &'static str, description: &'static str) -> Param {
+        Param { name, default, description }
+    }
+
+    /// The value given for this parameter in the current run, or its default.
+    pub fn value(&self) -> String {
+        OVERRIDES.with(|overrides| {
+            overrides.borrow().iter()
+                .rfind(|(name, _)| name == self.name)
+                .map_or_else(|| self.default.to_string(), |(_, value)| value.clone())
+        })
+    }
+
+    /// Parses the value of this parameter.
+    pub fn parse<T>(&self) -> Result<T, AppErr>
+        where T: FromStr, T::Err: Display
+    {
+        let value = self.value();
+        value.parse().map_err(|err| AppErr::usage(&format!("invalid value `{}` for parameter `{}`: {}", value, self.name, err)))
+    }
+}
+
+thread_local! {
+    static OVERRIDES: RefCell<Overrides> = const { RefCell::new(Vec::new()) };
+}
+
+/// Runs `f` with `overrides` in place of the current thread's parameter values.
+pub fn with_overrides<T, F>(overrides: &[(String, String)], f: F) -> T
+    where F: FnOnce() -> T
+{
+    let previous = OVERRIDES.with(|current| current.replace(overrides.to_vec()));
+    let result = f();
+    OVERRIDES.with(|current| current.replace(previous));
+    result
+}
+
+/// The current thread's parameter values.
+/// A solution which starts its own threads should pass them on with `with_overrides`.
+pub fn current() -> Overrides {
+    OVERRIDES.with(|current| current.borrow().clone())
+}
+
+/// Parses a parameter value given as `<name>=<value>`.
+pub fn parse_override(text: &str) -> Result<(String, String), AppErr> {
+    match text.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => Ok((name.trim().to_string(), value.trim().to_string())),
+        _ => Err(AppErr::usage(&format!("expected `<name>=<value>`, found `{}`", text))),
+    }
+}
+
+/// Fails unless each of `overrides` names one of `params`.
+pub fn check_overrides(params: &[Param], overrides: &[(String, String)]) -> Result<(), AppErr> {
+    for (name, _) in overrides {
+        if !params.iter().any(|p| p.name == name) {
+            let names = params.iter().map(|p| p.name).collect::<Vec<_>>();
+            if names.is_empty() {
+                return Err(AppErr::usage(&format!("no parameter `{}`: the puzzle has no parameters", name)));
+            }
+            return Err(AppErr::usage(&format!("no parameter `{}`: expected one of {}", name, names.join(", "))));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod params_tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    const ROUNDS: Param = Param::new("rounds", "100", "The number of rounds to run");
+
+    fn overrides(pairs: &[(&str, &str)]) -> Overrides {
+        pairs.iter().map(|&(n, v)| (n.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn uses_default() {
+        assert_eq!(ROUNDS.value(), "100");
+        assert_eq!(ROUNDS.parse::<u32>().unwrap(), 100);
+    }
+
+    #[test]
+    fn uses_overrides() {
+        let overrides = overrides(&[("rounds", "4"), ("other", "x"), ("rounds", "5")]);
+        let rounds = with_overrides(&overrides, || {
+            assert_eq!(current().len(), 3);
+            ROUNDS.parse::<u32>()
+        });
+        assert_eq!(rounds.unwrap(), 5);
+        assert_eq!(ROUNDS.value(), "100");
+
+        let invalid = with_overrides(&self::overrides(&[("rounds", "many")]), || ROUNDS.parse::<u32>());
+        assert_eq!(invalid.unwrap_err().kind(), ErrorKind::Usage);
+    }
+
+    #[test]
+    fn parses_overrides() {
+        assert_eq!(parse_override("rounds = 4").unwrap(), ("rounds".to_string(), "4".to_string()));
+        assert_eq!(parse_override("list=1,2").unwrap(), ("list".to_string(), "1,2".to_string()));
+        assert!(parse_override("rounds").is_err());
+        assert!(parse_override("=4").is_err());
+    }
+
+    #[test]
+    fn checks_override_names() {
+        assert!(check_overrides(&[ROUNDS], &overrides(&[("rounds", "4")])).is_ok());
+        let err = check_overrides(&[ROUNDS], &overrides(&[("round", "4")])).unwrap_err();
+        assert_eq!(err.to_string(), "[Usage] no parameter `round`: expected one of rounds");
+        assert!(check_overrides(&[], &overrides(&[("rounds", "4")])).is_err());
+    }
+}
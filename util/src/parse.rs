@@ -176,6 +176,7 @@ impl<'a> Input<'a> {
     /// - a contiguous sequence of numeric characters
     /// - a single ascii punctuation character
     /// - a contiguous sequence of newline characters
+    ///
     /// All whitespace is ignored.
     pub fn next_token(self) -> ParseResult<'a, (TokenKind, &'a str)> {
         let source = self.remaining();
@@ -429,7 +430,7 @@ mod parse_tests {
     #[test]
     fn parser_chaining() { 
 
-        fn parse_2(input: Input) -> ParseResult<(&str, i32)> {
+        fn parse_2(input: Input<'_>) -> ParseResult<'_, (&str, i32)> {
             let (input, a) = input.parse_alpha()?;
             let (input, b) = input.parse_i32()?;
             Ok((input, (a, b)))
@@ -518,7 +519,7 @@ impl<'a> Input<'a> {
         Fi: Fn(Input<'a>) -> ParseResult<'a, I>,
         Fs: Fn(Input<'a>) -> ParseResult<'a, S>
     {
-        let mut input = self.clone();
+        let mut input = self;
         let mut results = Vec::new();
 
         loop {
@@ -560,7 +561,7 @@ impl<'a> Input<'a> {
     where
         Fi: Fn(Input<'a>) -> ParseResult<'a, I>
     {
-        let mut input = self.clone();
+        let mut input = self;
         let mut results = Vec::new();
 
         loop {
@@ -569,7 +570,7 @@ impl<'a> Input<'a> {
                     input = next;
                     results.push(val);
                 },
-                Err(_) if results.len() > 0 => {
+                Err(_) if !results.is_empty() => {
                     break;
                 },
                 Err(err) => {
@@ -583,9 +584,9 @@ impl<'a> Input<'a> {
 
     /// Parses each of the tokens in the input sequence in order.
     pub fn parse_token_sequence<'b>(self, tokens: impl IntoIterator<Item=&'b str>) -> ParseResult<'a, ()> {
-        let mut input = self.clone();
-        let mut tokens = tokens.into_iter();
-        while let Some(token) = tokens.next() {
+        let mut input = self;
+        let tokens = tokens.into_iter();
+        for token in tokens {
             let (next, (_, actual)) = input.next_token()?;
             if actual != token {
                 return Err(ParseErr::expected_token(input.snapshot(), token));
@@ -690,11 +691,11 @@ pub enum ParseErr<'a> {
 impl<'a> ParseErr<'a> {
     fn snapshot(&self) -> &InputSnapshot<'a> {
         match self { 
-            ParseErr::EndOfInput(snap) => &snap,
-            ParseErr::InvalidInput(snap, _) => &snap,
-            ParseErr::UnexpectedInput(snap, _) => &snap,
-            ParseErr::ExpectedSingle(snap, _) => &snap,
-            ParseErr::ExpectedMultiple(snap, _) => &snap,
+            ParseErr::EndOfInput(snap) => snap,
+            ParseErr::InvalidInput(snap, _) => snap,
+            ParseErr::UnexpectedInput(snap, _) => snap,
+            ParseErr::ExpectedSingle(snap, _) => snap,
+            ParseErr::ExpectedMultiple(snap, _) => snap,
         }
     }
 }
@@ -713,11 +714,11 @@ impl<'a> ParseErr<'a> {
     }
     
     pub fn expected_alpha(snapshot: InputSnapshot<'a>) -> ParseErr<'a> {
-        ParseErr::ExpectedSingle(snapshot, format!("alpha"))
+        ParseErr::ExpectedSingle(snapshot, "alpha".to_string())
     }
 
     pub fn expected_number(snapshot: InputSnapshot<'a>) -> ParseErr<'a> {
-        ParseErr::ExpectedSingle(snapshot, format!("number"))
+        ParseErr::ExpectedSingle(snapshot, "number".to_string())
     }
 
     pub fn expected_token(snapshot: InputSnapshot<'a>, token: &str) -> ParseErr<'a> {
@@ -725,15 +726,15 @@ impl<'a> ParseErr<'a> {
     }
 
     pub fn expected_end_of_input(snapshot: InputSnapshot<'a>) -> ParseErr<'a> {
-        ParseErr::ExpectedSingle(snapshot, format!("end of input"))
+        ParseErr::ExpectedSingle(snapshot, "end of input".to_string())
     }
 
     pub fn expected_newline(snapshot: InputSnapshot<'a>) -> ParseErr<'a> {
-        ParseErr::ExpectedSingle(snapshot, format!("newline"))
+        ParseErr::ExpectedSingle(snapshot, "newline".to_string())
     }
 
     pub fn combine(a: ParseErr<'a>, b: ParseErr<'a>) -> ParseErr<'a> {
-        let snapshot = a.snapshot().clone();
+        let snapshot = *a.snapshot();
         let mut errors = match a {
             ParseErr::ExpectedSingle(_, a) => vec![a],
             ParseErr::ExpectedMultiple(_, a) => a,
@@ -785,14 +786,14 @@ impl<'a> fmt::Display for ParseErr<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // "Display" version includes input text
         writeln!(f, "{:?}", self)?;
-        writeln!(f, "{}", input_snippet(&self.snapshot(), 20))?;
-        write!(f, "{}", input_caret(&self.snapshot(), 20))?;
+        writeln!(f, "{}", input_snippet(self.snapshot(), 20))?;
+        write!(f, "{}", input_caret(self.snapshot(), 20))?;
         Ok(())
     }
 }
 
 fn input_snippet(snap: &InputSnapshot, context: usize) -> String {
-    let snip_start = if snap.offset > context  { snap.offset - context } else { 0 };
+    let snip_start = snap.offset.saturating_sub(context);
     let snip_end = (snap.offset + context).min(snap.source.len() - 1);
     format!(
         "{}{}{}",
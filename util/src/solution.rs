@@ -0,0 +1,717 @@
+// Utility for structuring each day as a reusable solver
+
+use std::fmt;
+use std::path::Path;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use std::sync::mpsc::{ self, RecvTimeoutError };
+use std::thread;
+use std::time::{ Duration, Instant };
+
+use crate::answers::canonical;
+use crate::cancel::{ self, CancellationToken };
+use crate::error::{ AppErr, Context, ErrorKind, Exit };
+use crate::vault;
+use crate::memory::{ Phase, Usage };
+use crate::params::{ self, Param };
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    /// A picture, one string per row. E.g. letters drawn on a grid.
+    Grid(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(v)     => write!(f, "{}", v),
+            Answer::Str(s)     => write!(f, "{}", s),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_int {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Answer {
+                fn from(v: $type) -> Self {
+                    Answer::Int(v as i128)
+                }
+            }
+        )*
+    }
+}
+
+impl_answer_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl<'a> From<&'a str> for Answer {
+    fn from(s: &'a str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(rows: Vec<String>) -> Self {
+        Answer::Grid(rows)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(n: u32) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Part {}", self.number())
+    }
+}
+
+/// A solution to a single day's puzzle.
+/// The input is parsed once into a `Model`, which is shared by both parts.
+pub trait Solution: Sync {
+    /// The parsed puzzle input. May borrow from the input text.
+    type Model<'a>;
+
+    /// Values from the puzzle text which examples may change, read with `Param::parse`.
+    const PARAMS: &'static [Param] = &[];
+
+    /// Parts known not to finish within the default time budget, such as a brute force search.
+    /// `aoc verify` reports their timeouts without failing.
+    const SLOW_PARTS: &'static [Part] = &[];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr>;
+
+    fn part1(&self, model: &Self::Model<'_>) -> Result<Answer, AppErr>;
+
+    fn part2(&self, model: &Self::Model<'_>) -> Result<Answer, AppErr>;
+}
+
+/// The answer (or failure) for each part which was run.
+pub type PartResults = Vec<(Part, Result<Answer, AppErr>)>;
+
+/// How long each phase of a solve took, and the memory it used.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    /// The time taken by each part, in the order the parts were run.
+    pub parts: Vec<Duration>,
+    /// The memory used by parsing, if `memory::CountingAllocator` is installed.
+    pub parse_memory: Option<Usage>,
+    /// The memory used by each part, as for `parse_memory`.
+    pub part_memory: Vec<Option<Usage>>,
+}
+
+/// A phase of a solve which has finished, passed to the caller of `Solver::solve_each` as soon as it is done.
+#[derive(Debug)]
+pub enum Step {
+    Parsed { time: Duration, memory: Option<Usage> },
+    Solved { part: Part, answer: Result<Answer, AppErr>, time: Duration, memory: Option<Usage> },
+}
+
+/// Object-safe form of `Solution`, implemented for every `Solution`.
+pub trait Solver: Sync {
+    /// Parses `input` and then solves each of `parts` in order.
+    /// Fails only if the input could not be parsed, with an error of kind `Parse` unless parsing ran out of time.
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<PartResults, AppErr> {
+        self.solve_timed(input, parts).map(|(results, _)| results)
+    }
+
+    /// As `solve`, also timing the parse and each part.
+    fn solve_timed(&self, input: &str, parts: &[Part]) -> Result<(PartResults, Timings), AppErr> {
+        let mut results = Vec::with_capacity(parts.len());
+        let mut timings = Timings::default();
+        self.solve_each(input, parts, &mut |step| match step {
+            Step::Parsed { time, memory } => {
+                timings.parse = time;
+                timings.parse_memory = memory;
+            }
+            Step::Solved { part, answer, time, memory } => {
+                results.push((part, answer));
+                timings.parts.push(time);
+                timings.part_memory.push(memory);
+            }
+        })?;
+        Ok((results, timings))
+    }
+
+    /// As `solve_timed`, passing each phase to `on_step` as it finishes.
+    /// `on_step` is not called if the input could not be parsed.
+    fn solve_each(&self, input: &str, parts: &[Part], on_step: &mut dyn FnMut(Step)) -> Result<(), AppErr>;
+
+    /// The parameters of the puzzle, from `Solution::PARAMS`.
+    fn params(&self) -> &'static [Param];
+
+    /// The parts known to run out of time, from `Solution::SLOW_PARTS`.
+    fn slow_parts(&self) -> &'static [Part];
+}
+
+impl<S> Solver for S where S: Solution {
+    fn solve_each(&self, input: &str, parts: &[Part], on_step: &mut dyn FnMut(Step)) -> Result<(), AppErr> {
+        let phase = Phase::start();
+        let start = Instant::now();
+        // Whatever went wrong, e.g. a `&str` error or a failed validation, the input was not understood
+        let model = self.parse(input).map_err(|err| match err.kind() {
+            ErrorKind::Timeout => err,
+            _                  => err.with_kind(ErrorKind::Parse),
+        })?;
+        on_step(Step::Parsed { time: start.elapsed(), memory: phase.finish() });
+
+        for &part in parts {
+            let phase = Phase::start();
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => self.part1(&model),
+                Part::Two => self.part2(&model),
+            };
+            on_step(Step::Solved { part, answer, time: start.elapsed(), memory: phase.finish() });
+        }
+        Ok(())
+    }
+
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn slow_parts(&self) -> &'static [Part] {
+        S::SLOW_PARTS
+    }
+}
+
+/// How long a phase which has run out of time is given to notice its cancelled token, before it is abandoned.
+const GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+/// The number of solver threads started by `solve_with_budget` which have not yet finished.
+static RUNNING_SOLVERS: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts a solver thread as running until it is dropped, even if the solver panics.
+struct RunningSolver;
+
+impl RunningSolver {
+    fn start() -> RunningSolver {
+        RUNNING_SOLVERS.fetch_add(1, Ordering::SeqCst);
+        RunningSolver
+    }
+}
+
+impl Drop for RunningSolver {
+    fn drop(&mut self) {
+        RUNNING_SOLVERS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// The number of solver threads started by `solve_with_budget` which are still running,
+/// including those which were abandoned and have not yet noticed that they were cancelled.
+pub fn running_solvers() -> usize {
+    RUNNING_SOLVERS.load(Ordering::SeqCst)
+}
+
+/// As `Solver::solve_timed`, allowing parsing and each part at most `budget`.
+/// The solver runs on another thread, with this thread's parameter values,
+/// and with a `CancellationToken` which is cancelled when a phase runs out of time.
+/// A phase which does not stop within a grace period is abandoned, and left to run in the background
+/// (counted by `running_solvers` until it finishes): the part fails with a timeout error, as does each part after it.
+pub fn solve_with_budget(registration: &Registration, input: String, parts: &[Part], budget: Duration) -> Result<(PartResults, Timings), AppErr> {
+    let solver = registration.solver;
+    let token = CancellationToken::with_budget(budget);
+    let (sender, receiver) = mpsc::channel();
+    {
+        let token = token.clone();
+        let parts = parts.to_vec();
+        let overrides = params::current();
+        let running = RunningSolver::start();
+        thread::Builder::new()
+            .name(format!("{} day {}", registration.year, registration.day))
+            .spawn(move || params::with_overrides(&overrides, || cancel::with_token(&token, || {
+                let _running = running;
+                let parsed = solver.solve_each(&input, &parts, &mut |step| {
+                    token.restart(budget);
+                    let _ = sender.send(Ok(step));
+                });
+                if let Err(err) = parsed {
+                    let _ = sender.send(Err(err));
+                }
+            })))
+            .context("failed to start solver thread")?;
+    }
+
+    let mut results = Vec::with_capacity(parts.len());
+    let mut timings = Timings::default();
+    let mut started = Instant::now();
+    let mut parsed = false;
+    loop {
+        let step = match receiver.recv_timeout(budget.saturating_add(GRACE_PERIOD)) {
+            Ok(step) => step?,
+            // The solver finished, or panicked
+            Err(RecvTimeoutError::Disconnected) if parsed && results.len() == parts.len() => break,
+            Err(RecvTimeoutError::Disconnected) => return Err(AppErr::new("Solver", "the solver thread panicked")),
+            Err(RecvTimeoutError::Timeout) => {
+                token.cancel();
+                if !parsed {
+                    return Err(AppErr::timeout(&format!("abandoned parsing after it ran over its time budget of {:?}", budget)));
+                }
+                let abandoned = parts[results.len()];
+                results.push((abandoned, Err(AppErr::timeout(&format!("abandoned after it ran over its time budget of {:?}", budget)))));
+                timings.parts.push(started.elapsed());
+                timings.part_memory.push(None);
+                for &part in &parts[results.len()..] {
+                    results.push((part, Err(AppErr::timeout(&format!("not run, as {} was abandoned", abandoned)))));
+                    timings.parts.push(Duration::ZERO);
+                    timings.part_memory.push(None);
+                }
+                break;
+            }
+        };
+        match step {
+            Step::Parsed { time, memory } => {
+                parsed = true;
+                timings.parse = time;
+                timings.parse_memory = memory;
+            }
+            Step::Solved { part, answer, time, memory } => {
+                results.push((part, answer));
+                timings.parts.push(time);
+                timings.part_memory.push(memory);
+            }
+        }
+        started = Instant::now();
+    }
+    Ok((results, timings))
+}
+
+/// A solver for the puzzle on the given year and day.
+/// Each day's library exposes one of these as `SOLUTION`.
+#[derive(Copy, Clone)]
+pub struct Registration {
+    pub year: u32,
+    pub day: u32,
+    pub solver: &'static dyn Solver,
+}
+
+impl fmt::Debug for Registration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Registration({} day {})", self.year, self.day)
+    }
+}
+
+/// The name of the implementation of a day which is registered with `Registry::register`.
+pub const MAIN_IMPLEMENTATION: &str = "main";
+
+/// A collection of registered solvers, ordered by year and day.
+/// A day may have alternative implementations, which are checked against its main implementation.
+#[derive(Debug, Default)]
+pub struct Registry {
+    entries: Vec<Registration>,
+    alternatives: Vec<(&'static str, Registration)>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry { entries: Vec::new(), alternatives: Vec::new() }
+    }
+
+    /// Adds a solver to the registry, replacing any existing solver for the same day.
+    pub fn register(&mut self, registration: Registration) {
+        let key = (registration.year, registration.day);
+        match self.entries.binary_search_by_key(&key, |r| (r.year, r.day)) {
+            Ok(i)  => self.entries[i] = registration,
+            Err(i) => self.entries.insert(i, registration),
+        }
+    }
+
+    /// Adds another implementation of a day under `name`, replacing any existing implementation of that name.
+    pub fn register_alternative(&mut self, name: &'static str, registration: Registration) {
+        let key = (registration.year, registration.day, name);
+        match self.alternatives.binary_search_by_key(&key, |&(n, r)| (r.year, r.day, n)) {
+            Ok(i)  => self.alternatives[i] = (name, registration),
+            Err(i) => self.alternatives.insert(i, (name, registration)),
+        }
+    }
+
+    pub fn find(&self, year: u32, day: u32) -> Option<&Registration> {
+        self.entries.iter().find(|r| r.year == year && r.day == day)
+    }
+
+    /// Finds the implementation of a day called `name`, which may be `MAIN_IMPLEMENTATION`.
+    pub fn find_implementation(&self, year: u32, day: u32, name: &str) -> Option<&Registration> {
+        self.implementations(year, day).into_iter()
+            .find(|&(n, _)| n == name)
+            .map(|(_, r)| r)
+    }
+
+    /// Each implementation of a day with its name, starting with the main implementation.
+    pub fn implementations(&self, year: u32, day: u32) -> Vec<(&'static str, &Registration)> {
+        let main = self.find(year, day).map(|r| (MAIN_IMPLEMENTATION, r));
+        let alternatives = self.alternatives.iter()
+            .filter(|(_, r)| r.year == year && r.day == day)
+            .map(|(n, r)| (*n, r));
+        main.into_iter().chain(alternatives).collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=&Registration> {
+        self.entries.iter()
+    }
+
+    /// Each year with at least one registered solver.
+    pub fn years(&self) -> Vec<u32> {
+        let mut years = self.entries.iter().map(|r| r.year).collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+}
+
+/// Solves both parts of `registration` against the input file at `input_path`,
+/// printing each answer.
+pub fn run_file<P>(registration: &Registration, input_path: P) -> Result<(), AppErr>
+    where P: AsRef<Path>
+{
+    run_parts(registration, input_path, &Part::ALL)
+}
+
+/// As `run_file`, solving only the given `parts`.
+pub fn run_parts<P>(registration: &Registration, input_path: P, parts: &[Part]) -> Result<(), AppErr>
+    where P: AsRef<Path>
+{
+    let input_path = input_path.as_ref();
+    let input = vault::read_input(input_path)
+        .with_context(|| format!("failed to read {}", input_path.display()))?;
+    let results = registration.solver.solve(input.as_str(), parts)
+        .with_context(|| format!("failed to parse {}", input_path.display()))?;
+    for (part, answer) in results {
+        let answer = answer.with_context(|| format!("failed to solve {}", part))?;
+        match answer {
+            Answer::Grid(_) => println!("{}:\n{}", part, answer),
+            _               => println!("{}: {}", part, answer),
+        }
+    }
+    Ok(())
+}
+
+/// Entry point for a day's binary: solves both parts against `input.txt`, or the encrypted `input.txt.enc`.
+pub fn main(registration: &Registration) -> Exit {
+    run_file(registration, "input.txt").into()
+}
+
+/// Solves `input`, panicking unless each part gives the expected answer.
+/// Answers are compared as they are recorded in `answers.txt`.
+pub fn assert_example(registration: &Registration, input: &str, expected: &[(Part, Answer)]) {
+    let Registration { year, day, .. } = *registration;
+    let parts = expected.iter().map(|&(part, _)| part).collect::<Vec<_>>();
+    let results = match registration.solver.solve(input, &parts) {
+        Ok(results) => results,
+        Err(err)    => panic!("{} day {}: failed to parse example: {}", year, day, err),
+    };
+    for ((part, answer), (_, expected)) in results.into_iter().zip(expected) {
+        match answer {
+            Ok(answer) => assert_eq!(canonical(&answer), canonical(expected), "{} day {} {}", year, day, part),
+            Err(err)   => panic!("{} day {} {}: failed to solve example: {}", year, day, part, err),
+        }
+    }
+}
+
+/// Checks the day's `SOLUTION`, which must be in scope, against an example from the puzzle.
+///
+/// Parameters which the example changes from the puzzle's defaults are given first:
+///
+/// ```ignore
+/// aoc_example!("+1\n-2\n+3\n+1", part1 = 3, part2 = 2);
+/// aoc_example!(include_str!("../tests/fixtures/part2-1.txt"), params(workers = 2, base_duration = 0), part2 = 15);
+/// ```
+#[macro_export]
+macro_rules! aoc_example {
+    ($input:expr, params($($name:ident = $value:expr),* $(,)*), $($answers:tt)*) => {
+        $crate::params::with_overrides(&[$((stringify!($name).to_string(), $value.to_string())),*], || {
+            $crate::aoc_example!($input, $($answers)*)
+        })
+    };
+    ($input:expr, part1 = $part1:expr, part2 = $part2:expr $(,)*) => {
+        $crate::solution::assert_example(&SOLUTION, $input, &[
+            ($crate::solution::Part::One, $crate::solution::Answer::from($part1)),
+            ($crate::solution::Part::Two, $crate::solution::Answer::from($part2)),
+        ])
+    };
+    ($input:expr, part1 = $part1:expr $(,)*) => {
+        $crate::solution::assert_example(&SOLUTION, $input, &[
+            ($crate::solution::Part::One, $crate::solution::Answer::from($part1)),
+        ])
+    };
+    ($input:expr, part2 = $part2:expr $(,)*) => {
+        $crate::solution::assert_example(&SOLUTION, $input, &[
+            ($crate::solution::Part::Two, $crate::solution::Answer::from($part2)),
+        ])
+    };
+}
+
+#[cfg(test)]
+mod solution_tests {
+    use super::*;
+
+    // Sums the numbers on each line, and then the digits of each number
+    struct Example;
+
+    impl Solution for Example {
+        type Model<'a> = Vec<&'a str>;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+            Ok(input.lines().collect())
+        }
+
+        fn part1(&self, model: &Self::Model<'_>) -> Result<Answer, AppErr> {
+            let mut sum = 0;
+            for line in model {
+                sum += line.parse::<i32>()?;
+            }
+            Ok(sum.into())
+        }
+
+        fn part2(&self, model: &Self::Model<'_>) -> Result<Answer, AppErr> {
+            let sum = model.iter()
+                .flat_map(|line| line.chars())
+                .filter_map(|c| c.to_digit(10))
+                .sum::<u32>();
+            Ok(sum.into())
+        }
+    }
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::from(-12).to_string(), "-12");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("ABC").to_string(), "ABC");
+        assert_eq!(Answer::from(vec!["#.".to_string(), ".#".to_string()]).to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn part_numbers() {
+        assert_eq!(Part::from_number(1), Some(Part::One));
+        assert_eq!(Part::from_number(3), None);
+        assert_eq!(Part::Two.to_string(), "Part 2");
+    }
+
+    #[test]
+    fn solver_runs_requested_parts() {
+        let results = Example.solve("12\n34", &[Part::Two]).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, Part::Two);
+        assert_eq!(results[0].1.as_ref().unwrap(), &Answer::Int(10));
+
+        let results = Example.solve("12\n34", &Part::ALL).unwrap();
+        assert_eq!(results[0].1.as_ref().unwrap(), &Answer::Int(46));
+    }
+
+    #[test]
+    fn solver_times_each_part() {
+        let (results, timings) = Example.solve_timed("12\n34", &[Part::Two, Part::One]).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(timings.parts.len(), 2);
+        // The counting allocator is installed by the memory tests
+        assert!(timings.parse_memory.unwrap().allocations >= 1);
+        assert_eq!(timings.part_memory.len(), 2);
+    }
+
+    #[test]
+    fn solver_reports_part_failure() {
+        let results = Example.solve("12\nxx", &Part::ALL).unwrap();
+        assert!(results[0].1.is_err());
+        assert_eq!(results[1].1.as_ref().unwrap(), &Answer::Int(3));
+    }
+
+    // Fails to parse with an error of no particular kind
+    struct Unparseable;
+
+    impl Solution for Unparseable {
+        type Model<'a> = ();
+
+        fn parse<'a>(&self, _input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+            Err("Empty input".into())
+        }
+
+        fn part1(&self, _model: &Self::Model<'_>) -> Result<Answer, AppErr> {
+            unreachable!()
+        }
+
+        fn part2(&self, _model: &Self::Model<'_>) -> Result<Answer, AppErr> {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn solver_fails_to_parse_with_parse_kind() {
+        let err = Unparseable.solve("", &Part::ALL).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Parse);
+        assert_eq!(err.kind().exit_code(), 65);
+    }
+
+    // Part 1 stops when it runs out of time, part 2 never stops
+    struct Endless;
+
+    impl Solution for Endless {
+        type Model<'a> = ();
+
+        fn parse<'a>(&self, _input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+            Ok(())
+        }
+
+        fn part1(&self, _model: &Self::Model<'_>) -> Result<Answer, AppErr> {
+            loop {
+                cancel::check()?;
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+        fn part2(&self, _model: &Self::Model<'_>) -> Result<Answer, AppErr> {
+            loop {
+                thread::sleep(Duration::from_millis(10));
+            }
+        }
+    }
+
+    #[test]
+    fn budget_allows_quick_parts() {
+        let registration = Registration { year: 2000, day: 1, solver: &Example };
+        let (results, timings) = solve_with_budget(&registration, "12\n34".to_string(), &Part::ALL, Duration::from_secs(60)).unwrap();
+        assert_eq!(results[0].1.as_ref().unwrap(), &Answer::Int(46));
+        assert_eq!(results[1].1.as_ref().unwrap(), &Answer::Int(10));
+        assert_eq!(timings.parts.len(), 2);
+        assert!(solve_with_budget(&registration, String::new(), &Part::ALL, Duration::from_secs(60)).is_ok());
+    }
+
+    #[test]
+    fn budget_cancels_long_parts() {
+        let registration = Registration { year: 2000, day: 2, solver: &Endless };
+        let (results, timings) = solve_with_budget(&registration, String::new(), &[Part::One, Part::Two, Part::One], Duration::from_millis(50)).unwrap();
+        let kinds = results.iter().map(|(_, r)| r.as_ref().unwrap_err().kind()).collect::<Vec<_>>();
+        assert_eq!(kinds, [ErrorKind::Timeout; 3]);
+        assert!(results[0].1.as_ref().unwrap_err().to_string().contains("cancelled"));
+        assert!(results[1].1.as_ref().unwrap_err().to_string().contains("abandoned"));
+        assert_eq!(results[2].1.as_ref().unwrap_err().to_string(), "[Timeout] not run, as Part 2 was abandoned");
+        assert_eq!(timings.parts.len(), 3);
+        assert!(timings.parts[0] < GRACE_PERIOD);
+        // Part 2 is still running in the background
+        assert!(running_solvers() >= 1);
+    }
+
+    const SOLUTION: Registration = Registration { year: 2000, day: 1, solver: &Example };
+
+    #[test]
+    fn example_macro() {
+        aoc_example!("12\n34", part1 = 46, part2 = 10);
+        aoc_example!("12\n34", part2 = 10,);
+        aoc_example!("5", part1 = "5");
+    }
+
+    mod scaled {
+        use super::*;
+
+        const FACTOR: Param = Param::new("factor", "1", "What to multiply each answer by");
+
+        // As `Example`, with each answer multiplied by `FACTOR`
+        struct Scaled;
+
+        impl Solution for Scaled {
+            type Model<'a> = Vec<&'a str>;
+
+            const PARAMS: &'static [Param] = &[FACTOR];
+
+            fn parse<'a>(&self, input: &'a str) -> Result<Self::Model<'a>, AppErr> {
+                Example.parse(input)
+            }
+
+            fn part1(&self, model: &Self::Model<'_>) -> Result<Answer, AppErr> {
+                match Example.part1(model)? {
+                    Answer::Int(n) => Ok((n * FACTOR.parse::<i128>()?).into()),
+                    answer => Ok(answer),
+                }
+            }
+
+            fn part2(&self, model: &Self::Model<'_>) -> Result<Answer, AppErr> {
+                Example.part2(model)
+            }
+        }
+
+        const SOLUTION: Registration = Registration { year: 2000, day: 3, solver: &Scaled };
+
+        #[test]
+        fn example_macro_sets_params() {
+            assert_eq!(SOLUTION.solver.params(), &[FACTOR]);
+            aoc_example!("12\n34", part1 = 46);
+            aoc_example!("12\n34", params(factor = 2), part1 = 92, part2 = 10);
+            aoc_example!("12\n34", params(factor = "-1",), part1 = -46);
+        }
+
+        #[test]
+        fn budget_keeps_params() {
+            let overrides = [("factor".to_string(), "3".to_string())];
+            let (results, _) = params::with_overrides(&overrides, || {
+                solve_with_budget(&SOLUTION, "12\n34".to_string(), &[Part::One], Duration::from_secs(60))
+            }).unwrap();
+            assert_eq!(results[0].1.as_ref().unwrap(), &Answer::Int(138));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "2000 day 1 Part 1")]
+    fn example_macro_reports_wrong_answer() {
+        aoc_example!("12\n34", part1 = 45);
+    }
+
+    #[test]
+    fn registry_is_ordered() {
+        let mut registry = Registry::new();
+        registry.register(Registration { year: 2023, day: 2, solver: &Example });
+        registry.register(Registration { year: 2020, day: 8, solver: &Example });
+        registry.register(Registration { year: 2023, day: 1, solver: &Example });
+        registry.register(Registration { year: 2023, day: 1, solver: &Example });
+        let days = registry.iter().map(|r| (r.year, r.day)).collect::<Vec<_>>();
+        assert_eq!(days, vec![(2020, 8), (2023, 1), (2023, 2)]);
+        assert_eq!(registry.years(), vec![2020, 2023]);
+        assert!(registry.find(2020, 8).is_some());
+        assert!(registry.find(2020, 9).is_none());
+    }
+
+    #[test]
+    fn registry_holds_alternatives() {
+        let mut registry = Registry::new();
+        registry.register(Registration { year: 2023, day: 2, solver: &Example });
+        registry.register_alternative("b", Registration { year: 2023, day: 2, solver: &Example });
+        registry.register_alternative("a", Registration { year: 2023, day: 2, solver: &Example });
+        registry.register_alternative("a", Registration { year: 2023, day: 2, solver: &Example });
+        registry.register_alternative("a", Registration { year: 2023, day: 3, solver: &Example });
+        let names = registry.implementations(2023, 2).iter().map(|&(n, _)| n).collect::<Vec<_>>();
+        assert_eq!(names, vec![MAIN_IMPLEMENTATION, "a", "b"]);
+        assert_eq!(registry.iter().count(), 1);
+        assert!(registry.find_implementation(2023, 2, "b").is_some());
+        assert!(registry.find_implementation(2023, 2, MAIN_IMPLEMENTATION).is_some());
+        assert!(registry.find_implementation(2023, 2, "c").is_none());
+        assert!(registry.implementations(2023, 4).is_empty());
+    }
+}
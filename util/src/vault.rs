@@ -0,0 +1,422 @@
+// Keeps puzzle inputs encrypted in the repository, as `input.txt.enc` in place of each `input.txt`.
+
+use std::fmt;
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use chacha20poly1305::aead::{ Aead, KeyInit };
+use chacha20poly1305::{ ChaCha20Poly1305, Nonce };
+
+use crate::error::{ AppErr, Context, ErrorKind };
+use crate::input::InputFile;
+
+/// Holds the key in hex, in place of the key file.
+pub const KEY_VAR: &str = "AOC_VAULT_KEY";
+
+/// Overrides the path of the key file.
+pub const KEY_FILE_VAR: &str = "AOC_VAULT_KEY_FILE";
+
+/// Starts every encrypted input, followed by the nonce and then the ciphertext.
+const MAGIC: &[u8] = b"aoc-vault-1\n";
+const NONCE_LEN: usize = 12;
+
+/// A ChaCha20-Poly1305 key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+// Keep the key out of any debug output
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Key(..)")
+    }
+}
+
+impl Key {
+    pub fn generate() -> Result<Key, AppErr> {
+        let mut key = [0; 32];
+        random(&mut key)?;
+        Ok(Key(key))
+    }
+
+    /// Parses a key written as 64 hex digits.
+    pub fn parse(text: &str) -> Result<Key, AppErr> {
+        let invalid = || AppErr::new("Vault", "invalid key: expected 64 hex digits").with_kind(ErrorKind::Parse);
+        let text = text.trim();
+        if text.len() != 64 || !text.is_ascii() {
+            return Err(invalid());
+        }
+        let mut key = [0; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&text[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+        }
+        Ok(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, AppErr> {
+        let mut nonce = [0; NONCE_LEN];
+        random(&mut nonce)?;
+        let ciphertext = ChaCha20Poly1305::new((&self.0).into())
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map_err(|_| AppErr::new("Vault", "failed to encrypt"))?;
+        Ok([MAGIC, &nonce, &ciphertext].concat())
+    }
+
+    /// Decrypts data written by `encrypt`, failing if it was encrypted with another key or has been changed.
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, AppErr> {
+        let rest = data.strip_prefix(MAGIC)
+            .filter(|rest| rest.len() >= NONCE_LEN)
+            .ok_or_else(|| AppErr::new("Vault", "not an encrypted input").with_kind(ErrorKind::Parse))?;
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        ChaCha20Poly1305::new((&self.0).into())
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| AppErr::new("Vault", "failed to decrypt: the key is wrong, or the file has been changed"))
+    }
+}
+
+fn random(bytes: &mut [u8]) -> Result<(), AppErr> {
+    getrandom::getrandom(bytes).map_err(|err| AppErr::from_display("Vault", &err))
+}
+
+/// Where the key was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeySource {
+    Env,
+    File(PathBuf),
+}
+
+/// The key file: `$AOC_VAULT_KEY_FILE`, or `aoc/vault.key` in the user's config directory.
+pub fn key_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(KEY_FILE_VAR) {
+        return Some(PathBuf::from(path));
+    }
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("aoc").join("vault.key"))
+}
+
+/// The key from `$AOC_VAULT_KEY` if it is set, otherwise from the key file if there is one.
+pub fn find_key() -> Result<Option<(Key, KeySource)>, AppErr> {
+    if let Some(hex) = std::env::var(KEY_VAR).ok().filter(|hex| !hex.is_empty()) {
+        let key = Key::parse(&hex).with_context(|| format!("failed to read {}", KEY_VAR))?;
+        return Ok(Some((key, KeySource::Env)));
+    }
+    match key_path() {
+        Some(path) if path.exists() => {
+            let text = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            let key = Key::parse(&text).with_context(|| format!("failed to read {}", path.display()))?;
+            Ok(Some((key, KeySource::File(path))))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// As `find_key`, failing if there is no key.
+pub fn load_key() -> Result<(Key, KeySource), AppErr> {
+    find_key()?.ok_or_else(|| {
+        let file = key_path().map_or("a key file".to_string(), |path| path.display().to_string());
+        AppErr::usage(&format!("no key for encrypted inputs: set {} or write the key to {}", KEY_VAR, file))
+    })
+}
+
+/// Writes `key` to the key file at `path`, readable only by the user.
+pub fn save_key(path: &Path, key: &Key) -> Result<(), AppErr> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    // Replace any old key only once the new one is written
+    let partial = path.with_extension("partial");
+    let write = |partial: &Path| -> std::io::Result<()> {
+        use std::io::Write;
+        let mut file = options.open(partial)?;
+        writeln!(file, "{}", key.to_hex())
+    };
+    write(&partial)
+        .with_context(|| format!("failed to write {}", partial.display()))?;
+    fs::rename(&partial, path)
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// The encrypted input kept in place of `path`, e.g. `input.txt.enc` for `input.txt`.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".enc");
+    PathBuf::from(name)
+}
+
+pub fn is_encrypted(path: &Path) -> bool {
+    path.extension().is_some_and(|e| e == "enc")
+}
+
+/// The input to read for `path`: its encrypted input if `path` does not exist but that does, otherwise `path`.
+pub fn resolve_input(path: &Path) -> PathBuf {
+    let encrypted = encrypted_path(path);
+    if !path.exists() && encrypted.exists() { encrypted } else { path.to_path_buf() }
+}
+
+/// Reads the input at `path`, or its encrypted input in its place as for `resolve_input`,
+/// decrypting it with the vault key if it is encrypted.
+pub fn read_input(path: &Path) -> Result<InputFile, AppErr> {
+    let path = resolve_input(path);
+    if !is_encrypted(&path) {
+        return Ok(InputFile::open(&path)?);
+    }
+    let (key, _) = load_key()?;
+    let text = decrypt_file(&key, &path)?;
+    Ok(InputFile::from_string(path, text))
+}
+
+/// Decrypts the input at `path`.
+pub fn decrypt_file(key: &Key, path: &Path) -> Result<String, AppErr> {
+    let data = fs::read(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let plaintext = key.decrypt(&data)
+        .with_context(|| format!("failed to decrypt {}", path.display()))?;
+    String::from_utf8(plaintext)
+        .map_err(|_| AppErr::new("Vault", &format!("{} is not UTF-8 text", path.display())))
+}
+
+/// Encrypts the text input at `source` to `path`.
+pub fn encrypt_file(key: &Key, source: &Path, path: &Path) -> Result<(), AppErr> {
+    let text = fs::read_to_string(source)
+        .with_context(|| format!("failed to read {}", source.display()))?;
+    write_atomic(path, &key.encrypt(text.as_bytes())?)
+}
+
+/// Writes to a temporary file first, so that a failed write never replaces an input.
+fn write_atomic(path: &Path, data: &[u8]) -> Result<(), AppErr> {
+    let partial = path.with_extension("partial");
+    fs::write(&partial, data)
+        .with_context(|| format!("failed to write {}", partial.display()))?;
+    fs::rename(&partial, path)
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Each `<year>/day<n>/input.txt.enc` below `root_dir`.
+pub fn encrypted_inputs(root_dir: &Path) -> Vec<PathBuf> {
+    let children = |dir: &Path| -> Vec<PathBuf> {
+        let mut paths = fs::read_dir(dir)
+            .map(|entries| entries.filter_map(Result::ok).map(|e| e.path()).collect())
+            .unwrap_or_else(|_| Vec::new());
+        paths.sort();
+        paths
+    };
+    let is_year = |path: &Path| path.file_name().and_then(|n| n.to_str()).is_some_and(|n| n.parse::<u32>().is_ok());
+    children(root_dir).into_iter()
+        .filter(|path| is_year(path))
+        .flat_map(|year_dir| children(&year_dir))
+        .map(|day_dir| encrypted_path(&day_dir.join("input.txt")))
+        .filter(|path| path.exists())
+        .collect()
+}
+
+/// Where `rotate` keeps the old key for the key file at `path` until every input has been re-encrypted, e.g. `vault.key.old`.
+pub fn old_key_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".old");
+    PathBuf::from(name)
+}
+
+/// Re-encrypts each of `paths` from the `old` key to the `new` one, saving the new key to the key file at `key_path`.
+/// Every input is re-encrypted to a temporary file, and the old key is kept at `old_key_path`, before the new key is saved
+/// and the inputs are replaced, so a failure part way through leaves every input readable with one key or the other.
+/// Inputs which the new key already decrypts are left alone, so running again with the same keys finishes a rotation.
+pub fn rotate(paths: &[PathBuf], key_path: &Path, old: &Key, new: &Key) -> Result<(), AppErr> {
+    rotate_with(paths, key_path, old, new, |from, to| fs::rename(from, to))
+}
+
+fn rotate_with<F>(paths: &[PathBuf], key_path: &Path, old: &Key, new: &Key, rename: F) -> Result<(), AppErr>
+    where F: Fn(&Path, &Path) -> std::io::Result<()>
+{
+    let mut staged = Vec::with_capacity(paths.len());
+    let remove_staged = |staged: &[(PathBuf, &PathBuf)]| {
+        for (partial, _) in staged {
+            let _ = fs::remove_file(partial);
+        }
+    };
+    let mut stage = || -> Result<(), AppErr> {
+        for path in paths {
+            let data = fs::read(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            if new.decrypt(&data).is_ok() {
+                continue;
+            }
+            let text = decrypt_file(old, path)?;
+            let partial = path.with_extension("partial");
+            fs::write(&partial, new.encrypt(text.as_bytes())?)
+                .with_context(|| format!("failed to write {}", partial.display()))?;
+            staged.push((partial, path));
+        }
+        Ok(())
+    };
+    let old_path = old_key_path(key_path);
+    let result = stage()
+        .and_then(|()| save_key(&old_path, old))
+        .and_then(|()| save_key(key_path, new));
+    if let Err(err) = result {
+        remove_staged(&staged);
+        return Err(err);
+    }
+
+    for (i, (partial, path)) in staged.iter().enumerate() {
+        if let Err(err) = rename(partial, path).with_context(|| format!("failed to write {}", path.display())) {
+            remove_staged(&staged[i..]);
+            return Err(err.context(format!("failed to re-encrypt every input: the old key is kept in {}, and rotating again finishes it", old_path.display())));
+        }
+    }
+    fs::remove_file(&old_path)
+        .with_context(|| format!("failed to remove {}", old_path.display()))
+}
+
+#[cfg(test)]
+mod vault_tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let key = Key::generate().unwrap();
+        let encrypted = key.encrypt(b"1721\n979\n").unwrap();
+        assert!(encrypted.starts_with(MAGIC));
+        assert_ne!(key.encrypt(b"1721\n979\n").unwrap(), encrypted);
+        assert_eq!(key.decrypt(&encrypted).unwrap(), b"1721\n979\n");
+
+        let other = Key::generate().unwrap();
+        assert!(other.decrypt(&encrypted).is_err());
+        let mut changed = encrypted.clone();
+        *changed.last_mut().unwrap() ^= 1;
+        assert!(key.decrypt(&changed).is_err());
+        assert_eq!(key.decrypt(b"1721\n979\n").unwrap_err().kind(), ErrorKind::Parse);
+    }
+
+    #[test]
+    fn parses_keys() {
+        let key = Key::generate().unwrap();
+        assert_eq!(Key::parse(&format!("{}\n", key.to_hex())).unwrap(), key);
+        assert_eq!(format!("{:?}", key), "Key(..)");
+        assert!(Key::parse("abc").is_err());
+        assert!(Key::parse(&"g".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn reads_encrypted_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-vault-read-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt");
+        assert_eq!(resolve_input(&path), path);
+        fs::write(encrypted_path(&path), "").unwrap();
+        assert_eq!(resolve_input(&path), encrypted_path(&path));
+        fs::write(&path, "1\n").unwrap();
+        assert_eq!(resolve_input(&path), path);
+        assert_eq!(read_input(&path).unwrap().as_str(), "1\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn finds_encrypted_inputs() {
+        assert_eq!(encrypted_path(Path::new("2020/day8/input.txt")), Path::new("2020/day8/input.txt.enc"));
+        assert!(is_encrypted(Path::new("2020/day8/input.txt.enc")));
+        assert!(!is_encrypted(Path::new("2020/day8/input.txt")));
+
+        let root = std::env::temp_dir().join(format!("aoc-vault-find-test-{}", std::process::id()));
+        for dir in ["2020/day8", "2020/day9", "2023/day1", "util"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        for file in ["2020/day8/input.txt.enc", "2020/day9/input.txt", "2023/day1/input.txt.enc", "util/input.txt.enc"] {
+            fs::write(root.join(file), "").unwrap();
+        }
+        assert_eq!(encrypted_inputs(&root), [root.join("2020/day8/input.txt.enc"), root.join("2023/day1/input.txt.enc")]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    fn write_inputs(dir: &Path, key: &Key) -> [PathBuf; 2] {
+        fs::create_dir_all(dir).unwrap();
+        let source = dir.join("input.txt");
+        let paths = [dir.join("a.txt.enc"), dir.join("b.txt.enc")];
+        for (path, text) in paths.iter().zip(["a\n", "b\n"]) {
+            fs::write(&source, text).unwrap();
+            encrypt_file(key, &source, path).unwrap();
+        }
+        paths
+    }
+
+    #[test]
+    fn rotates_keys() {
+        let dir = std::env::temp_dir().join(format!("aoc-vault-rotate-test-{}", std::process::id()));
+        let (old, new) = (Key::generate().unwrap(), Key::generate().unwrap());
+        let paths = write_inputs(&dir, &old);
+        let key_path = dir.join("vault.key");
+        save_key(&key_path, &old).unwrap();
+
+        // A wrong key changes nothing
+        let err = rotate(&paths, &key_path, &Key::generate().unwrap(), &new).unwrap_err();
+        assert!(err.to_string().contains("failed to decrypt"));
+        assert_eq!(decrypt_file(&old, &paths[0]).unwrap(), "a\n");
+        assert_eq!(Key::parse(&fs::read_to_string(&key_path).unwrap()).unwrap(), old);
+
+        // Nor does failing to save the key
+        assert!(rotate(&paths, &dir.join("a.txt.enc/vault.key"), &old, &new).is_err());
+        assert_eq!(decrypt_file(&old, &paths[1]).unwrap(), "b\n");
+        assert!(!dir.join("b.txt.partial").exists());
+
+        rotate(&paths, &key_path, &old, &new).unwrap();
+        assert_eq!(Key::parse(&fs::read_to_string(&key_path).unwrap()).unwrap(), new);
+        assert!(!old_key_path(&key_path).exists());
+        assert_eq!(decrypt_file(&new, &paths[0]).unwrap(), "a\n");
+        assert_eq!(decrypt_file(&new, &paths[1]).unwrap(), "b\n");
+        assert!(decrypt_file(&old, &paths[1]).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn finishes_failed_rotations() {
+        let dir = std::env::temp_dir().join(format!("aoc-vault-rotate-fail-test-{}", std::process::id()));
+        let (old, new) = (Key::generate().unwrap(), Key::generate().unwrap());
+        let paths = write_inputs(&dir, &old);
+        let key_path = dir.join("vault.key");
+        save_key(&key_path, &old).unwrap();
+
+        // Replacing the second input fails, leaving it with the old key, which is kept
+        let fail_second = |from: &Path, to: &Path| {
+            if to == paths[1] { Err(std::io::Error::other("no space")) } else { fs::rename(from, to) }
+        };
+        let err = rotate_with(&paths, &key_path, &old, &new, fail_second).unwrap_err();
+        assert!(err.to_string().contains("the old key is kept in"));
+        assert_eq!(decrypt_file(&new, &paths[0]).unwrap(), "a\n");
+        assert_eq!(decrypt_file(&old, &paths[1]).unwrap(), "b\n");
+        assert!(!dir.join("b.txt.partial").exists());
+        assert_eq!(Key::parse(&fs::read_to_string(&key_path).unwrap()).unwrap(), new);
+        assert_eq!(Key::parse(&fs::read_to_string(old_key_path(&key_path)).unwrap()).unwrap(), old);
+
+        // Rotating again with the same keys finishes it
+        rotate(&paths, &key_path, &old, &new).unwrap();
+        assert_eq!(decrypt_file(&new, &paths[0]).unwrap(), "a\n");
+        assert_eq!(decrypt_file(&new, &paths[1]).unwrap(), "b\n");
+        assert!(!old_key_path(&key_path).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn saves_keys() {
+        let path = std::env::temp_dir().join(format!("aoc-vault-key-test-{}", std::process::id())).join("vault.key");
+        let key = Key::generate().unwrap();
+        save_key(&path, &key).unwrap();
+        assert_eq!(Key::parse(&fs::read_to_string(&path).unwrap()).unwrap(), key);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}